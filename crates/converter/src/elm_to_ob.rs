@@ -0,0 +1,649 @@
+use crate::{lang, Conversion, ConversionError, ConversionOptions, ConversionReport};
+use std::str::FromStr;
+use types_common::OneOrMany;
+use types_elm_v3 as elm;
+use types_ob_v3::prelude as ob;
+
+const CREDENTIALS_CONTEXT: &str = "https://www.w3.org/2018/credentials/v1";
+const OB_CONTEXT: &str = "https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.2.json";
+
+pub fn convert(
+    credential: &elm::EuropassEdcCredential,
+    options: &ConversionOptions,
+) -> Result<Conversion<ob::AchievementCredential>, ConversionError> {
+    let mut converter = ElmToOb {
+        lang: options.language.clone(),
+        report: ConversionReport::default(),
+    };
+
+    let credential = converter.credential(credential)?;
+
+    Ok(Conversion {
+        credential,
+        report: converter.report,
+    })
+}
+
+fn as_vec<T>(value: &OneOrMany<T>) -> Vec<&T> {
+    match value {
+        OneOrMany::One(one) => vec![one.as_ref()],
+        OneOrMany::Many(many) => many.iter().collect(),
+    }
+}
+
+struct ElmToOb {
+    lang: String,
+    report: ConversionReport,
+}
+
+impl ElmToOb {
+    fn credential(
+        &mut self,
+        credential: &elm::EuropassEdcCredential,
+    ) -> Result<ob::AchievementCredential, ConversionError> {
+        let subjects = as_vec(&credential.credential_subject);
+
+        for i in 1..subjects.len() {
+            self.report.unmapped(
+                format!("$.credentialSubject[{i}]"),
+                "OBv3 credentials have exactly one credential subject",
+            );
+        }
+
+        let first = subjects
+            .first()
+            .ok_or(ConversionError::MissingClaim("credential subject"))?;
+        let (subject, claim) = self.subject(first, &credential.id)?;
+
+        if credential.credential_profiles.is_some() {
+            self.report
+                .unmapped("$.credentialProfiles", "OBv3 has no credential profiles");
+        }
+
+        self.report
+            .unmapped("$.credentialSchema", "ELM schemas don't apply to an OBv3 credential");
+
+        if credential.proof.is_some() {
+            self.report.unmapped(
+                "$.proof",
+                "The proof signs the ELM credential, it doesn't hold for the OBv3 credential",
+            );
+        }
+
+        let mut name = self.text(&claim.title);
+        let mut description = None;
+
+        if let Some(display) = &credential.display_parameter {
+            name = self.text(&display.title);
+            description = display.description.as_ref().and_then(|d| self.text(d));
+            self.report.lossy(
+                "$.displayParameter",
+                "Only the title and description of the display parameter are kept",
+            );
+        }
+
+        let issuer = self.profile(&credential.issuer, "$.issuer", &credential.id);
+
+        let credential_status = credential.credential_status.as_ref().and_then(|status| {
+            match status {
+                elm::CredentialStatusValue::StatusList2021Entry(_)
+                | elm::CredentialStatusValue::BitstringStatusListEntry(_) => self.report.lossy(
+                    "$.credentialStatus",
+                    "OBv3 credential statuses only have an id and a type, the status list fields are dropped",
+                ),
+                elm::CredentialStatusValue::CredentialStatus(_) | elm::CredentialStatusValue::Other(_) => {}
+            }
+
+            match (status.id(), status.type_name()) {
+                (Some(id), Some(type_)) => Some(ob::CredentialStatus {
+                    id,
+                    type_: type_.to_string(),
+                }),
+                _ => {
+                    self.report
+                        .unmapped("$.credentialStatus", "OBv3 credential statuses need an id and a type");
+                    None
+                }
+            }
+        });
+
+        let evidence = match &credential.evidence {
+            Some(evidence) => as_vec(evidence)
+                .into_iter()
+                .enumerate()
+                .map(|(i, evidence)| self.evidence(evidence, &format!("$.evidence[{i}]")))
+                .collect(),
+            None => vec![],
+        };
+
+        let terms_of_use = credential.terms_of_use.as_ref().map(|terms_of_use| {
+            ob::AchievementCredentialTermsOfUse::VecTermsOfUse(
+                as_vec(terms_of_use)
+                    .into_iter()
+                    .map(|terms| ob::TermsOfUse {
+                        id: terms.id.clone(),
+                        type_: terms.type_extension.clone(),
+                    })
+                    .collect(),
+            )
+        });
+
+        Ok(ob::AchievementCredential {
+            context: vec![CREDENTIALS_CONTEXT.into(), OB_CONTEXT.into()],
+            id: credential.id.clone(),
+            type_: Some(ob::AchievementCredentialType::from(vec![
+                "VerifiableCredential",
+                "OpenBadgeCredential",
+            ])),
+            name: name.unwrap_or_else(|| subject.achievement.name.clone()),
+            description,
+            image: None,
+            awarded_date: claim.awarded_by.awarding_date,
+            credential_subject: subject,
+            endorsement: vec![],
+            endorsement_jwt: vec![],
+            evidence,
+            issuer,
+            issuance_date: credential.valid_from,
+            expiration_date: credential.valid_until,
+            proof: None,
+            credential_schema: None,
+            credential_status,
+            refresh_service: None,
+            terms_of_use,
+        })
+    }
+
+    /// Returns the achievement subject and the learning achievement it was built from.
+    fn subject<'a>(
+        &mut self,
+        subject: &'a elm::AgentOrPersonOrOrganisation,
+        credential_id: &str,
+    ) -> Result<(ob::AchievementSubject, &'a elm::LearningAchievement), ConversionError> {
+        let path = "$.credentialSubject";
+
+        let elm::AgentOrPersonOrOrganisation::Person(person) = subject else {
+            return Err(ConversionError::MissingClaim("person as credential subject"));
+        };
+
+        let claims = person.has_claim.as_ref().map(as_vec).unwrap_or_default();
+
+        let mut achievement = None;
+        for (i, claim) in claims.into_iter().enumerate() {
+            match claim {
+                elm::ClaimNode::LearningAchievement(learning_achievement) if achievement.is_none() => {
+                    achievement = Some(learning_achievement.as_ref());
+                }
+                _ => self.report.unmapped(
+                    format!("{path}.hasClaim[{i}]"),
+                    "OBv3 credentials contain a single achievement",
+                ),
+            }
+        }
+
+        let claim = achievement.ok_or(ConversionError::MissingClaim("learning achievement"))?;
+
+        let mut identifier = vec![];
+
+        if let Some(full_name) = &person.full_name {
+            if let Some(name) = lang::kv_text(full_name) {
+                identifier.push(self.identity_object(name, ob::IdentityObjectTypeEnum::Name.into()));
+            }
+        }
+
+        if let Some(identifiers) = &person.identifier {
+            for (i, id) in as_vec(identifiers).into_iter().enumerate() {
+                let (notation, scheme_name) = match id {
+                    elm::IdentifierOrLegalIdentifier::Identifier(id) => (&id.notation, &id.scheme_name),
+                    elm::IdentifierOrLegalIdentifier::LegalIdentifier(id) => (&id.notation, &id.scheme_name),
+                };
+
+                // Identity types are a closed vocabulary, unknown scheme names become a generic identifier.
+                let identity_type = scheme_name
+                    .as_deref()
+                    .and_then(|name| ob::IdentityObjectType::from_str(name).ok())
+                    .unwrap_or_else(|| {
+                        if scheme_name.is_some() {
+                            self.report.lossy(
+                                format!("{path}.identifier[{i}].schemeName"),
+                                "Not a valid OBv3 identity type",
+                            );
+                        }
+                        ob::IdentityObjectTypeEnum::Identifier.into()
+                    });
+
+                identifier.push(self.identity_object(notation.to_string(), identity_type));
+            }
+        }
+
+        for (field, value) in [
+            ("givenName", person.given_name.is_some()),
+            ("familyName", person.family_name.is_some()),
+            ("birthName", person.birth_name.is_some()),
+            ("patronymicName", person.patronymic_name.is_some()),
+            ("dateOfBirth", person.date_of_birth.is_some()),
+            ("placeOfBirth", person.place_of_birth.is_some()),
+            ("citizenshipCountry", person.citizenship_country.is_some()),
+            ("gender", person.gender.is_some()),
+            ("contactPoint", person.contact_point.is_some()),
+            ("location", person.location.is_some()),
+            ("nationalID", person.national_id.is_some()),
+            ("memberOf", person.member_of.is_some()),
+            ("groupMemberOf", person.group_member_of.is_some()),
+            ("hasCredential", person.has_credential.is_some()),
+        ] {
+            if value {
+                self.report
+                    .unmapped(format!("{path}.{field}"), "OBv3 has no equivalent for this field");
+            }
+        }
+
+        let achievement = self.achievement(claim, credential_id)?;
+
+        let credits_earned = claim
+            .credit_received
+            .as_ref()
+            .and_then(|credits| self.credits(credits, "$.credentialSubject.hasClaim.creditReceived"));
+
+        let result = match &claim.proven_by {
+            Some(assessments) => as_vec(assessments)
+                .into_iter()
+                .map(|assessment| ob::ResultAchievement {
+                    type_: "Result".into(),
+                    achieved_level: None,
+                    alignment: vec![],
+                    result_description: None,
+                    status: None,
+                    value: self.text(&assessment.grade.note_literal),
+                })
+                .collect(),
+            None => vec![],
+        };
+
+        if claim.proven_by.is_some() {
+            self.report.lossy(
+                format!("{path}.hasClaim.provenBy"),
+                "Only the grades of the learning assessments are kept",
+            );
+        }
+
+        Ok((
+            ob::AchievementSubject {
+                id: person.id.as_ref().map(|id| id.to_string()),
+                type_: "AchievementSubject".into(),
+                activity_end_date: None,
+                activity_start_date: None,
+                credits_earned,
+                achievement,
+                identifier,
+                image: None,
+                license_number: None,
+                narrative: claim.additional_note.as_ref().and_then(|notes| self.notes(notes)),
+                result,
+                role: None,
+                source: None,
+                term: None,
+            },
+            claim,
+        ))
+    }
+
+    fn achievement(
+        &mut self,
+        claim: &elm::LearningAchievement,
+        credential_id: &str,
+    ) -> Result<ob::Achievement, ConversionError> {
+        let path = "$.credentialSubject.hasClaim";
+
+        let specification = match &claim.specified_by {
+            Some(elm::LearningAchievementSpecificationOrQualification::LearningAchievementSpecification(spec)) => {
+                Some(spec.as_ref())
+            }
+            Some(elm::LearningAchievementSpecificationOrQualification::Qualification(_)) => {
+                self.report.unmapped(
+                    format!("{path}.specifiedBy"),
+                    "Qualifications can't be expressed as an OBv3 achievement",
+                );
+                None
+            }
+            None => None,
+        };
+
+        let id = specification
+            .and_then(|spec| spec.id.as_ref())
+            .or(claim.id.as_ref())
+            .map(|id| id.to_string())
+            .unwrap_or_else(|| format!("{credential_id}#achievement"));
+
+        let name = self.text(&claim.title).ok_or_else(|| ConversionError::InvalidValue {
+            path: format!("{path}.title"),
+            reason: "Doesn't contain a text".to_string(),
+        })?;
+
+        let description = claim
+            .description
+            .as_ref()
+            .or(specification.and_then(|spec| spec.description.as_ref()))
+            .and_then(|description| self.text(description))
+            .unwrap_or_else(|| name.clone());
+
+        let mut alignment = vec![];
+        let mut criteria_narrative = None;
+        let mut credits_available = None;
+        let mut human_code = None;
+        let mut tag = vec![];
+
+        if let Some(spec) = specification {
+            if let Some(outcomes) = &spec.learning_outcome {
+                for outcome in as_vec(outcomes) {
+                    alignment.extend(self.alignment(outcome));
+                }
+            }
+
+            criteria_narrative = spec.learning_outcome_summary.as_ref().and_then(|note| self.note(note));
+            credits_available = spec
+                .credit_point
+                .as_ref()
+                .and_then(|credits| self.credits(credits, &format!("{path}.specifiedBy.creditPoint")));
+
+            if let Some(elm::IdentifierOrLegalIdentifier::Identifier(identifier)) = &spec.identifier {
+                if identifier.scheme_name.as_deref() == Some("humanCode") {
+                    human_code = Some(identifier.notation.to_string());
+                }
+            }
+
+            if let Some(category) = &spec.category {
+                if let Some(category) = self.text(category) {
+                    tag = category.split(", ").map(|tag| tag.to_string()).collect();
+                }
+            }
+        }
+
+        Ok(ob::Achievement {
+            id,
+            type_: "Achievement".into(),
+            alignment,
+            achievement_type: None,
+            creator: None,
+            credits_available,
+            criteria: ob::Criteria {
+                id: None,
+                narrative: criteria_narrative,
+            },
+            description,
+            endorsement: vec![],
+            endorsement_jwt: vec![],
+            field_of_study: None,
+            human_code,
+            image: None,
+            language: None,
+            name,
+            other_identifier: vec![],
+            related: vec![],
+            result_description: vec![],
+            specialization: None,
+            tag,
+            version: None,
+        })
+    }
+
+    fn alignment(&mut self, outcome: &elm::LearningOutcome) -> Vec<ob::Alignment> {
+        let mut skills = vec![];
+
+        if let Some(related_skill) = &outcome.related_skill {
+            skills.extend(as_vec(related_skill));
+        }
+
+        if let Some(related_esco_skill) = &outcome.related_esco_skill {
+            skills.extend(as_vec(related_esco_skill));
+        }
+
+        skills
+            .into_iter()
+            .filter_map(|skill| {
+                let target_url = skill.id.as_ref()?.to_string();
+
+                Some(ob::Alignment {
+                    type_: "Alignment".into(),
+                    target_code: skill.notation.as_ref().map(|notation| notation.to_string()),
+                    target_description: skill.definition.as_ref().and_then(|definition| self.text(definition)),
+                    target_name: skill
+                        .pref_label
+                        .as_ref()
+                        .and_then(|label| self.text(label))
+                        .or_else(|| self.text(&outcome.title))?,
+                    target_framework: None,
+                    target_type: None,
+                    target_url,
+                })
+            })
+            .collect()
+    }
+
+    fn profile(&mut self, organisation: &elm::Organisation, path: &str, credential_id: &str) -> ob::Profile {
+        let id = match &organisation.id {
+            Some(id) => id.to_string(),
+            None => {
+                self.report.lossy(
+                    format!("{path}.id"),
+                    "Profiles require an id, one is derived from the credential",
+                );
+                format!("{credential_id}#issuer")
+            }
+        };
+
+        let url = organisation
+            .homepage
+            .as_ref()
+            .and_then(|homepage| as_vec(homepage).first().map(|page| page.content_url.to_string()));
+
+        let contact_points = organisation.contact_point.as_ref().map(as_vec).unwrap_or_default();
+        let email = contact_points
+            .iter()
+            .filter_map(|contact| contact.email_address.as_ref())
+            .flat_map(as_vec)
+            .find_map(|mailbox| match &mailbox.id {
+                Some(elm::Email::EmailAddress(email)) => Some(email.to_string()),
+                Some(elm::Email::MailTo(mail_to)) => Some(mail_to.trim_start_matches("mailto:").to_string()),
+                None => None,
+            });
+        let phone = contact_points
+            .iter()
+            .filter_map(|contact| contact.phone.as_ref())
+            .flat_map(as_vec)
+            .find_map(|phone| phone.phone_number.clone());
+
+        let other_identifier = organisation
+            .identifier
+            .as_ref()
+            .map(as_vec)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|identifier| {
+                let notation = match identifier {
+                    elm::IdentifierOrLegalIdentifier::Identifier(id) => id.notation.to_string(),
+                    elm::IdentifierOrLegalIdentifier::LegalIdentifier(id) => id.notation.to_string(),
+                };
+
+                ob::IdentifierEntry {
+                    type_: "IdentifierEntry".to_string(),
+                    identifier: notation,
+                    identifier_type: ob::IdentifierTypeEnum::Identifier.into(),
+                }
+            })
+            .collect();
+
+        let address = as_vec(&organisation.location)
+            .first()
+            .and_then(|location| self.address(location));
+
+        let parent_org = organisation
+            .sub_organization_of
+            .as_ref()
+            .map(|parent| self.profile(parent, &format!("{path}.subOrganizationOf"), credential_id));
+
+        for (field, value) in [
+            ("accreditation", organisation.accreditation.is_some()),
+            ("altLabel", organisation.alt_label.is_some()),
+            ("dcType", organisation.dc_type.is_some()),
+            ("eIDASIdentifier", organisation.e_idas_identifier.is_some()),
+            ("groupMemberOf", organisation.group_member_of.is_some()),
+            ("hasMember", organisation.has_member.is_some()),
+            ("hasSubOrganization", organisation.has_sub_organization.is_some()),
+            ("logo", organisation.logo.is_some()),
+            ("registration", organisation.registration.is_some()),
+            ("taxIdentifier", organisation.tax_identifier.is_some()),
+            ("vatIdentifier", organisation.vat_identifier.is_some()),
+        ] {
+            if value {
+                self.report.unmapped(
+                    format!("{path}.{field}"),
+                    "OBv3 profiles have no equivalent for this field",
+                );
+            }
+        }
+
+        ob::Profile {
+            id,
+            type_: "Profile".into(),
+            name: self.text(&organisation.legal_name),
+            url,
+            phone,
+            description: organisation
+                .additional_note
+                .as_ref()
+                .and_then(|notes| self.notes(notes)),
+            endorsement: vec![],
+            endorsement_jwt: vec![],
+            image: None,
+            email,
+            address,
+            other_identifier,
+            official: None,
+            parent_org: Box::new(parent_org),
+            family_name: None,
+            given_name: None,
+            additional_name: None,
+            patronymic_name: None,
+            honorific_prefix: None,
+            honorific_suffix: None,
+            family_name_prefix: None,
+            date_of_birth: None,
+        }
+    }
+
+    fn address(&self, location: &elm::Location) -> Option<ob::Address> {
+        let address = location
+            .address
+            .as_ref()
+            .and_then(|address| as_vec(address).first().copied());
+
+        let street_address = address
+            .and_then(|address| address.full_address.as_ref())
+            .and_then(|note| self.note(note))
+            .or_else(|| {
+                location
+                    .description
+                    .as_ref()
+                    .and_then(|description| self.text(description))
+            });
+
+        let address_country = address
+            .and_then(|address| address.country_code.pref_label.as_ref())
+            .and_then(|label| self.text(label));
+
+        let geo = location.geometry.as_ref().and_then(|geometry| {
+            let geometry = as_vec(geometry).into_iter().next()?;
+
+            Some(ob::GeoCoordinates {
+                type_: "GeoCoordinates".to_string(),
+                latitude: geometry.latitude.as_ref()?.parse().ok()?,
+                longitude: geometry.longitude.as_ref()?.parse().ok()?,
+            })
+        });
+
+        if street_address.is_none() && address_country.is_none() && geo.is_none() {
+            return None;
+        }
+
+        Some(ob::Address {
+            type_: "Address".into(),
+            address_country,
+            address_country_code: None,
+            address_region: None,
+            address_locality: None,
+            street_address,
+            post_office_box_number: None,
+            postal_code: None,
+            geo,
+        })
+    }
+
+    fn evidence(&mut self, evidence: &elm::Evidence, path: &str) -> ob::Evidence {
+        for (field, value) in [
+            ("accreditation", evidence.accreditation.is_some()),
+            ("embeddedEvidence", evidence.embedded_evidence.is_some()),
+            ("evidenceTarget", evidence.evidence_target.is_some()),
+            ("dcType", evidence.dc_type.is_some()),
+        ] {
+            if value {
+                self.report.unmapped(
+                    format!("{path}.{field}"),
+                    "OBv3 evidence has no equivalent for this field",
+                );
+            }
+        }
+
+        ob::Evidence {
+            id: evidence.id.as_ref().map(|id| id.to_string()),
+            type_: "Evidence".into(),
+            narrative: evidence.evidence_statement.clone(),
+            name: None,
+            description: None,
+            genre: None,
+            audience: None,
+        }
+    }
+
+    fn identity_object(&self, identity: String, identity_type: ob::IdentityObjectType) -> ob::IdentityObject {
+        ob::IdentityObject {
+            type_: "IdentityObject".to_string(),
+            hashed: false,
+            identity_hash: identity,
+            identity_object_type: identity_type,
+            salt: None,
+        }
+    }
+
+    /// Sums up the credit points, OBv3 only knows a single amount of credits without a framework.
+    fn credits(&mut self, credits: &OneOrMany<elm::CreditPoint>, path: &str) -> Option<f64> {
+        let credits = as_vec(credits);
+
+        let framework = credits.first().map(|credit| &credit.framework.id);
+        if credits.iter().any(|credit| Some(&credit.framework.id) != framework) {
+            self.report.lossy(
+                path,
+                "Credit points of different frameworks are added up, OBv3 credits have no framework",
+            );
+        }
+
+        credits
+            .into_iter()
+            .filter_map(|credit| credit.point.parse::<f64>().ok())
+            .reduce(|a, b| a + b)
+    }
+
+    fn notes(&self, notes: &OneOrMany<elm::Note>) -> Option<String> {
+        let notes: Vec<String> = as_vec(notes).into_iter().filter_map(|note| self.note(note)).collect();
+
+        (!notes.is_empty()).then(|| notes.join("\n\n"))
+    }
+
+    fn note(&self, note: &elm::Note) -> Option<String> {
+        self.text(&note.note_literal)
+    }
+
+    fn text(&self, pairs: &elm::LangKVPairs) -> Option<String> {
+        lang::text(pairs, &self.lang)
+    }
+}
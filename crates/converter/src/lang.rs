@@ -0,0 +1,36 @@
+use std::collections::HashMap;
+use types_elm_v3::{LangKV, LangKVPairs, LangKey};
+
+pub fn lang_pairs(lang: &LangKey, text: &str) -> LangKVPairs {
+    let kv_pairs = HashMap::from([(lang.clone(), serde_json::Value::String(text.to_string()))]);
+
+    LangKVPairs::new(kv_pairs).expect("Contains one pair")
+}
+
+pub fn lang_kv(lang: &LangKey, text: &str) -> LangKV {
+    let mut kv_pair = serde_json::Map::new();
+    kv_pair.insert(lang.to_string(), serde_json::Value::String(text.to_string()));
+
+    LangKV::new(kv_pair).expect("Contains one pair")
+}
+
+/// Picks the text in the preferred language, or the first language in alphabetical order.
+pub fn text(pairs: &LangKVPairs, lang: &str) -> Option<String> {
+    let lang = match pairs.text(lang) {
+        Some(_) => lang,
+        None => pairs.iter_texts().next()?.0.as_str(),
+    };
+
+    join(pairs.texts(lang))
+}
+
+pub fn kv_text(kv: &LangKV) -> Option<String> {
+    join(kv.texts())
+}
+
+/// Language values are either a string or an array of strings, arrays are joined.
+fn join<'a>(texts: impl Iterator<Item = &'a str>) -> Option<String> {
+    let texts: Vec<&str> = texts.collect();
+
+    (!texts.is_empty()).then(|| texts.join(", "))
+}
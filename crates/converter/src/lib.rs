@@ -0,0 +1,72 @@
+//! Conversion between Open Badges v3 `AchievementCredential`s and ELM v3 `EuropassEdcCredential`s.
+//!
+//! The two data models don't cover the same information, every field that can't be carried over
+//! unharmed ends up in the [`ConversionReport`] of the returned [`Conversion`].
+use std::fmt;
+use types_elm_v3::EuropassEdcCredential;
+use types_ob_v3::prelude::AchievementCredential;
+
+pub use report::{ConversionReport, ReportEntry, ReportKind};
+
+mod elm_to_ob;
+mod lang;
+mod ob_to_elm;
+mod report;
+
+#[derive(Debug)]
+pub enum ConversionError {
+    /// The credential doesn't contain the claim the target model requires.
+    MissingClaim(&'static str),
+    /// The configured language is not a valid language key.
+    InvalidLanguage(String),
+    /// A value that is required in the target model can't be converted.
+    InvalidValue { path: String, reason: String },
+}
+
+impl std::error::Error for ConversionError {}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MissingClaim(claim) => write!(f, "Credential doesn't contain a {claim}"),
+            Self::InvalidLanguage(lang) => write!(f, "Not a valid language: \"{lang}\""),
+            Self::InvalidValue { path, reason } => write!(f, "Can't convert {path}: {reason}"),
+        }
+    }
+}
+
+/// The converted credential together with everything that was lost on the way.
+#[derive(Clone, Debug)]
+pub struct Conversion<T> {
+    pub credential: T,
+    pub report: ConversionReport,
+}
+
+#[derive(Clone, Debug)]
+pub struct ConversionOptions {
+    /// Language used for the multilingual ELM fields when converting plain OBv3 strings,
+    /// and the preferred language when picking a text from ELM fields.
+    pub language: String,
+}
+
+impl Default for ConversionOptions {
+    fn default() -> Self {
+        Self {
+            language: "en".to_string(),
+        }
+    }
+}
+
+pub fn ob_to_elm(
+    credential: &AchievementCredential,
+    options: &ConversionOptions,
+) -> Result<Conversion<EuropassEdcCredential>, ConversionError> {
+    ob_to_elm::convert(credential, options)
+}
+
+pub fn elm_to_ob(
+    credential: &EuropassEdcCredential,
+    options: &ConversionOptions,
+) -> Result<Conversion<AchievementCredential>, ConversionError> {
+    elm_to_ob::convert(credential, options)
+}
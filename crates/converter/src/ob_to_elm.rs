@@ -0,0 +1,810 @@
+use crate::{lang, Conversion, ConversionError, ConversionOptions, ConversionReport};
+use chrono::{DateTime, Utc};
+use std::str::FromStr;
+use types_common::{EmailAddress, OneOrMany};
+use types_elm_v3 as elm;
+use types_ob_v3::prelude as ob;
+
+const CREDENTIALS_CONTEXT: &str = "https://www.w3.org/ns/credentials/v2";
+const EDC_CONTEXT: &str = "http://data.europa.eu/snb/model/context/edc-ap";
+const EDC_SHACL_SCHEMA: &str = "http://data.europa.eu/snb/model/ap/edc-generic-full";
+const ECTS_FRAMEWORK: &str = "http://data.europa.eu/snb/education-credit/6fcec5c5af";
+
+pub fn convert(
+    credential: &ob::AchievementCredential,
+    options: &ConversionOptions,
+) -> Result<Conversion<elm::EuropassEdcCredential>, ConversionError> {
+    let lang = elm::LangKey::from_str(&options.language)
+        .map_err(|_| ConversionError::InvalidLanguage(options.language.clone()))?;
+
+    let mut converter = ObToElm {
+        lang,
+        report: ConversionReport::default(),
+    };
+
+    let credential = converter.credential(credential)?;
+
+    Ok(Conversion {
+        credential,
+        report: converter.report,
+    })
+}
+
+struct ObToElm {
+    lang: elm::LangKey,
+    report: ConversionReport,
+}
+
+impl ObToElm {
+    fn credential(
+        &mut self,
+        credential: &ob::AchievementCredential,
+    ) -> Result<elm::EuropassEdcCredential, ConversionError> {
+        let issuer = self.organisation(&credential.issuer, "$.issuer")?;
+        let awarding_date = credential.awarded_date.unwrap_or(credential.issuance_date);
+        let subject = self.person(&credential.credential_subject, &issuer, awarding_date)?;
+
+        if credential.credential_schema.is_some() {
+            self.report.unmapped(
+                "$.credentialSchema",
+                "Replaced by the EDC SHACL schema, OBv3 JSON schemas don't apply to an EDC",
+            );
+        }
+
+        if credential.proof.is_some() {
+            self.report.unmapped(
+                "$.proof",
+                "A proof can't survive the conversion, the EDC has to be signed again",
+            );
+        }
+
+        if !credential.endorsement.is_empty() {
+            self.report.unmapped("$.endorsement", "ELM has no endorsements");
+        }
+
+        if !credential.endorsement_jwt.is_empty() {
+            self.report.unmapped("$.endorsementJwt", "ELM has no endorsements");
+        }
+
+        if credential.image.is_some() {
+            self.report.unmapped("$.image", "ELM credentials have no image");
+        }
+
+        if credential.refresh_service.is_some() {
+            self.report.unmapped("$.refreshService", "ELM has no refresh service");
+        }
+
+        if credential.description.is_some() {
+            self.report
+                .unmapped("$.description", "ELM credentials have no description");
+        }
+
+        if credential.name != credential.credential_subject.achievement.name {
+            self.report.unmapped(
+                "$.name",
+                "ELM credentials have no name, the achievement title is used instead",
+            );
+        }
+
+        let credential_status = match &credential.credential_status {
+            Some(status) => {
+                self.uri(&status.id, "$.credentialStatus.id")?;
+
+                let status = serde_json::json!({ "id": status.id, "type": status.type_ });
+
+                // A status list entry without its list fields can only be kept as it is.
+                Some(serde_json::from_value(status.clone()).unwrap_or(elm::CredentialStatusValue::Other(status)))
+            }
+            None => None,
+        };
+
+        let terms_of_use = credential.terms_of_use.as_ref().map(|terms_of_use| {
+            let terms_of_use = match terms_of_use {
+                ob::AchievementCredentialTermsOfUse::TermsOfUse(terms) => vec![terms.clone()],
+                ob::AchievementCredentialTermsOfUse::VecTermsOfUse(terms) => terms.clone(),
+            };
+
+            OneOrMany::Many(
+                terms_of_use
+                    .into_iter()
+                    .map(|terms| elm::TermsOfUseValue {
+                        id: terms.id,
+                        type_extension: terms.type_,
+                    })
+                    .collect(),
+            )
+        });
+
+        let mut evidence = vec![];
+        for (i, item) in credential.evidence.iter().enumerate() {
+            evidence.push(self.evidence(item, &format!("$.evidence[{i}]")));
+        }
+
+        Ok(elm::EuropassEdcCredential {
+            context: elm::EuropassEdcCredentialContext::Many(vec![
+                CREDENTIALS_CONTEXT.to_string(),
+                EDC_CONTEXT.to_string(),
+            ]),
+            credential_profiles: None,
+            credential_schema: OneOrMany::One(Box::new(elm::CredentialSchema {
+                id: Some(self.uri(EDC_SHACL_SCHEMA, "$.credentialSchema")?),
+                type_: elm::CredentialSchemaType::ShaclValidator2017,
+            })),
+            credential_status,
+            credential_subject: OneOrMany::One(Box::new(elm::AgentOrPersonOrOrganisation::Person(Box::new(subject)))),
+            display_parameter: None,
+            evidence: (!evidence.is_empty()).then_some(OneOrMany::Many(evidence)),
+            id: credential.id.clone(),
+            issuer,
+            proof: None,
+            terms_of_use,
+            type_: vec![
+                "VerifiableCredential".to_string(),
+                "VerifiableAttestation".to_string(),
+                "EuropeanDigitalCredential".to_string(),
+            ],
+            valid_from: credential.issuance_date,
+            valid_until: credential.expiration_date,
+        })
+    }
+
+    fn person(
+        &mut self,
+        subject: &ob::AchievementSubject,
+        issuer: &elm::Organisation,
+        awarding_date: DateTime<Utc>,
+    ) -> Result<elm::Person, ConversionError> {
+        let path = "$.credentialSubject";
+
+        let id = match &subject.id {
+            Some(id) => Some(self.uri(id, &format!("{path}.id"))?),
+            None => None,
+        };
+
+        let mut full_name = None;
+        let mut identifiers = vec![];
+
+        for (i, identity) in subject.identifier.iter().enumerate() {
+            let identity_path = format!("{path}.identifier[{i}]");
+            let identity_type = serde_json::to_value(&identity.identity_object_type)
+                .ok()
+                .and_then(|value| value.as_str().map(|s| s.to_string()))
+                .unwrap_or_default();
+
+            if identity.hashed {
+                self.report.unmapped(
+                    identity_path,
+                    "Hashed identities can't be represented as an ELM identifier",
+                );
+                continue;
+            }
+
+            if identity.salt.is_some() {
+                self.report
+                    .lossy(format!("{identity_path}.salt"), "ELM identifiers have no salt");
+            }
+
+            if identity_type == "name" && full_name.is_none() {
+                full_name = Some(lang::lang_kv(&self.lang, &identity.identity_hash));
+            } else {
+                identifiers.push(elm::IdentifierOrLegalIdentifier::Identifier(Box::new(
+                    self.identifier(&identity.identity_hash, &identity_type),
+                )));
+            }
+        }
+
+        let achievement = self.learning_achievement(subject, issuer, awarding_date)?;
+
+        for (field, value) in [
+            ("activityStartDate", subject.activity_start_date.is_some()),
+            ("activityEndDate", subject.activity_end_date.is_some()),
+            ("image", subject.image.is_some()),
+            ("licenseNumber", subject.license_number.is_some()),
+            ("role", subject.role.is_some()),
+            ("source", subject.source.is_some()),
+            ("term", subject.term.is_some()),
+        ] {
+            if value {
+                self.report
+                    .unmapped(format!("{path}.{field}"), "ELM has no equivalent for this field");
+            }
+        }
+
+        Ok(elm::Person {
+            birth_name: None,
+            citizenship_country: None,
+            contact_point: None,
+            date_modified: None,
+            date_of_birth: None,
+            family_name: None,
+            full_name,
+            gender: None,
+            given_name: None,
+            group_member_of: None,
+            has_claim: Some(OneOrMany::One(Box::new(elm::ClaimNode::LearningAchievement(Box::new(
+                achievement,
+            ))))),
+            has_credential: None,
+            id,
+            identifier: (!identifiers.is_empty()).then_some(OneOrMany::Many(identifiers)),
+            location: None,
+            member_of: None,
+            national_id: None,
+            patronymic_name: None,
+            place_of_birth: None,
+            type_: elm::PersonTag::default(),
+        })
+    }
+
+    fn learning_achievement(
+        &mut self,
+        subject: &ob::AchievementSubject,
+        issuer: &elm::Organisation,
+        awarding_date: DateTime<Utc>,
+    ) -> Result<elm::LearningAchievement, ConversionError> {
+        let achievement = &subject.achievement;
+
+        let awarded_by = elm::AwardingProcess {
+            additional_note: None,
+            awarding_body: OneOrMany::One(Box::new(elm::AgentOrPersonOrOrganisation::Organisation(Box::new(
+                issuer.clone(),
+            )))),
+            awarding_date: Some(awarding_date),
+            awards: None,
+            description: None,
+            educational_system_note: None,
+            id: None,
+            identifier: None,
+            location: None,
+            used: None,
+            type_: elm::AwardingProcessTag::default(),
+        };
+
+        let mut assessments = vec![];
+        for (i, result) in subject.result.iter().enumerate() {
+            if let Some(assessment) = self.learning_assessment(result, achievement, &awarded_by, i) {
+                assessments.push(assessment);
+            }
+        }
+
+        let credit_received = subject.credits_earned.map(|credits| self.credit_point(credits));
+        let specification = self.learning_achievement_specification(achievement)?;
+
+        Ok(elm::LearningAchievement {
+            additional_note: subject
+                .narrative
+                .as_ref()
+                .map(|narrative| OneOrMany::One(Box::new(self.note(narrative)))),
+            awarded_by: Box::new(awarded_by),
+            credit_received: credit_received.map(|credit| OneOrMany::One(Box::new(credit))),
+            dc_type: None,
+            description: Some(lang::lang_pairs(&self.lang, &achievement.description)),
+            entitles_to: None,
+            has_part: None,
+            id: None,
+            identifier: None,
+            influenced_by: None,
+            is_part_of: None,
+            learning_opportunity: None,
+            proven_by: (!assessments.is_empty()).then(|| Box::new(OneOrMany::Many(assessments))),
+            specified_by: Some(
+                elm::LearningAchievementSpecificationOrQualification::LearningAchievementSpecification(Box::new(
+                    specification,
+                )),
+            ),
+            supplementary_document: None,
+            title: lang::lang_pairs(&self.lang, &achievement.name),
+            type_: elm::LearningAchievementTag::default(),
+        })
+    }
+
+    fn learning_achievement_specification(
+        &mut self,
+        achievement: &ob::Achievement,
+    ) -> Result<elm::LearningAchievementSpecification, ConversionError> {
+        let path = "$.credentialSubject.achievement";
+
+        let mut learning_outcomes = vec![];
+        for (i, alignment) in achievement.alignment.iter().enumerate() {
+            if let Some(outcome) = self.learning_outcome(alignment, &format!("{path}.alignment[{i}]")) {
+                learning_outcomes.push(outcome);
+            }
+        }
+
+        let supplementary_document = match &achievement.criteria.id {
+            Some(id) => match elm::UriType::from_str(id) {
+                Ok(uri) => Some(OneOrMany::One(Box::new(self.web_resource(uri)))),
+                Err(err) => {
+                    self.report.unmapped(format!("{path}.criteria.id"), err.to_string());
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let identifier = achievement
+            .human_code
+            .as_ref()
+            .map(|human_code| self.identifier(human_code, "humanCode"));
+
+        if !achievement.tag.is_empty() {
+            self.report.lossy(
+                format!("{path}.tag"),
+                "Tags are joined into the category of the learning achievement specification",
+            );
+        }
+
+        if achievement.language.is_some() {
+            self.report.unmapped(
+                format!("{path}.language"),
+                "ELM languages are concepts of the EU language authority table",
+            );
+        }
+
+        for (field, value) in [
+            ("achievementType", achievement.achievement_type.is_some()),
+            ("creator", achievement.creator.is_some()),
+            ("endorsement", !achievement.endorsement.is_empty()),
+            ("endorsementJwt", !achievement.endorsement_jwt.is_empty()),
+            ("fieldOfStudy", achievement.field_of_study.is_some()),
+            ("image", achievement.image.is_some()),
+            ("otherIdentifier", !achievement.other_identifier.is_empty()),
+            ("related", !achievement.related.is_empty()),
+            ("resultDescription", !achievement.result_description.is_empty()),
+            ("specialization", achievement.specialization.is_some()),
+            ("version", achievement.version.is_some()),
+        ] {
+            if value {
+                self.report
+                    .unmapped(format!("{path}.{field}"), "ELM has no equivalent for this field");
+            }
+        }
+
+        Ok(elm::LearningAchievementSpecification {
+            additional_note: None,
+            alt_label: None,
+            awarding_opportunity: None,
+            category: (!achievement.tag.is_empty()).then(|| lang::lang_pairs(&self.lang, &achievement.tag.join(", "))),
+            credit_point: achievement
+                .credits_available
+                .map(|credits| OneOrMany::One(Box::new(self.credit_point(credits)))),
+            date_modified: None,
+            dc_type: None,
+            description: Some(lang::lang_pairs(&self.lang, &achievement.description)),
+            education_level: None,
+            education_subject: None,
+            entitles_to: None,
+            entry_requirement: None,
+            generalisation_of: None,
+            has_part: None,
+            homepage: None,
+            id: Some(self.uri(&achievement.id, &format!("{path}.id"))?),
+            identifier: identifier.map(|identifier| elm::IdentifierOrLegalIdentifier::Identifier(Box::new(identifier))),
+            influenced_by: None,
+            is_part_of: None,
+            language: None,
+            learning_outcome: (!learning_outcomes.is_empty()).then_some(OneOrMany::Many(learning_outcomes)),
+            learning_outcome_summary: achievement
+                .criteria
+                .narrative
+                .as_ref()
+                .map(|narrative| self.note(narrative)),
+            learning_setting: None,
+            maximum_duration: None,
+            mode: None,
+            proven_by: None,
+            specialisation_of: None,
+            status: None,
+            supplementary_document,
+            target_group: None,
+            thematic_area: None,
+            title: lang::lang_pairs(&self.lang, &achievement.name),
+            volume_of_learning: None,
+            type_: elm::LearningAchievementSpecificationTag::default(),
+        })
+    }
+
+    fn learning_outcome(&mut self, alignment: &ob::Alignment, path: &str) -> Option<elm::LearningOutcome> {
+        let id = match elm::UriType::from_str(&alignment.target_url) {
+            Ok(id) => id,
+            Err(err) => {
+                self.report.unmapped(path, err.to_string());
+                return None;
+            }
+        };
+
+        if alignment.target_framework.is_some() || alignment.target_type.is_some() {
+            self.report.lossy(
+                path,
+                "The target framework and type can't be expressed on the related skill",
+            );
+        }
+
+        let skill = elm::Concept {
+            definition: alignment
+                .target_description
+                .as_ref()
+                .map(|description| lang::lang_pairs(&self.lang, description)),
+            id: Some(id),
+            in_scheme: None,
+            notation: alignment.target_code.clone().map(elm::Literal),
+            pref_label: Some(lang::lang_pairs(&self.lang, &alignment.target_name)),
+            type_: elm::ConceptTag::default(),
+        };
+
+        Some(elm::LearningOutcome {
+            additional_note: None,
+            dc_type: None,
+            id: None,
+            identifier: None,
+            related_esco_skill: None,
+            related_skill: Some(OneOrMany::One(Box::new(skill))),
+            reusability_level: None,
+            title: lang::lang_pairs(&self.lang, &alignment.target_name),
+            type_: elm::LearningOutcomeTag::default(),
+        })
+    }
+
+    fn learning_assessment(
+        &mut self,
+        result: &ob::ResultAchievement,
+        achievement: &ob::Achievement,
+        awarded_by: &elm::AwardingProcess,
+        index: usize,
+    ) -> Option<elm::LearningAssessment> {
+        let path = format!("$.credentialSubject.result[{index}]");
+
+        let status = result.status.map(|status| format!("{status:?}"));
+        let Some(grade) = result
+            .value
+            .clone()
+            .or_else(|| result.achieved_level.clone())
+            .or(status)
+        else {
+            self.report
+                .unmapped(path, "A result without value can't be expressed as a grade");
+            return None;
+        };
+
+        if !result.alignment.is_empty() {
+            self.report
+                .unmapped(format!("{path}.alignment"), "ELM assessments have no alignment");
+        }
+
+        let title = result
+            .result_description
+            .as_ref()
+            .and_then(|id| {
+                achievement
+                    .result_description
+                    .iter()
+                    .find(|description| &description.id == id)
+            })
+            .map(|description| description.name.clone())
+            .unwrap_or_else(|| "Result".to_string());
+
+        Some(elm::LearningAssessment {
+            additional_note: None,
+            assessed_by: None,
+            awarded_by: Box::new(awarded_by.clone()),
+            date_issued: None,
+            dc_type: None,
+            description: None,
+            grade: self.note(&grade),
+            grade_status: None,
+            has_part: None,
+            id: None,
+            id_verification: None,
+            identifier: None,
+            is_part_of: None,
+            location: None,
+            proves: None,
+            result_distribution: None,
+            shortened_grading: None,
+            specified_by: None,
+            supplementary_document: None,
+            title: lang::lang_pairs(&self.lang, &title),
+            type_: elm::LearningAssessmentTag::default(),
+        })
+    }
+
+    fn organisation(&mut self, profile: &ob::Profile, path: &str) -> Result<elm::Organisation, ConversionError> {
+        let legal_name = match &profile.name {
+            Some(name) => name.clone(),
+            None => {
+                self.report.lossy(
+                    format!("{path}.name"),
+                    "Organisations require a legal name, the id is used",
+                );
+                profile.id.clone()
+            }
+        };
+
+        let homepage = match &profile.url {
+            Some(url) => match elm::UriType::from_str(url) {
+                Ok(uri) => Some(OneOrMany::One(Box::new(self.web_resource(uri)))),
+                Err(err) => {
+                    self.report.unmapped(format!("{path}.url"), err.to_string());
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let location = match &profile.address {
+            Some(address) => self.location(address, &format!("{path}.address")),
+            None => self.empty_location(),
+        };
+
+        let sub_organization_of = match profile.parent_org.as_ref() {
+            Some(parent) => Some(Box::new(self.organisation(parent, &format!("{path}.parentOrg"))?)),
+            None => None,
+        };
+
+        let identifiers: Vec<elm::IdentifierOrLegalIdentifier> = profile
+            .other_identifier
+            .iter()
+            .map(|entry| {
+                let identifier_type = serde_json::to_value(&entry.identifier_type)
+                    .ok()
+                    .and_then(|value| value.as_str().map(|s| s.to_string()))
+                    .unwrap_or_default();
+
+                elm::IdentifierOrLegalIdentifier::Identifier(Box::new(
+                    self.identifier(&entry.identifier, &identifier_type),
+                ))
+            })
+            .collect();
+
+        for (field, value) in [
+            ("image", profile.image.is_some()),
+            ("endorsement", !profile.endorsement.is_empty()),
+            ("endorsementJwt", !profile.endorsement_jwt.is_empty()),
+            ("official", profile.official.is_some()),
+            ("familyName", profile.family_name.is_some()),
+            ("givenName", profile.given_name.is_some()),
+            ("additionalName", profile.additional_name.is_some()),
+            ("patronymicName", profile.patronymic_name.is_some()),
+            ("honorificPrefix", profile.honorific_prefix.is_some()),
+            ("honorificSuffix", profile.honorific_suffix.is_some()),
+            ("familyNamePrefix", profile.family_name_prefix.is_some()),
+            ("dateOfBirth", profile.date_of_birth.is_some()),
+        ] {
+            if value {
+                self.report.unmapped(
+                    format!("{path}.{field}"),
+                    "ELM organisations have no equivalent for this field",
+                );
+            }
+        }
+
+        Ok(elm::Organisation {
+            accreditation: None,
+            additional_note: profile
+                .description
+                .as_ref()
+                .map(|description| OneOrMany::One(Box::new(self.note(description)))),
+            alt_label: None,
+            contact_point: self.contact_point(profile, path),
+            date_modified: None,
+            dc_type: None,
+            e_idas_identifier: None,
+            group_member_of: None,
+            has_member: None,
+            has_sub_organization: None,
+            homepage,
+            id: Some(self.uri(&profile.id, &format!("{path}.id"))?),
+            identifier: (!identifiers.is_empty()).then_some(OneOrMany::Many(identifiers)),
+            legal_name: lang::lang_pairs(&self.lang, &legal_name),
+            location: OneOrMany::One(Box::new(location)),
+            logo: None,
+            registration: None,
+            sub_organization_of,
+            tax_identifier: None,
+            vat_identifier: None,
+            type_: elm::OrganisationTag::default(),
+        })
+    }
+
+    fn contact_point(&mut self, profile: &ob::Profile, path: &str) -> Option<OneOrMany<elm::ContactPoint>> {
+        let email_address = match &profile.email {
+            Some(email) => match EmailAddress::from_str(email) {
+                Ok(email) => Some(OneOrMany::One(Box::new(elm::Mailbox {
+                    id: Some(elm::Email::EmailAddress(email)),
+                    type_: elm::MailboxTag::default(),
+                }))),
+                Err(err) => {
+                    self.report.unmapped(format!("{path}.email"), err.to_string());
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let phone = profile.phone.as_ref().map(|phone| {
+            OneOrMany::One(Box::new(elm::Phone {
+                area_dialing: None,
+                country_dialing: None,
+                dial_number: None,
+                id: None,
+                phone_number: Some(phone.clone()),
+                type_: elm::PhoneTag::default(),
+            }))
+        });
+
+        if email_address.is_none() && phone.is_none() {
+            return None;
+        }
+
+        Some(OneOrMany::One(Box::new(elm::ContactPoint {
+            additional_note: None,
+            address: None,
+            contact_form: None,
+            description: None,
+            email_address,
+            id: None,
+            phone,
+            type_: elm::ContactPointTag::default(),
+        })))
+    }
+
+    fn location(&mut self, address: &ob::Address, path: &str) -> elm::Location {
+        let full_address: Vec<&str> = [
+            &address.street_address,
+            &address.post_office_box_number,
+            &address.postal_code,
+            &address.address_locality,
+            &address.address_region,
+            &address.address_country,
+        ]
+        .into_iter()
+        .filter_map(|part| part.as_deref())
+        .collect();
+
+        if address.address_country_code.is_some() {
+            self.report.lossy(
+                format!("{path}.addressCountryCode"),
+                "ISO 3166-1 alpha-2 codes don't map onto the EU country authority table",
+            );
+        }
+
+        self.report.lossy(
+            path,
+            "ELM addresses require a country concept, the address is kept as location description",
+        );
+
+        let geometry = address.geo.as_ref().map(|geo| {
+            OneOrMany::One(Box::new(elm::Geometry {
+                id: None,
+                latitude: Some(geo.latitude.to_string()),
+                longitude: Some(geo.longitude.to_string()),
+                type_: elm::GeometryTag::default(),
+            }))
+        });
+
+        elm::Location {
+            description: (!full_address.is_empty()).then(|| lang::lang_pairs(&self.lang, &full_address.join(", "))),
+            geometry,
+            ..self.empty_location()
+        }
+    }
+
+    fn empty_location(&self) -> elm::Location {
+        elm::Location {
+            address: None,
+            description: None,
+            geographic_name: None,
+            geometry: None,
+            id: None,
+            identifier: None,
+            spatial_code: None,
+            type_: elm::LocationTag::default(),
+        }
+    }
+
+    fn evidence(&mut self, evidence: &ob::Evidence, path: &str) -> elm::Evidence {
+        let id = match &evidence.id {
+            Some(id) => match elm::UriType::from_str(id) {
+                Ok(id) => Some(id),
+                Err(_) => {
+                    // Data URIs aren't valid ELM identifiers.
+                    self.report.unmapped(format!("{path}.id"), "Not a valid URI");
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let statement = match (&evidence.narrative, &evidence.description) {
+            (Some(narrative), Some(_)) => {
+                self.report.lossy(
+                    format!("{path}.description"),
+                    "The narrative is used as evidence statement",
+                );
+                Some(narrative.clone())
+            }
+            (narrative, description) => narrative.clone().or_else(|| description.clone()),
+        };
+
+        for (field, value) in [
+            ("name", evidence.name.is_some()),
+            ("genre", evidence.genre.is_some()),
+            ("audience", evidence.audience.is_some()),
+        ] {
+            if value {
+                self.report.unmapped(
+                    format!("{path}.{field}"),
+                    "ELM evidence has no equivalent for this field",
+                );
+            }
+        }
+
+        elm::Evidence {
+            accreditation: None,
+            embedded_evidence: None,
+            evidence_statement: statement,
+            evidence_target: None,
+            id,
+            dc_type: None,
+            type_: elm::EvidenceTag::default(),
+        }
+    }
+
+    fn credit_point(&self, credits: f64) -> elm::CreditPoint {
+        elm::CreditPoint {
+            framework: elm::Concept {
+                definition: None,
+                id: elm::UriType::from_str(ECTS_FRAMEWORK).ok(),
+                in_scheme: None,
+                notation: None,
+                pref_label: Some(lang::lang_pairs(&self.lang, "European Credit Transfer System")),
+                type_: elm::ConceptTag::default(),
+            },
+            id: None,
+            point: credits.to_string(),
+            type_: elm::CreditPointTag::default(),
+        }
+    }
+
+    fn identifier(&self, notation: &str, scheme_name: &str) -> elm::Identifier {
+        elm::Identifier {
+            creator: None,
+            date_issued: None,
+            dc_type: None,
+            id: None,
+            notation: elm::Literal(notation.to_string()),
+            scheme_agency: None,
+            scheme_id: None,
+            scheme_name: (!scheme_name.is_empty()).then(|| scheme_name.to_string()),
+            scheme_version: None,
+            type_: elm::IdentifierTag::default(),
+        }
+    }
+
+    fn note(&self, text: &str) -> elm::Note {
+        elm::Note {
+            id: None,
+            note_format: None,
+            note_literal: lang::lang_pairs(&self.lang, text),
+            subject: None,
+            type_: elm::NoteTag::default(),
+        }
+    }
+
+    fn web_resource(&self, content_url: elm::UriType) -> elm::WebResource {
+        elm::WebResource {
+            content_url,
+            id: None,
+            language: None,
+            title: None,
+            type_: elm::WebResourceTag::default(),
+        }
+    }
+
+    fn uri(&self, value: &str, path: &str) -> Result<elm::UriType, ConversionError> {
+        elm::UriType::from_str(value).map_err(|err| ConversionError::InvalidValue {
+            path: path.to_string(),
+            reason: err.to_string(),
+        })
+    }
+}
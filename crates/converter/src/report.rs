@@ -0,0 +1,76 @@
+use serde::Serialize;
+use std::fmt;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub enum ReportKind {
+    /// The value was carried over, but information was lost on the way.
+    Lossy,
+    /// The target model has no place for the value, it was dropped.
+    Unmapped,
+}
+
+impl fmt::Display for ReportKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Lossy => f.write_str("lossy"),
+            Self::Unmapped => f.write_str("unmapped"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct ReportEntry {
+    /// JSON path of the field in the source credential, e.g. `$.credentialSubject.role`
+    pub path: String,
+    pub kind: ReportKind,
+    pub message: String,
+}
+
+impl fmt::Display for ReportEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.path, self.kind, self.message)
+    }
+}
+
+/// Every field of the source credential that didn't make it into the target credential unharmed.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
+pub struct ConversionReport {
+    pub entries: Vec<ReportEntry>,
+}
+
+impl ConversionReport {
+    pub fn lossy(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.push(path.into(), ReportKind::Lossy, message.into());
+    }
+
+    pub fn unmapped(&mut self, path: impl Into<String>, message: impl Into<String>) {
+        self.push(path.into(), ReportKind::Unmapped, message.into());
+    }
+
+    /// Returns true if nothing was lost during the conversion.
+    pub fn is_lossless(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &ReportEntry> {
+        self.entries.iter()
+    }
+
+    pub fn get(&self, path: &str) -> Option<&ReportEntry> {
+        self.entries.iter().find(|entry| entry.path == path)
+    }
+
+    fn push(&mut self, path: String, kind: ReportKind, message: String) {
+        self.entries.push(ReportEntry { path, kind, message });
+    }
+}
+
+impl fmt::Display for ConversionReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for entry in self.entries.iter() {
+            writeln!(f, "{entry}")?;
+        }
+
+        Ok(())
+    }
+}
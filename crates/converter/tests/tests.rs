@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests {
+    use converter::*;
+    use std::fs::File;
+    use types_elm_v3::EuropassEdcCredential;
+    use types_ob_v3::prelude::*;
+
+    fn ob_example(filename: &str) -> AchievementCredential {
+        let file = File::open(format!("../types-ob-v3/tests/obv3_json_examples/{filename}")).expect("file exists");
+        serde_json::from_reader(file).expect("valid OBv3 credential")
+    }
+
+    fn elm_example(filename: &str) -> EuropassEdcCredential {
+        let file = File::open(format!("../validator/elm-requests/{filename}")).expect("file exists");
+        serde_json::from_reader(file).expect("valid ELM credential")
+    }
+
+    #[test]
+    fn test_basic_ob_to_elm() {
+        let credential = ob_example("basic_achievement_credential.json");
+        let conversion = ob_to_elm(&credential, &ConversionOptions::default()).unwrap();
+
+        let json = serde_json::to_value(&conversion.credential).unwrap();
+        assert_eq!(json["credentialSubject"]["hasClaim"]["title"]["en"], "Teamwork");
+        assert_eq!(json["issuer"]["legalName"]["en"], "Example Corp");
+        assert_eq!(json["validFrom"], "2010-01-01T00:00:00Z");
+
+        // The serialized credential is a valid ELM credential again.
+        let _: EuropassEdcCredential = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            conversion.report.get("$.name").map(|entry| entry.kind),
+            Some(ReportKind::Unmapped)
+        );
+    }
+
+    #[test]
+    fn test_full_ob_to_elm_report() {
+        let credential = ob_example("full_achievement_credential.json");
+        let conversion = ob_to_elm(&credential, &ConversionOptions::default()).unwrap();
+
+        let report = &conversion.report;
+        assert!(!report.is_lossless());
+        assert!(report.get("$.credentialSubject.role").is_some());
+        assert!(report.get("$.credentialSubject.term").is_some());
+        assert!(report.get("$.endorsement").is_some());
+        assert!(report.get("$.credentialSubject.identifier[0].salt").is_some());
+
+        let json = serde_json::to_value(&conversion.credential).unwrap();
+        let _: EuropassEdcCredential = serde_json::from_value(json).unwrap();
+    }
+
+    #[test]
+    fn test_ob_round_trip() {
+        let credential = ob_example("basic_achievement_credential.json");
+        let options = ConversionOptions::default();
+
+        let elm = ob_to_elm(&credential, &options).unwrap().credential;
+        let ob = elm_to_ob(&elm, &options).unwrap().credential;
+
+        let achievement = &ob.credential_subject.achievement;
+        assert_eq!(achievement.id, credential.credential_subject.achievement.id);
+        assert_eq!(achievement.name, "Teamwork");
+        assert_eq!(
+            achievement.description,
+            credential.credential_subject.achievement.description
+        );
+        assert_eq!(achievement.criteria, credential.credential_subject.achievement.criteria);
+        assert_eq!(ob.issuer.id, credential.issuer.id);
+        assert_eq!(ob.issuer.name, credential.issuer.name);
+        assert_eq!(ob.issuance_date, credential.issuance_date);
+        assert_eq!(ob.credential_subject.id, credential.credential_subject.id);
+    }
+
+    #[test]
+    fn test_microcredential_elm_to_ob() {
+        let credential = elm_example("microcredential.json");
+        let conversion = elm_to_ob(&credential, &ConversionOptions::default()).unwrap();
+
+        let ob = &conversion.credential;
+        assert_eq!(ob.credential_subject.achievement.name, "Data and software business");
+        assert_eq!(ob.issuer.name.as_deref(), Some("ORGANIZACION TEST"));
+        assert_eq!(ob.credential_subject.id.as_deref(), Some("did:key:afsdlkj34134"));
+        assert!(conversion.report.get("$.credentialSubject.givenName").is_some());
+
+        // The serialized credential is a valid OBv3 credential again.
+        let json = serde_json::to_value(ob).unwrap();
+        let _: AchievementCredential = serde_json::from_value(json).unwrap();
+    }
+
+    #[test]
+    fn test_elm_requests_to_ob() {
+        for filename in [
+            "bengales-highschool-diploma.json",
+            "digicomp-generic.json",
+            "diploma-rntuo-credential.json",
+            "francisco-cruz.json",
+            "microcredential.json",
+            "transcript-of-records.json",
+        ] {
+            let credential = elm_example(filename);
+            let conversion = elm_to_ob(&credential, &ConversionOptions::default());
+
+            assert!(conversion.is_ok(), "{filename}: {}", conversion.unwrap_err());
+        }
+    }
+
+    #[test]
+    fn test_empty_credential_subject() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(include_str!("../../validator/elm-requests/microcredential.json")).unwrap();
+        json["credentialSubject"] = serde_json::json!([]);
+        let credential: EuropassEdcCredential = serde_json::from_value(json).unwrap();
+
+        assert!(matches!(
+            elm_to_ob(&credential, &ConversionOptions::default()),
+            Err(ConversionError::MissingClaim("credential subject"))
+        ));
+    }
+
+    #[test]
+    fn test_dropped_proof_and_mixed_credit_frameworks() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(include_str!("../../validator/elm-requests/microcredential.json")).unwrap();
+        json["proof"] = serde_json::json!({
+            "type": "JsonWebSignature2020",
+            "created": "2024-01-01T00:00:00Z",
+            "proofPurpose": "assertionMethod",
+            "verificationMethod": "did:example:issuer#key-1",
+            "jws": "eyJhbGciOiJFUzI1NiJ9..c2lnbmF0dXJl"
+        });
+        let credits = &mut json["credentialSubject"]["hasClaim"][0]["specifiedBy"]["creditPoint"];
+        let mut national = credits[0].clone();
+        national["id"] = "urn:epass:creditPoint:2".into();
+        national["framework"]["id"] = "urn:example:national-credits".into();
+        national["point"] = "10".into();
+        credits.as_array_mut().unwrap().push(national);
+        let credential: EuropassEdcCredential = serde_json::from_value(json).unwrap();
+
+        let conversion = elm_to_ob(&credential, &ConversionOptions::default()).unwrap();
+
+        let proof = conversion.report.get("$.proof").unwrap();
+        assert_eq!(proof.kind, ReportKind::Unmapped);
+        assert!(conversion.credential.proof.is_none());
+
+        let credits = conversion
+            .report
+            .get("$.credentialSubject.hasClaim.specifiedBy.creditPoint")
+            .unwrap();
+        assert_eq!(credits.kind, ReportKind::Lossy);
+        assert_eq!(
+            conversion.credential.credential_subject.achievement.credits_available,
+            Some(15.0)
+        );
+
+        // Credit points of a single framework add up without a report entry.
+        let credential = elm_example("microcredential.json");
+        let conversion = elm_to_ob(&credential, &ConversionOptions::default()).unwrap();
+        assert!(conversion
+            .report
+            .get("$.credentialSubject.hasClaim.specifiedBy.creditPoint")
+            .is_none());
+        assert!(conversion.report.get("$.proof").is_none());
+    }
+
+    #[test]
+    fn test_invalid_language() {
+        let credential = ob_example("basic_achievement_credential.json");
+        let options = ConversionOptions {
+            language: "english".to_string(),
+        };
+
+        assert!(matches!(
+            ob_to_elm(&credential, &options),
+            Err(ConversionError::InvalidLanguage(_))
+        ));
+    }
+}
@@ -0,0 +1,11 @@
+#!/usr/bin/env bash
+# Downloads the W3C contexts bundled in `contexts/` from their URLs, safe mode resolves remote contexts to them.
+# `edc.jsonld` is maintained by hand, it covers the ELM vocabulary for the SHACL validation.
+
+cd "$(dirname $0)/contexts"
+
+wget -O credentials-v1.jsonld https://www.w3.org/2018/credentials/v1
+wget -O credentials-v2.jsonld https://www.w3.org/ns/credentials/v2
+wget -O credentials-examples-v2.jsonld https://www.w3.org/ns/credentials/examples/v2
+wget -O data-integrity-v1.jsonld https://w3id.org/security/data-integrity/v1
+wget -O jws-2020-v1.jsonld https://w3id.org/security/suites/jws-2020/v1
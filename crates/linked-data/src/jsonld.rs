@@ -0,0 +1,658 @@
+//! Converts JSON-LD credentials into an RDF [`Graph`].
+//!
+//! Remote contexts are never fetched. By default the credential is interpreted with a bundled context
+//! (`contexts/edc.jsonld`) that covers the vocabulary of the ELM and the verifiable credentials data model. Embedded
+//! context objects are applied on top of it, other remote contexts can be supplied with [`JsonLdOptions::contexts`].
+//!
+//! In safe mode, which signatures use, remote contexts resolve to the documents bundled in `contexts/` instead, see
+//! [`bundled_context`]. `fetch-contexts.sh` refreshes them from their URLs.
+
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt,
+    sync::OnceLock,
+};
+
+use log::warn;
+use serde_json::{Map, Value};
+
+use crate::rdf::{Graph, Term, RDF_FIRST, RDF_NIL, RDF_REST, RDF_TYPE, XSD, XSD_STRING};
+
+const EDC_CONTEXT: &str = include_str!("../contexts/edc.jsonld");
+
+/// Remote contexts whose terms are covered by the bundled EDC context.
+const KNOWN_CONTEXTS: &[&str] = &[
+    "https://www.w3.org/2018/credentials/v1",
+    "https://www.w3.org/ns/credentials/v2",
+    "http://data.europa.eu/snb/model/context/edc-ap",
+];
+
+/// The context documents bundled for safe mode, by URL.
+const BUNDLED_CONTEXTS: &[(&str, &str)] = &[
+    (
+        "https://www.w3.org/2018/credentials/v1",
+        include_str!("../contexts/credentials-v1.jsonld"),
+    ),
+    (
+        "https://www.w3.org/ns/credentials/v2",
+        include_str!("../contexts/credentials-v2.jsonld"),
+    ),
+    (
+        "https://www.w3.org/ns/credentials/examples/v2",
+        include_str!("../contexts/credentials-examples-v2.jsonld"),
+    ),
+    (
+        "https://w3id.org/security/data-integrity/v1",
+        include_str!("../contexts/data-integrity-v1.jsonld"),
+    ),
+    (
+        "https://w3id.org/security/suites/jws-2020/v1",
+        include_str!("../contexts/jws-2020-v1.jsonld"),
+    ),
+    ("http://data.europa.eu/snb/model/context/edc-ap", EDC_CONTEXT),
+];
+
+/// The bundled document of a remote context, e.g. of `https://www.w3.org/ns/credentials/v2`.
+pub fn bundled_context(url: &str) -> Option<&'static Value> {
+    static DOCUMENTS: OnceLock<HashMap<&str, Value>> = OnceLock::new();
+
+    DOCUMENTS
+        .get_or_init(|| {
+            BUNDLED_CONTEXTS
+                .iter()
+                .map(|(url, document)| {
+                    (
+                        *url,
+                        serde_json::from_str(document).expect("bundled context is valid JSON"),
+                    )
+                })
+                .collect()
+        })
+        .get(url)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonLdError {
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for JsonLdError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+impl std::error::Error for JsonLdError {}
+
+/// How [`to_rdf_with_options`] treats the parts of a document it can't interpret.
+#[derive(Debug, Clone, Default)]
+pub struct JsonLdOptions {
+    /// Documents of remote contexts by URL, e.g. the OBv3 context. Their `@context` is applied where the URL is
+    /// referenced.
+    pub contexts: HashMap<String, Value>,
+    /// Fails on unknown remote contexts, properties and types without a mapping and unsupported containers instead
+    /// of dropping them. Signatures over the RDF need this, everything that is signed has to end up in the graph.
+    ///
+    /// Remote contexts then resolve to their [`bundled_context`] rather than to the EDC context.
+    pub safe: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    vocab: Option<String>,
+    terms: HashMap<String, TermDefinition>,
+}
+
+#[derive(Debug, Clone)]
+struct TermDefinition {
+    id: String,
+    type_: Option<String>,
+    containers: Vec<String>,
+    /// A scoped context, applied to the values of a property or to the nodes of a type.
+    context: Option<Value>,
+}
+
+impl TermDefinition {
+    fn container(&self, container: &str) -> bool {
+        self.containers.iter().any(|c| c == container)
+    }
+
+    /// The container or type of this term that the conversion doesn't implement.
+    fn unsupported(&self) -> Option<&str> {
+        self.containers
+            .iter()
+            .map(String::as_str)
+            .find(|c| ["@graph", "@id", "@index", "@type"].contains(c))
+            .or(self.type_.as_deref().filter(|t| *t == "@json"))
+    }
+}
+
+impl Context {
+    /// The bundled context for European Digital Credentials.
+    pub fn edc() -> &'static Context {
+        static CONTEXT: OnceLock<Context> = OnceLock::new();
+
+        CONTEXT.get_or_init(|| {
+            let mut context = Context::default();
+            context
+                .merge(
+                    &Context::edc_document()["@context"],
+                    "$.@context",
+                    &JsonLdOptions::default(),
+                    0,
+                )
+                .expect("bundled context is a valid JSON-LD context");
+            context
+        })
+    }
+
+    fn edc_document() -> &'static Value {
+        static DOCUMENT: OnceLock<Value> = OnceLock::new();
+
+        DOCUMENT.get_or_init(|| serde_json::from_str(EDC_CONTEXT).expect("bundled context is valid JSON"))
+    }
+
+    /// Applies a local context, which can be a context object, a remote context or an array of both.
+    fn merge(&mut self, local: &Value, path: &str, options: &JsonLdOptions, depth: usize) -> Result<(), JsonLdError> {
+        // Guards against remote contexts that reference each other.
+        if depth > 16 {
+            return Err(error(path, "Too many nested contexts"));
+        }
+
+        match local {
+            Value::Null => *self = Context::default(),
+            Value::String(url) => {
+                let document = options
+                    .contexts
+                    .get(url)
+                    .or_else(|| bundled_context(url).filter(|_| options.safe));
+
+                if let Some(document) = document {
+                    self.merge(&document["@context"], path, options, depth + 1)?;
+                } else if options.safe {
+                    return Err(error(path, &format!("Remote context {url} is not known")));
+                } else if !KNOWN_CONTEXTS.contains(&url.as_str()) {
+                    warn!("Remote context {url} is not supported, its terms are ignored");
+                }
+            }
+            Value::Array(contexts) => {
+                for (i, context) in contexts.iter().enumerate() {
+                    self.merge(context, &format!("{path}[{i}]"), options, depth)?;
+                }
+            }
+            Value::Object(definitions) => {
+                for (term, definition) in definitions {
+                    self.define(term, definition, &format!("{path}.{term}"))?;
+                }
+            }
+            _ => return Err(error(path, "A context must be an object, a string or an array")),
+        }
+
+        Ok(())
+    }
+
+    fn define(&mut self, term: &str, definition: &Value, path: &str) -> Result<(), JsonLdError> {
+        match (term, definition) {
+            ("@vocab", Value::String(vocab)) => self.vocab = Some(self.expand_iri(vocab, true)),
+            ("@vocab", Value::Null) => self.vocab = None,
+            ("@version" | "@protected" | "@base" | "@language", _) => {}
+            (_, Value::Null) => {
+                self.terms.remove(term);
+            }
+            (_, Value::String(id)) => {
+                self.terms.insert(
+                    term.to_string(),
+                    TermDefinition {
+                        id: id.clone(),
+                        type_: None,
+                        containers: vec![],
+                        context: None,
+                    },
+                );
+            }
+            (_, Value::Object(definition)) => {
+                let id = match definition.get("@id") {
+                    Some(Value::String(id)) => id.clone(),
+                    None => term.to_string(),
+                    Some(_) => return Err(error(path, "@id must be a string")),
+                };
+
+                let type_ = match definition.get("@type") {
+                    Some(Value::String(type_)) => Some(type_.clone()),
+                    None => None,
+                    Some(_) => return Err(error(path, "@type must be a string")),
+                };
+
+                let containers = match definition.get("@container") {
+                    Some(Value::String(container)) => vec![container.clone()],
+                    Some(Value::Array(containers)) => containers
+                        .iter()
+                        .filter_map(|c| c.as_str().map(str::to_string))
+                        .collect(),
+                    _ => vec![],
+                };
+
+                self.terms.insert(
+                    term.to_string(),
+                    TermDefinition {
+                        id,
+                        type_,
+                        containers,
+                        context: definition.get("@context").cloned(),
+                    },
+                );
+            }
+            _ => return Err(error(path, "A term definition must be a string or an object")),
+        }
+
+        Ok(())
+    }
+
+    /// The term that expands to `iri`, or its name under `@vocab`. Used to find the JSON name of a class or property.
+    pub fn compact_iri(&self, iri: &str) -> Option<String> {
+        let term = self
+            .terms
+            .iter()
+            .filter(|(term, definition)| !definition.id.starts_with('@') && self.expand_iri(term, true) == iri)
+            .map(|(term, _)| term.as_str())
+            .min();
+
+        match (term, &self.vocab) {
+            (Some(term), _) => Some(term.to_string()),
+            (None, Some(vocab)) => iri.strip_prefix(vocab.as_str()).map(str::to_string),
+            (None, None) => None,
+        }
+    }
+
+    /// Expands a term, compact IRI or keyword alias. Plain names are resolved against `@vocab` if `vocab` is set.
+    fn expand_iri(&self, value: &str, vocab: bool) -> String {
+        self.expand_iri_with_depth(value, vocab, 0)
+    }
+
+    fn expand_iri_with_depth(&self, value: &str, vocab: bool, depth: usize) -> String {
+        // Guards against cyclic term definitions.
+        if value.starts_with('@') || depth > 16 {
+            return value.to_string();
+        }
+
+        if vocab {
+            if let Some(definition) = self.terms.get(value) {
+                if definition.id != value {
+                    return self.expand_iri_with_depth(&definition.id, true, depth + 1);
+                }
+            }
+        }
+
+        if let Some((prefix, suffix)) = value.split_once(':') {
+            if prefix == "_" || suffix.starts_with("//") {
+                return value.to_string();
+            }
+
+            if let Some(definition) = self.terms.get(prefix) {
+                return format!(
+                    "{}{suffix}",
+                    self.expand_iri_with_depth(&definition.id, true, depth + 1)
+                );
+            }
+
+            return value.to_string();
+        }
+
+        match &self.vocab {
+            Some(vocab_iri) if vocab => format!("{vocab_iri}{value}"),
+            _ => value.to_string(),
+        }
+    }
+}
+
+/// Maps the nodes and triples of a converted document back to the JSON paths they were read from.
+#[derive(Debug, Clone, Default)]
+pub struct JsonPaths {
+    nodes: BTreeMap<Term, String>,
+    properties: BTreeMap<(Term, String), String>,
+    values: BTreeMap<(Term, String, Term), String>,
+}
+
+impl JsonPaths {
+    /// The path of the first JSON object describing `node`.
+    pub fn node(&self, node: &Term) -> Option<&str> {
+        self.nodes.get(node).map(String::as_str)
+    }
+
+    /// The path of the JSON key holding `predicate` on `subject`.
+    pub fn property(&self, subject: &Term, predicate: &str) -> Option<&str> {
+        self.properties
+            .get(&(subject.clone(), predicate.to_string()))
+            .map(String::as_str)
+    }
+
+    /// The path of the JSON value that produced the given triple.
+    pub fn value(&self, subject: &Term, predicate: &str, object: &Term) -> Option<&str> {
+        self.values
+            .get(&(subject.clone(), predicate.to_string(), object.clone()))
+            .map(String::as_str)
+    }
+}
+
+/// Converts a JSON-LD document into an RDF graph, using the bundled EDC context.
+pub fn to_rdf(document: &Value) -> Result<Graph, JsonLdError> {
+    to_rdf_with_paths(document).map(|(graph, _)| graph)
+}
+
+/// Like [`to_rdf`], with remote contexts and the handling of what can't be interpreted set by `options`.
+pub fn to_rdf_with_options(document: &Value, options: &JsonLdOptions) -> Result<Graph, JsonLdError> {
+    convert(document, options).map(|(graph, _)| graph)
+}
+
+/// Like [`to_rdf`], but also returns where each node and triple is located in the document.
+pub fn to_rdf_with_paths(document: &Value) -> Result<(Graph, JsonPaths), JsonLdError> {
+    convert(document, &JsonLdOptions::default())
+}
+
+fn convert(document: &Value, options: &JsonLdOptions) -> Result<(Graph, JsonPaths), JsonLdError> {
+    let mut converter = ToRdf {
+        options,
+        graph: Graph::new(),
+        paths: JsonPaths::default(),
+        blank_nodes: 0,
+    };
+
+    let empty = Context::default();
+    let base = if options.safe { &empty } else { Context::edc() };
+
+    match document {
+        Value::Object(object) => {
+            converter.node(base, object, "$")?;
+        }
+        Value::Array(objects) => {
+            for (i, object) in objects.iter().enumerate() {
+                let path = format!("$[{i}]");
+                let Value::Object(object) = object else {
+                    return Err(error(&path, "Expected a node object"));
+                };
+                converter.node(base, object, &path)?;
+            }
+        }
+        _ => return Err(error("$", "A JSON-LD document must be an object or an array")),
+    }
+
+    Ok((converter.graph, converter.paths))
+}
+
+struct ToRdf<'a> {
+    options: &'a JsonLdOptions,
+    graph: Graph,
+    paths: JsonPaths,
+    blank_nodes: usize,
+}
+
+impl ToRdf<'_> {
+    fn node(&mut self, context: &Context, object: &Map<String, Value>, path: &str) -> Result<Term, JsonLdError> {
+        let context = match object.get("@context") {
+            Some(local) => {
+                let mut context = context.clone();
+                context.merge(local, &format!("{path}.@context"), self.options, 0)?;
+                Cow::Owned(context)
+            }
+            None => Cow::Borrowed(context),
+        };
+
+        // Type-scoped contexts apply to the properties of this node, but not to the nodes below it.
+        let mut types: Vec<&str> = object
+            .iter()
+            .filter(|(key, _)| context.expand_iri(key, true) == "@type")
+            .flat_map(|(_, value)| as_slice(value))
+            .filter_map(Value::as_str)
+            .collect();
+        types.sort_unstable();
+
+        let mut active = Cow::Borrowed(context.as_ref());
+        for type_ in types {
+            if let Some(scoped) = context.terms.get(type_).and_then(|d| d.context.as_ref()) {
+                active
+                    .to_mut()
+                    .merge(scoped, &format!("{path}.@type"), self.options, 0)?;
+            }
+        }
+
+        let mut subject = None;
+        for (key, value) in object {
+            if active.expand_iri(key, true) == "@id" {
+                let Value::String(id) = value else {
+                    return Err(error(&format!("{path}.{key}"), "@id must be a string"));
+                };
+                subject = Some(match id.strip_prefix("_:") {
+                    Some(label) => Term::BlankNode(format!("l{label}")),
+                    None => Term::Iri(active.expand_iri(id, false)),
+                });
+            }
+        }
+        let subject = subject.unwrap_or_else(|| self.fresh_blank_node());
+        self.paths
+            .nodes
+            .entry(subject.clone())
+            .or_insert_with(|| path.to_string());
+
+        for (key, value) in object {
+            let path = format!("{path}.{key}");
+
+            match active.expand_iri(key, true).as_str() {
+                "@type" => {
+                    for (i, type_) in as_slice(value).iter().enumerate() {
+                        let Value::String(type_) = type_ else {
+                            return Err(error(&format!("{path}[{i}]"), "@type must be a string"));
+                        };
+
+                        let type_ = context.expand_iri(type_, true);
+                        if self.options.safe && !type_.contains(':') {
+                            return Err(error(&format!("{path}[{i}]"), "Type isn't defined by the context"));
+                        }
+                        self.graph.insert(subject.clone(), RDF_TYPE, Term::Iri(type_));
+                    }
+                }
+                "@graph" => {
+                    for (i, node) in as_slice(value).iter().enumerate() {
+                        if let Value::Object(node) = node {
+                            self.node(&context, node, &format!("{path}[{i}]"))?;
+                        }
+                    }
+                }
+                // Other keywords don't produce triples.
+                keyword if keyword.starts_with('@') => {}
+                // Without a vocabulary mapping the property is dropped, as in any JSON-LD processor.
+                predicate if !predicate.contains(':') => {
+                    if self.options.safe {
+                        return Err(error(&path, "Property isn't defined by the context"));
+                    }
+                }
+                predicate => {
+                    let definition = active.terms.get(key);
+
+                    if self.options.safe {
+                        if let Some(unsupported) = definition.and_then(|d| d.unsupported()) {
+                            return Err(error(&path, &format!("{unsupported} isn't supported")));
+                        }
+                    }
+
+                    // A property-scoped context applies to the nodes below, instead of the type-scoped ones.
+                    let scoped;
+                    let nested = match definition.and_then(|d| d.context.as_ref()) {
+                        Some(local) => {
+                            let mut nested = context.as_ref().clone();
+                            nested.merge(local, &format!("{path}.@context"), self.options, 0)?;
+                            scoped = nested;
+                            &scoped
+                        }
+                        None => context.as_ref(),
+                    };
+
+                    let list;
+                    let value = if definition.is_some_and(|d| d.container("@list")) && value.get("@list").is_none() {
+                        list = serde_json::json!({ "@list": value });
+                        &list
+                    } else {
+                        value
+                    };
+
+                    let mut objects = vec![];
+                    self.values(nested, definition, value, &path, &mut objects)?;
+
+                    self.paths
+                        .properties
+                        .entry((subject.clone(), predicate.to_string()))
+                        .or_insert_with(|| path.clone());
+
+                    for (object, path) in objects {
+                        self.paths
+                            .values
+                            .entry((subject.clone(), predicate.to_string(), object.clone()))
+                            .or_insert(path);
+                        self.graph.insert(subject.clone(), predicate, object);
+                    }
+                }
+            }
+        }
+
+        Ok(subject)
+    }
+
+    fn values(
+        &mut self,
+        context: &Context,
+        definition: Option<&TermDefinition>,
+        value: &Value,
+        path: &str,
+        objects: &mut Vec<(Term, String)>,
+    ) -> Result<(), JsonLdError> {
+        match value {
+            Value::Null => {}
+            Value::Array(values) => {
+                for (i, value) in values.iter().enumerate() {
+                    self.values(context, definition, value, &format!("{path}[{i}]"), objects)?;
+                }
+            }
+            Value::Object(map) if definition.is_some_and(|d| d.container("@language")) => {
+                for (language, value) in map {
+                    for (i, text) in as_slice(value).iter().enumerate() {
+                        let path = match value {
+                            Value::Array(_) => format!("{path}.{language}[{i}]"),
+                            _ => format!("{path}.{language}"),
+                        };
+
+                        match text {
+                            Value::Null => {}
+                            Value::String(text) if language == "@none" => {
+                                objects.push((Term::literal(text, XSD_STRING), path));
+                            }
+                            Value::String(text) => {
+                                objects.push((Term::lang_literal(text, language.to_lowercase()), path))
+                            }
+                            _ => return Err(error(&path, "Language map values must be strings")),
+                        }
+                    }
+                }
+            }
+            Value::Object(map) if map.contains_key("@value") => {
+                objects.extend(value_object(context, map, path)?.map(|term| (term, path.to_string())))
+            }
+            Value::Object(map) if map.contains_key("@list") => {
+                let mut items = vec![];
+                self.values(context, definition, &map["@list"], &format!("{path}.@list"), &mut items)?;
+
+                let mut head = Term::iri(RDF_NIL);
+                for (item, _) in items.into_iter().rev() {
+                    let node = self.fresh_blank_node();
+                    self.graph.insert(node.clone(), RDF_FIRST, item);
+                    self.graph.insert(node.clone(), RDF_REST, head);
+                    head = node;
+                }
+                objects.push((head, path.to_string()));
+            }
+            Value::Object(map) => objects.push((self.node(context, map, path)?, path.to_string())),
+            Value::String(text) => {
+                let type_ = definition.and_then(|d| d.type_.as_deref());
+
+                let term = match type_ {
+                    Some("@id") => match text.strip_prefix("_:") {
+                        Some(label) => Term::BlankNode(format!("l{label}")),
+                        None => Term::Iri(context.expand_iri(text, false)),
+                    },
+                    Some("@vocab") => Term::Iri(context.expand_iri(text, true)),
+                    Some(datatype) => Term::literal(text, context.expand_iri(datatype, true)),
+                    None => Term::literal(text, XSD_STRING),
+                };
+                objects.push((term, path.to_string()));
+            }
+            Value::Bool(value) => objects.push((
+                Term::literal(value.to_string(), format!("{XSD}boolean")),
+                path.to_string(),
+            )),
+            Value::Number(number) => objects.push((number_literal(number), path.to_string())),
+        }
+
+        Ok(())
+    }
+
+    fn fresh_blank_node(&mut self) -> Term {
+        self.blank_nodes += 1;
+        Term::BlankNode(format!("b{}", self.blank_nodes))
+    }
+}
+
+fn value_object(context: &Context, map: &Map<String, Value>, path: &str) -> Result<Option<Term>, JsonLdError> {
+    let value = match &map["@value"] {
+        Value::Null => return Ok(None),
+        Value::String(value) => value.clone(),
+        Value::Bool(value) => value.to_string(),
+        Value::Number(value) => value.to_string(),
+        _ => return Err(error(&format!("{path}.@value"), "@value must be a scalar")),
+    };
+
+    if let Some(Value::String(language)) = map.get("@language") {
+        return Ok(Some(Term::lang_literal(value, language.to_lowercase())));
+    }
+
+    let datatype = match (map.get("@type"), &map["@value"]) {
+        (Some(Value::String(datatype)), _) => context.expand_iri(datatype, true),
+        (_, Value::Number(number)) => return Ok(Some(number_literal(number))),
+        (_, Value::Bool(_)) => format!("{XSD}boolean"),
+        _ => XSD_STRING.to_string(),
+    };
+
+    Ok(Some(Term::literal(value, datatype)))
+}
+
+/// A native JSON number as an `xsd:integer`, or as an `xsd:double` in canonical form if it has a fraction.
+fn number_literal(number: &serde_json::Number) -> Term {
+    match number.as_f64() {
+        Some(double) if number.is_f64() && double.fract() != 0.0 => {
+            // `1.5E0` rather than Rust's `1.500000000000000E0`.
+            let formatted = format!("{double:.15E}");
+            let (mantissa, exponent) = formatted.split_once('E').expect("exponent format");
+            let mantissa = mantissa.trim_end_matches('0');
+            let mantissa = mantissa
+                .strip_suffix('.')
+                .map_or(mantissa.to_string(), |m| format!("{m}.0"));
+
+            Term::literal(format!("{mantissa}E{exponent}"), format!("{XSD}double"))
+        }
+        Some(double) if number.is_f64() => Term::literal(format!("{double:.0}"), format!("{XSD}integer")),
+        _ => Term::literal(number.to_string(), format!("{XSD}integer")),
+    }
+}
+
+fn as_slice(value: &Value) -> &[Value] {
+    match value {
+        Value::Array(values) => values,
+        value => std::slice::from_ref(value),
+    }
+}
+
+fn error(path: &str, message: &str) -> JsonLdError {
+    JsonLdError {
+        path: path.to_string(),
+        message: message.to_string(),
+    }
+}
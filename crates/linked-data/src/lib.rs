@@ -0,0 +1,7 @@
+//! The RDF side of the credential models: JSON-LD to RDF with bundled contexts, Turtle and RDF Dataset
+//! Canonicalization. Shared by the validator, which checks the graph against SHACL shapes, and by the proofs of the
+//! credential crates, which sign its canonical form.
+pub mod jsonld;
+pub mod rdf;
+pub mod rdfc;
+pub mod turtle;
@@ -0,0 +1,226 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+pub const XSD: &str = "http://www.w3.org/2001/XMLSchema#";
+
+pub const RDF_TYPE: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#type";
+pub const RDF_FIRST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#first";
+pub const RDF_REST: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#rest";
+pub const RDF_NIL: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#nil";
+pub const RDF_LANG_STRING: &str = "http://www.w3.org/1999/02/22-rdf-syntax-ns#langString";
+pub const RDFS_SUB_CLASS_OF: &str = "http://www.w3.org/2000/01/rdf-schema#subClassOf";
+pub const XSD_STRING: &str = "http://www.w3.org/2001/XMLSchema#string";
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Term {
+    Iri(String),
+    BlankNode(String),
+    Literal(Literal),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Literal {
+    pub value: String,
+    pub datatype: String,
+    pub language: Option<String>,
+}
+
+impl Term {
+    pub fn iri(iri: impl Into<String>) -> Self {
+        Term::Iri(iri.into())
+    }
+
+    pub fn literal(value: impl Into<String>, datatype: impl Into<String>) -> Self {
+        Term::Literal(Literal {
+            value: value.into(),
+            datatype: datatype.into(),
+            language: None,
+        })
+    }
+
+    pub fn lang_literal(value: impl Into<String>, language: impl Into<String>) -> Self {
+        Term::Literal(Literal {
+            value: value.into(),
+            datatype: RDF_LANG_STRING.to_string(),
+            language: Some(language.into()),
+        })
+    }
+
+    pub fn as_iri(&self) -> Option<&str> {
+        match self {
+            Term::Iri(iri) => Some(iri),
+            _ => None,
+        }
+    }
+
+    pub fn as_literal(&self) -> Option<&Literal> {
+        match self {
+            Term::Literal(literal) => Some(literal),
+            _ => None,
+        }
+    }
+
+    pub fn is_iri(&self) -> bool {
+        matches!(self, Term::Iri(_))
+    }
+
+    pub fn is_blank_node(&self) -> bool {
+        matches!(self, Term::BlankNode(_))
+    }
+
+    pub fn is_literal(&self) -> bool {
+        matches!(self, Term::Literal(_))
+    }
+}
+
+impl fmt::Display for Term {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Term::Iri(iri) => write!(f, "<{iri}>"),
+            Term::BlankNode(id) => write!(f, "_:{id}"),
+            Term::Literal(literal) => {
+                write!(f, "{:?}", literal.value)?;
+
+                match &literal.language {
+                    Some(language) => write!(f, "@{language}"),
+                    None if literal.datatype == XSD_STRING => Ok(()),
+                    None => write!(f, "^^<{}>", literal.datatype),
+                }
+            }
+        }
+    }
+}
+
+/// An in-memory RDF graph, indexed by subject and predicate.
+///
+/// Triples are stored as a set, so inserting the same triple twice has no effect.
+#[derive(Debug, Clone, Default)]
+pub struct Graph {
+    subjects: BTreeMap<Term, BTreeMap<String, BTreeSet<Term>>>,
+    len: usize,
+}
+
+impl Graph {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns `false` if the triple was already present.
+    pub fn insert(&mut self, subject: Term, predicate: impl Into<String>, object: Term) -> bool {
+        let inserted = self
+            .subjects
+            .entry(subject)
+            .or_default()
+            .entry(predicate.into())
+            .or_default()
+            .insert(object);
+
+        if inserted {
+            self.len += 1;
+        }
+
+        inserted
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn objects<'a>(&'a self, subject: &Term, predicate: &str) -> impl Iterator<Item = &'a Term> {
+        self.subjects
+            .get(subject)
+            .and_then(|predicates| predicates.get(predicate))
+            .into_iter()
+            .flatten()
+    }
+
+    pub fn object(&self, subject: &Term, predicate: &str) -> Option<&Term> {
+        self.objects(subject, predicate).next()
+    }
+
+    pub fn subjects<'a>(&'a self, predicate: &'a str, object: &'a Term) -> impl Iterator<Item = &'a Term> {
+        self.subjects.iter().filter_map(move |(subject, predicates)| {
+            predicates
+                .get(predicate)
+                .filter(|objects| objects.contains(object))
+                .map(|_| subject)
+        })
+    }
+
+    /// All subjects that have `predicate`, regardless of its value.
+    pub fn subjects_with<'a>(&'a self, predicate: &'a str) -> impl Iterator<Item = &'a Term> {
+        self.subjects
+            .iter()
+            .filter(move |(_, predicates)| predicates.contains_key(predicate))
+            .map(|(subject, _)| subject)
+    }
+
+    /// All objects of `predicate`, regardless of the subject.
+    pub fn objects_of<'a>(&'a self, predicate: &'a str) -> impl Iterator<Item = &'a Term> {
+        self.subjects
+            .values()
+            .filter_map(move |predicates| predicates.get(predicate))
+            .flatten()
+    }
+
+    pub fn triples(&self) -> impl Iterator<Item = (&Term, &str, &Term)> {
+        self.subjects.iter().flat_map(|(subject, predicates)| {
+            predicates.iter().flat_map(move |(predicate, objects)| {
+                objects.iter().map(move |object| (subject, predicate.as_str(), object))
+            })
+        })
+    }
+
+    /// Reads an RDF list starting at `head`, returns `None` if the list is malformed.
+    pub fn list(&self, head: &Term) -> Option<Vec<Term>> {
+        let mut items = vec![];
+        let mut node = head.clone();
+
+        while node.as_iri() != Some(RDF_NIL) {
+            items.push(self.object(&node, RDF_FIRST)?.clone());
+            node = self.object(&node, RDF_REST)?.clone();
+
+            if items.len() > self.len {
+                return None;
+            }
+        }
+
+        Some(items)
+    }
+
+    /// Whether `node` is a SHACL instance of `class`, following `rdfs:subClassOf` in this graph.
+    pub fn is_instance_of(&self, node: &Term, class: &str) -> bool {
+        self.objects(node, RDF_TYPE)
+            .any(|type_| self.is_subclass_of(type_, class))
+    }
+
+    pub fn is_subclass_of(&self, class: &Term, superclass: &str) -> bool {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![class];
+
+        while let Some(class) = stack.pop() {
+            if class.as_iri() == Some(superclass) {
+                return true;
+            }
+
+            if visited.insert(class) {
+                stack.extend(self.objects(class, RDFS_SUB_CLASS_OF));
+            }
+        }
+
+        false
+    }
+
+    /// All instances of `class`, including instances of its subclasses.
+    pub fn instances_of(&self, class: &str) -> BTreeSet<&Term> {
+        self.subjects_with(RDF_TYPE)
+            .filter(|node| self.is_instance_of(node, class))
+            .collect()
+    }
+}
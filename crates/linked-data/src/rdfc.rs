@@ -0,0 +1,392 @@
+//! RDF Dataset Canonicalization ([RDFC-1.0](https://www.w3.org/TR/rdf-canon/)), which is URDNA2015 under its
+//! standard name.
+//!
+//! The canonical N-Quads of a JSON-LD document are what `eddsa-rdfc-2022` and `JsonWebSignature2020` proofs hash.
+//! Blank nodes get the labels `_:c14n0`, `_:c14n1`, … from the structure of the graph, so two documents that only
+//! differ in blank node labels or in the order of their statements are canonicalized to the same string.
+//!
+//! ```ignore
+//! let rdfc = Rdfc::new().with_context(OB_CONTEXT_URL, ob_context);
+//! let nquads = rdfc.canonicalize(&credential)?;
+//! ```
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    jsonld::{self, JsonLdError, JsonLdOptions},
+    rdf::{Graph, Term, XSD_STRING},
+};
+
+/// The number of Hash N-Degree Quads calls after which a graph is rejected, RDFC-1.0 needs to bound the work on
+/// graphs crafted to make the permutations explode.
+const MAX_N_DEGREE_CALLS: usize = 10_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RdfcError {
+    JsonLd(JsonLdError),
+    /// The blank nodes of the graph need too much work to tell apart.
+    TooComplex,
+}
+
+impl fmt::Display for RdfcError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RdfcError::JsonLd(err) => write!(f, "{err}"),
+            RdfcError::TooComplex => write!(f, "Graph is too complex to canonicalize"),
+        }
+    }
+}
+
+impl std::error::Error for RdfcError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RdfcError::JsonLd(err) => Some(err),
+            RdfcError::TooComplex => None,
+        }
+    }
+}
+
+impl From<JsonLdError> for RdfcError {
+    fn from(err: JsonLdError) -> Self {
+        RdfcError::JsonLd(err)
+    }
+}
+
+/// Canonicalizes JSON-LD documents. The conversion to RDF runs in safe mode: a document with a remote context that
+/// is neither bundled nor supplied, or with a property the contexts don't define, fails instead of being signed or
+/// verified without it.
+#[derive(Debug, Clone)]
+pub struct Rdfc {
+    options: JsonLdOptions,
+}
+
+impl Default for Rdfc {
+    fn default() -> Self {
+        Self {
+            options: JsonLdOptions {
+                contexts: HashMap::new(),
+                safe: true,
+            },
+        }
+    }
+}
+
+impl Rdfc {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Supplies the document of a remote context, e.g. the OBv3 context. It takes precedence over the bundled one.
+    pub fn with_context(mut self, url: impl Into<String>, document: Value) -> Self {
+        self.options.contexts.insert(url.into(), document);
+        self
+    }
+
+    /// The canonical N-Quads of a JSON-LD document.
+    pub fn canonicalize(&self, document: &Value) -> Result<String, RdfcError> {
+        canonicalize(&jsonld::to_rdf_with_options(document, &self.options)?)
+    }
+}
+
+/// The canonical N-Quads of `graph`, one statement per line in code point order.
+pub fn canonicalize(graph: &Graph) -> Result<String, RdfcError> {
+    let mut state = State::new(graph);
+    state.label()?;
+
+    let mut lines: Vec<String> = graph
+        .triples()
+        .map(|(subject, predicate, object)| {
+            let label = |term: &Term| match term {
+                Term::BlankNode(id) => Term::BlankNode(state.canonical.issued[id].clone()),
+                term => term.clone(),
+            };
+            nquad(&label(subject), predicate, &label(object))
+        })
+        .collect();
+    lines.sort_unstable();
+
+    Ok(lines.concat())
+}
+
+struct State<'a> {
+    /// The statements each blank node appears in.
+    quads: BTreeMap<&'a str, Vec<(&'a Term, &'a str, &'a Term)>>,
+    canonical: IdentifierIssuer,
+    n_degree_calls: usize,
+}
+
+impl<'a> State<'a> {
+    fn new(graph: &'a Graph) -> Self {
+        let mut quads: BTreeMap<&str, Vec<_>> = BTreeMap::new();
+
+        for (subject, predicate, object) in graph.triples() {
+            for term in [subject, object] {
+                if let Term::BlankNode(id) = term {
+                    let statements = quads.entry(id.as_str()).or_default();
+                    // A blank node that is both subject and object of a statement appears in it once.
+                    if statements.last() != Some(&(subject, predicate, object)) {
+                        statements.push((subject, predicate, object));
+                    }
+                }
+            }
+        }
+
+        Self {
+            quads,
+            canonical: IdentifierIssuer::new("c14n"),
+            n_degree_calls: 0,
+        }
+    }
+
+    /// Issues the canonical labels, first to the blank nodes with a unique first degree hash, then to the others in
+    /// the order of their N-degree hashes.
+    fn label(&mut self) -> Result<(), RdfcError> {
+        let mut hashes: BTreeMap<String, Vec<&'a str>> = BTreeMap::new();
+        for &id in self.quads.keys() {
+            hashes.entry(self.hash_first_degree(id)).or_default().push(id);
+        }
+
+        let mut shared = vec![];
+        for ids in hashes.into_values() {
+            match ids[..] {
+                [id] => {
+                    self.canonical.issue(id);
+                }
+                _ => shared.push(ids),
+            }
+        }
+
+        for ids in shared {
+            let mut results = vec![];
+
+            for id in ids {
+                if self.canonical.has(id) {
+                    continue;
+                }
+
+                let mut issuer = IdentifierIssuer::new("b");
+                issuer.issue(id);
+                results.push(self.hash_n_degree(id, issuer)?);
+            }
+
+            results.sort_by(|(a, _), (b, _)| a.cmp(b));
+            for (_, issuer) in results {
+                for (id, _) in issuer.order {
+                    self.canonical.issue(&id);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The hash of the statements of `id`, with `id` labelled `_:a` and every other blank node `_:z`.
+    fn hash_first_degree(&self, id: &str) -> String {
+        let label = |term: &Term| match term {
+            Term::BlankNode(other) if other == id => Term::BlankNode("a".to_string()),
+            Term::BlankNode(_) => Term::BlankNode("z".to_string()),
+            term => term.clone(),
+        };
+
+        let mut lines: Vec<String> = self.quads[id]
+            .iter()
+            .map(|(subject, predicate, object)| nquad(&label(subject), predicate, &label(object)))
+            .collect();
+        lines.sort_unstable();
+
+        sha256_hex(lines.concat())
+    }
+
+    fn hash_related(&self, related: &str, predicate: &str, position: char, issuer: &IdentifierIssuer) -> String {
+        let id = match (self.canonical.issued.get(related), issuer.issued.get(related)) {
+            (Some(id), _) | (None, Some(id)) => format!("_:{id}"),
+            (None, None) => self.hash_first_degree(related),
+        };
+
+        sha256_hex(format!("{position}<{predicate}>{id}"))
+    }
+
+    /// Tells apart blank nodes with the same first degree hash by the paths to their neighbours, returns the hash and
+    /// the labels issued on the chosen path.
+    fn hash_n_degree(
+        &mut self,
+        id: &str,
+        mut issuer: IdentifierIssuer,
+    ) -> Result<(String, IdentifierIssuer), RdfcError> {
+        self.n_degree_calls += 1;
+        if self.n_degree_calls > MAX_N_DEGREE_CALLS {
+            return Err(RdfcError::TooComplex);
+        }
+
+        let mut related_by_hash: BTreeMap<String, Vec<&'a str>> = BTreeMap::new();
+        for &(subject, predicate, object) in &self.quads[id] {
+            for (term, position) in [(subject, 's'), (object, 'o')] {
+                if let Term::BlankNode(related) = term {
+                    if related != id {
+                        let hash = self.hash_related(related, predicate, position, &issuer);
+                        related_by_hash.entry(hash).or_default().push(related.as_str());
+                    }
+                }
+            }
+        }
+
+        let mut data = String::new();
+
+        for (hash, related) in related_by_hash {
+            data.push_str(&hash);
+
+            let mut chosen: Option<(String, IdentifierIssuer)> = None;
+
+            'permutations: for permutation in permutations(&related) {
+                let mut path_issuer = issuer.clone();
+                let mut path = String::new();
+                let mut recursion = vec![];
+
+                for &node in &permutation {
+                    match self.canonical.issued.get(node) {
+                        Some(label) => path.push_str(&format!("_:{label}")),
+                        None => {
+                            if !path_issuer.has(node) {
+                                recursion.push(node);
+                            }
+                            path.push_str(&format!("_:{}", path_issuer.issue(node)));
+                        }
+                    }
+
+                    if is_worse(&path, chosen.as_ref()) {
+                        continue 'permutations;
+                    }
+                }
+
+                for node in recursion {
+                    let (hash, result_issuer) = self.hash_n_degree(node, path_issuer.clone())?;
+                    path_issuer = result_issuer;
+                    path.push_str(&format!("_:{}<{hash}>", path_issuer.issue(node)));
+
+                    if is_worse(&path, chosen.as_ref()) {
+                        continue 'permutations;
+                    }
+                }
+
+                if chosen.as_ref().is_none_or(|(chosen, _)| path < *chosen) {
+                    chosen = Some((path, path_issuer));
+                }
+            }
+
+            let (path, path_issuer) = chosen.expect("at least one permutation");
+            data.push_str(&path);
+            issuer = path_issuer;
+        }
+
+        Ok((sha256_hex(data), issuer))
+    }
+}
+
+/// Whether `path` can't become smaller than the chosen path anymore.
+fn is_worse(path: &str, chosen: Option<&(String, IdentifierIssuer)>) -> bool {
+    chosen.is_some_and(|(chosen, _)| path.len() >= chosen.len() && path > chosen.as_str())
+}
+
+/// Issues labels with a prefix and a counter, remembering the order they were issued in.
+#[derive(Debug, Clone)]
+struct IdentifierIssuer {
+    prefix: &'static str,
+    issued: HashMap<String, String>,
+    order: Vec<(String, String)>,
+}
+
+impl IdentifierIssuer {
+    fn new(prefix: &'static str) -> Self {
+        Self {
+            prefix,
+            issued: HashMap::new(),
+            order: vec![],
+        }
+    }
+
+    fn has(&self, id: &str) -> bool {
+        self.issued.contains_key(id)
+    }
+
+    fn issue(&mut self, id: &str) -> String {
+        if let Some(label) = self.issued.get(id) {
+            return label.clone();
+        }
+
+        let label = format!("{}{}", self.prefix, self.order.len());
+        self.issued.insert(id.to_string(), label.clone());
+        self.order.push((id.to_string(), label.clone()));
+        label
+    }
+}
+
+fn permutations<'a>(items: &[&'a str]) -> Vec<Vec<&'a str>> {
+    if items.len() <= 1 {
+        return vec![items.to_vec()];
+    }
+
+    let mut result = vec![];
+    for i in 0..items.len() {
+        let mut rest = items.to_vec();
+        let first = rest.remove(i);
+
+        for mut permutation in permutations(&rest) {
+            permutation.insert(0, first);
+            result.push(permutation);
+        }
+    }
+
+    result
+}
+
+/// A statement of the default graph in canonical N-Quads form, including the line break.
+fn nquad(subject: &Term, predicate: &str, object: &Term) -> String {
+    format!("{} <{predicate}> {} .\n", term(subject), term(object))
+}
+
+fn term(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => format!("<{iri}>"),
+        Term::BlankNode(id) => format!("_:{id}"),
+        Term::Literal(literal) => {
+            let value = escape(&literal.value);
+
+            match &literal.language {
+                Some(language) => format!("\"{value}\"@{language}"),
+                None if literal.datatype == XSD_STRING => format!("\"{value}\""),
+                None => format!("\"{value}\"^^<{}>", literal.datatype),
+            }
+        }
+    }
+}
+
+/// Escapes a literal like canonical N-Quads, only the characters that must be escaped are.
+fn escape(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+
+    for c in value.chars() {
+        match c {
+            '\u{8}' => escaped.push_str("\\b"),
+            '\t' => escaped.push_str("\\t"),
+            '\n' => escaped.push_str("\\n"),
+            '\u{c}' => escaped.push_str("\\f"),
+            '\r' => escaped.push_str("\\r"),
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\u{0}'..='\u{1f}' | '\u{7f}' => escaped.push_str(&format!("\\u{:04X}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+fn sha256_hex(data: impl AsRef<[u8]>) -> String {
+    Sha256::digest(data).iter().map(|byte| format!("{byte:02x}")).collect()
+}
@@ -0,0 +1,520 @@
+//! A Turtle parser, sufficient for the SHACL shapes shipped with this crate.
+//!
+//! Supports prefix and base directives, prefixed names, blank node property lists, collections and all
+//! literal forms. Relative IRIs are resolved by appending them to the base IRI.
+
+use std::{collections::HashMap, fmt};
+
+use crate::rdf::{Graph, Term, RDF_FIRST, RDF_NIL, RDF_REST, RDF_TYPE, XSD, XSD_STRING};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+pub fn parse(input: &str) -> Result<Graph, ParseError> {
+    let mut parser = Parser {
+        chars: input.chars().collect(),
+        pos: 0,
+        prefixes: HashMap::new(),
+        base: String::new(),
+        blank_nodes: 0,
+        graph: Graph::new(),
+    };
+
+    parser.document()?;
+
+    Ok(parser.graph)
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+    prefixes: HashMap<String, String>,
+    base: String,
+    blank_nodes: usize,
+    graph: Graph,
+}
+
+impl Parser {
+    fn document(&mut self) -> Result<(), ParseError> {
+        loop {
+            self.skip_whitespace();
+
+            if self.peek().is_none() {
+                return Ok(());
+            }
+
+            if self.eat_keyword("@prefix") {
+                self.prefix()?;
+                self.expect('.')?;
+            } else if self.eat_keyword("@base") {
+                self.base = self.iri_ref()?;
+                self.expect('.')?;
+            } else if self.eat_keyword_ignore_case("PREFIX") {
+                self.prefix()?;
+            } else if self.eat_keyword_ignore_case("BASE") {
+                self.base = self.iri_ref()?;
+            } else {
+                self.triples()?;
+                self.expect('.')?;
+            }
+        }
+    }
+
+    fn prefix(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+
+        let start = self.pos;
+        while let Some(c) = self.peek() {
+            if c == ':' {
+                break;
+            }
+            if !is_name_char(c) {
+                return Err(self.error(format!("Unexpected character {c:?} in prefix")));
+            }
+            self.pos += 1;
+        }
+
+        let prefix: String = self.chars[start..self.pos].iter().collect();
+        self.expect(':')?;
+
+        let iri = self.iri_ref()?;
+        self.prefixes.insert(prefix, iri);
+
+        Ok(())
+    }
+
+    fn triples(&mut self) -> Result<(), ParseError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('[') {
+            let subject = self.blank_node_property_list()?;
+            self.skip_whitespace();
+
+            if self.peek() != Some('.') {
+                self.predicate_object_list(&subject)?;
+            }
+
+            return Ok(());
+        }
+
+        let subject = self.subject()?;
+        self.predicate_object_list(&subject)
+    }
+
+    fn subject(&mut self) -> Result<Term, ParseError> {
+        match self.peek() {
+            Some('(') => self.collection(),
+            Some('_') => self.blank_node_label(),
+            _ => self.iri().map(Term::Iri),
+        }
+    }
+
+    fn predicate_object_list(&mut self, subject: &Term) -> Result<(), ParseError> {
+        loop {
+            let predicate = self.verb()?;
+            self.object_list(subject, &predicate)?;
+
+            self.skip_whitespace();
+            if !self.eat(';') {
+                return Ok(());
+            }
+
+            // Repeated and trailing semicolons are allowed.
+            loop {
+                self.skip_whitespace();
+                if !self.eat(';') {
+                    break;
+                }
+            }
+
+            if matches!(self.peek(), Some('.') | Some(']') | None) {
+                return Ok(());
+            }
+        }
+    }
+
+    fn verb(&mut self) -> Result<String, ParseError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('a') && self.peek_at(1).is_none_or(|c| c.is_whitespace() || c == '<') {
+            self.pos += 1;
+            return Ok(RDF_TYPE.to_string());
+        }
+
+        self.iri()
+    }
+
+    fn object_list(&mut self, subject: &Term, predicate: &str) -> Result<(), ParseError> {
+        loop {
+            let object = self.object()?;
+            self.graph.insert(subject.clone(), predicate, object);
+
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(());
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Term, ParseError> {
+        self.skip_whitespace();
+
+        match self.peek() {
+            Some('[') => self.blank_node_property_list(),
+            Some('(') => self.collection(),
+            Some('_') if self.peek_at(1) == Some(':') => self.blank_node_label(),
+            Some('"') | Some('\'') => self.string_literal(),
+            Some(c) if c.is_ascii_digit() || c == '+' || c == '-' || c == '.' => self.numeric_literal(),
+            _ if self.eat_keyword("true") => Ok(Term::literal("true", format!("{XSD}boolean"))),
+            _ if self.eat_keyword("false") => Ok(Term::literal("false", format!("{XSD}boolean"))),
+            _ => self.iri().map(Term::Iri),
+        }
+    }
+
+    fn blank_node_property_list(&mut self) -> Result<Term, ParseError> {
+        self.expect('[')?;
+        let node = self.fresh_blank_node();
+
+        self.skip_whitespace();
+        if !self.eat(']') {
+            self.predicate_object_list(&node)?;
+            self.expect(']')?;
+        }
+
+        Ok(node)
+    }
+
+    fn collection(&mut self) -> Result<Term, ParseError> {
+        self.expect('(')?;
+
+        let mut items = vec![];
+        loop {
+            self.skip_whitespace();
+            if self.eat(')') {
+                break;
+            }
+            items.push(self.object()?);
+        }
+
+        let mut head = Term::iri(RDF_NIL);
+        for item in items.into_iter().rev() {
+            let node = self.fresh_blank_node();
+            self.graph.insert(node.clone(), RDF_FIRST, item);
+            self.graph.insert(node.clone(), RDF_REST, head);
+            head = node;
+        }
+
+        Ok(head)
+    }
+
+    fn blank_node_label(&mut self) -> Result<Term, ParseError> {
+        self.expect('_')?;
+        self.expect(':')?;
+
+        let start = self.pos;
+        while self.peek().is_some_and(is_name_char) {
+            self.pos += 1;
+        }
+        // A trailing dot ends the statement, it is not part of the label.
+        while self.pos > start && self.chars[self.pos - 1] == '.' {
+            self.pos -= 1;
+        }
+
+        if start == self.pos {
+            return Err(self.error("Empty blank node label"));
+        }
+
+        let label: String = self.chars[start..self.pos].iter().collect();
+        Ok(Term::BlankNode(format!("l{label}")))
+    }
+
+    fn fresh_blank_node(&mut self) -> Term {
+        self.blank_nodes += 1;
+        Term::BlankNode(format!("b{}", self.blank_nodes))
+    }
+
+    fn iri(&mut self) -> Result<String, ParseError> {
+        self.skip_whitespace();
+
+        if self.peek() == Some('<') {
+            return self.iri_ref();
+        }
+
+        let start = self.pos;
+        while self
+            .peek()
+            .is_some_and(|c| is_name_char(c) || c == ':' || c == '%' || c == '\\')
+        {
+            if self.peek() == Some('\\') {
+                self.pos += 1;
+            }
+            self.pos += 1;
+        }
+        while self.pos > start && self.chars[self.pos - 1] == '.' {
+            self.pos -= 1;
+        }
+
+        let name: String = self.chars[start..self.pos].iter().collect();
+
+        let Some((prefix, local)) = name.split_once(':') else {
+            self.pos = start;
+            return Err(self.error(format!("Expected an IRI, found {:?}", self.peek().unwrap_or(' '))));
+        };
+
+        let Some(namespace) = self.prefixes.get(prefix) else {
+            return Err(self.error(format!("Undefined prefix {prefix:?}")));
+        };
+
+        Ok(format!("{namespace}{}", local.replace('\\', "")))
+    }
+
+    fn iri_ref(&mut self) -> Result<String, ParseError> {
+        self.skip_whitespace();
+        self.expect('<')?;
+
+        let mut iri = String::new();
+        loop {
+            match self.next() {
+                Some('>') => break,
+                Some('\\') => iri.push(self.unicode_escape()?),
+                Some(c) if c.is_whitespace() => return Err(self.error("Whitespace in IRI")),
+                Some(c) => iri.push(c),
+                None => return Err(self.error("Unterminated IRI")),
+            }
+        }
+
+        if is_absolute(&iri) {
+            Ok(iri)
+        } else {
+            Ok(format!("{}{iri}", self.base))
+        }
+    }
+
+    fn string_literal(&mut self) -> Result<Term, ParseError> {
+        let quote = self.next().ok_or_else(|| self.error("Expected a string"))?;
+        let long = self.peek() == Some(quote) && self.peek_at(1) == Some(quote);
+
+        if long {
+            self.pos += 2;
+        }
+
+        let mut value = String::new();
+        loop {
+            match self.next() {
+                Some(c) if c == quote && !long => break,
+                Some(c) if c == quote && self.peek() == Some(quote) && self.peek_at(1) == Some(quote) => {
+                    self.pos += 2;
+                    // Quotes directly before the closing delimiter belong to the string.
+                    while self.peek() == Some(quote) {
+                        value.push(quote);
+                        self.pos += 1;
+                    }
+                    break;
+                }
+                Some('\\') => value.push(self.string_escape()?),
+                Some('\n') | Some('\r') if !long => return Err(self.error("Line break in string")),
+                Some(c) => value.push(c),
+                None => return Err(self.error("Unterminated string")),
+            }
+        }
+
+        if self.eat('@') {
+            let start = self.pos;
+            while self.peek().is_some_and(|c| c.is_ascii_alphanumeric() || c == '-') {
+                self.pos += 1;
+            }
+            let language: String = self.chars[start..self.pos].iter().collect();
+            return Ok(Term::lang_literal(value, language.to_lowercase()));
+        }
+
+        if self.peek() == Some('^') && self.peek_at(1) == Some('^') {
+            self.pos += 2;
+            let datatype = self.iri()?;
+            return Ok(Term::literal(value, datatype));
+        }
+
+        Ok(Term::literal(value, XSD_STRING))
+    }
+
+    fn numeric_literal(&mut self) -> Result<Term, ParseError> {
+        let start = self.pos;
+
+        if matches!(self.peek(), Some('+') | Some('-')) {
+            self.pos += 1;
+        }
+
+        let mut datatype = "integer";
+        while let Some(c) = self.peek() {
+            match c {
+                '0'..='9' => {}
+                '.' if datatype == "integer" && self.peek_at(1).is_some_and(|c| c.is_ascii_digit()) => {
+                    datatype = "decimal"
+                }
+                'e' | 'E' => {
+                    datatype = "double";
+                    if matches!(self.peek_at(1), Some('+') | Some('-')) {
+                        self.pos += 1;
+                    }
+                }
+                _ => break,
+            }
+            self.pos += 1;
+        }
+
+        let value: String = self.chars[start..self.pos].iter().collect();
+
+        if !value.chars().any(|c| c.is_ascii_digit()) {
+            return Err(self.error(format!("Invalid number {value:?}")));
+        }
+
+        Ok(Term::literal(value, format!("{XSD}{datatype}")))
+    }
+
+    fn string_escape(&mut self) -> Result<char, ParseError> {
+        match self.peek() {
+            Some('u') | Some('U') => self.unicode_escape(),
+            Some(c) => {
+                self.pos += 1;
+                match c {
+                    't' => Ok('\t'),
+                    'b' => Ok('\u{8}'),
+                    'n' => Ok('\n'),
+                    'r' => Ok('\r'),
+                    'f' => Ok('\u{c}'),
+                    '"' | '\'' | '\\' => Ok(c),
+                    _ => Err(self.error(format!("Invalid escape sequence \\{c}"))),
+                }
+            }
+            None => Err(self.error("Unterminated escape sequence")),
+        }
+    }
+
+    fn unicode_escape(&mut self) -> Result<char, ParseError> {
+        let length = match self.next() {
+            Some('u') => 4,
+            Some('U') => 8,
+            _ => return Err(self.error("Invalid escape sequence")),
+        };
+
+        if self.pos + length > self.chars.len() {
+            return Err(self.error("Unterminated escape sequence"));
+        }
+
+        let hex: String = self.chars[self.pos..self.pos + length].iter().collect();
+        self.pos += length;
+
+        u32::from_str_radix(&hex, 16)
+            .ok()
+            .and_then(char::from_u32)
+            .ok_or_else(|| self.error(format!("Invalid unicode escape {hex:?}")))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if c == '#' {
+                while self.peek().is_some_and(|c| c != '\n') {
+                    self.pos += 1;
+                }
+            } else if c.is_whitespace() {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).copied()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.pos += 1;
+        c
+    }
+
+    fn eat(&mut self, expected: char) -> bool {
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        self.skip_whitespace();
+
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            let found = self.peek().map_or("end of input".to_string(), |c| format!("{c:?}"));
+            Err(self.error(format!("Expected {expected:?}, found {found}")))
+        }
+    }
+
+    fn eat_keyword(&mut self, keyword: &str) -> bool {
+        self.eat_keyword_with(keyword, |a, b| a == b)
+    }
+
+    fn eat_keyword_ignore_case(&mut self, keyword: &str) -> bool {
+        self.eat_keyword_with(keyword, |a, b| a.eq_ignore_ascii_case(&b))
+    }
+
+    fn eat_keyword_with(&mut self, keyword: &str, eq: impl Fn(char, char) -> bool) -> bool {
+        let length = keyword.chars().count();
+        let matches = self.pos + length <= self.chars.len()
+            && self.chars[self.pos..self.pos + length]
+                .iter()
+                .zip(keyword.chars())
+                .all(|(&a, b)| eq(a, b))
+            && !self.peek_at(length).is_some_and(|c| is_name_char(c) || c == ':');
+
+        if matches {
+            self.pos += length;
+        }
+
+        matches
+    }
+
+    fn error(&self, message: impl Into<String>) -> ParseError {
+        let consumed = &self.chars[..self.pos.min(self.chars.len())];
+        let line = consumed.iter().filter(|&&c| c == '\n').count() + 1;
+        let column = consumed.iter().rev().take_while(|&&c| c != '\n').count() + 1;
+
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '-' | '.' | '\u{b7}')
+}
+
+fn is_absolute(iri: &str) -> bool {
+    iri.split_once(':').is_some_and(|(scheme, _)| {
+        !scheme.is_empty()
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+    })
+}
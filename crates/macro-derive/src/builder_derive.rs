@@ -0,0 +1,177 @@
+use quote::{format_ident, quote};
+
+/// Generates a builder like the ones in `types-ob-v3`: every field is a `Result`, required fields start as an error
+/// and `TryFrom<Builder>` fails with the first missing or invalid field.
+pub fn impl_builder(input: syn::DeriveInput) -> syn::Result<proc_macro::TokenStream> {
+    let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Named(fields),
+        ..
+    }) = &input.data
+    else {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "Builder only works on structs with named fields",
+        ));
+    };
+
+    let name = &input.ident;
+    let builder = format_ident!("{}Builder", name);
+
+    let mut builder_fields = vec![];
+    let mut defaults = vec![];
+    let mut setters = vec![];
+    let mut build_fields = vec![];
+    let mut from_fields = vec![];
+
+    for field in fields.named.iter() {
+        let ident = field.ident.as_ref().expect("named field");
+        let ty = &field.ty;
+
+        builder_fields.push(quote! { #ident: Result<#ty, String>, });
+
+        let default = match default_kind(ty) {
+            DefaultKind::Default => quote! { Ok(Default::default()) },
+            DefaultKind::OneTag => quote! { Ok(types_common::OneOrMany::One(Box::default())) },
+            DefaultKind::Required => {
+                let message = format!("no value supplied for {ident}");
+                quote! { Err(#message.to_string()) }
+            }
+        };
+        defaults.push(quote! { #ident: #default, });
+
+        let message = format!("error converting supplied value for {ident}: {{}}");
+        setters.push(quote! {
+            pub fn #ident<T>(mut self, value: T) -> Self
+            where
+                T: std::convert::TryInto<#ty>,
+                T::Error: std::fmt::Display,
+            {
+                self.#ident = value.try_into().map_err(|e| format!(#message, e));
+                self
+            }
+        });
+
+        build_fields.push(quote! { #ident: value.#ident?, });
+        from_fields.push(quote! { #ident: Ok(value.#ident), });
+    }
+
+    let doc = format!("Builder for [`{name}`], the type tags are filled in and required fields are checked on build.");
+
+    let expand = quote! {
+        #[doc = #doc]
+        #[derive(Clone, Debug)]
+        pub struct #builder {
+            #(#builder_fields)*
+        }
+
+        impl Default for #builder {
+            fn default() -> Self {
+                Self {
+                    #(#defaults)*
+                }
+            }
+        }
+
+        impl #builder {
+            #(#setters)*
+        }
+
+        impl std::convert::TryFrom<#builder> for #name {
+            type Error = String;
+
+            fn try_from(value: #builder) -> Result<Self, String> {
+                Ok(Self {
+                    #(#build_fields)*
+                })
+            }
+        }
+
+        impl From<#name> for #builder {
+            fn from(value: #name) -> Self {
+                Self {
+                    #(#from_fields)*
+                }
+            }
+        }
+
+        // Lets a builder be passed to the setter of an optional or repeated field.
+        impl std::convert::TryFrom<#builder> for Option<#name> {
+            type Error = String;
+
+            fn try_from(value: #builder) -> Result<Self, String> {
+                #name::try_from(value).map(Some)
+            }
+        }
+
+        impl std::convert::TryFrom<#builder> for types_common::OneOrMany<#name> {
+            type Error = String;
+
+            fn try_from(value: #builder) -> Result<Self, String> {
+                #name::try_from(value).map(types_common::OneOrMany::from)
+            }
+        }
+
+        impl std::convert::TryFrom<#builder> for Option<types_common::OneOrMany<#name>> {
+            type Error = String;
+
+            fn try_from(value: #builder) -> Result<Self, String> {
+                #name::try_from(value).map(|value| Some(types_common::OneOrMany::from(value)))
+            }
+        }
+
+        impl From<#name> for Option<types_common::OneOrMany<#name>> {
+            fn from(value: #name) -> Self {
+                Some(types_common::OneOrMany::from(value))
+            }
+        }
+    };
+
+    Ok(expand.into())
+}
+
+enum DefaultKind {
+    /// Optional and repeated fields and type tags.
+    Default,
+    /// A `OneOrMany` of type tags.
+    OneTag,
+    Required,
+}
+
+fn default_kind(ty: &syn::Type) -> DefaultKind {
+    let Some(segment) = last_segment(ty) else {
+        return DefaultKind::Required;
+    };
+
+    let type_name = segment.ident.to_string();
+
+    if type_name == "Option" || type_name == "Vec" || type_name == "Map" || type_name.ends_with("Tag") {
+        return DefaultKind::Default;
+    }
+
+    if type_name == "OneOrMany" && first_argument(segment).is_some_and(|ty| is_tag(&ty)) {
+        return DefaultKind::OneTag;
+    }
+
+    DefaultKind::Required
+}
+
+fn is_tag(ty: &syn::Type) -> bool {
+    last_segment(ty).is_some_and(|segment| segment.ident.to_string().ends_with("Tag"))
+}
+
+fn last_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(path_type) => path_type.path.segments.last(),
+        _ => None,
+    }
+}
+
+fn first_argument(segment: &syn::PathSegment) -> Option<syn::Type> {
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) => args.args.iter().find_map(|arg| match arg {
+            syn::GenericArgument::Type(ty) => Some(ty.clone()),
+            _ => None,
+        }),
+        _ => None,
+    }
+}
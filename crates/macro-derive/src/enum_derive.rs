@@ -9,6 +9,7 @@ pub fn impl_enum_deserialize(input: proc_macro::TokenStream) -> proc_macro::Toke
 
     let mut variants: Vec<proc_macro2::TokenStream> = vec![];
     let mut variant_names: Vec<String> = vec![];
+    let mut fallback: Option<syn::Ident> = None;
 
     fn invalid_enum() {
         panic!("EnumDeserialize may only have one unnamed field");
@@ -25,6 +26,12 @@ pub fn impl_enum_deserialize(input: proc_macro::TokenStream) -> proc_macro::Toke
                 _ => invalid_enum(),
             }
 
+            // `#[enum_deserialize(other)]` marks the variant that takes objects of any other type.
+            if variant.attrs.iter().any(is_other) {
+                fallback = Some(variant.ident.clone());
+                continue;
+            }
+
             let variant = &variant.ident;
 
             // Deserialize the object if matches the tag
@@ -58,6 +65,20 @@ pub fn impl_enum_deserialize(input: proc_macro::TokenStream) -> proc_macro::Toke
     let choices = create_choices(&variant_names);
     let parse_variants = proc_macro2::TokenStream::from_iter(variants);
 
+    // Objects with an unknown or non-string type go to the fallback variant, if there is one.
+    let (unknown_variant, invalid_tag) = match fallback {
+        Some(fallback) => {
+            let fallback = quote! {
+                Ok(Self::#fallback(serde_json::from_value(serde_value).map_err(::serde::de::Error::custom)?))
+            };
+            (fallback.clone(), fallback)
+        }
+        None => (
+            quote! { Err(::serde::de::Error::unknown_variant(&tag, #choices)) },
+            quote! { Err(::serde::de::Error::missing_field("type")) },
+        ),
+    };
+
     // This will add the trait to the struct.
     let expanded = quote! {
         impl<'de> ::serde::Deserialize<'de> for #name {
@@ -77,9 +98,9 @@ pub fn impl_enum_deserialize(input: proc_macro::TokenStream) -> proc_macro::Toke
                     // Deserialize the correct variant
                     #parse_variants
 
-                    Err(::serde::de::Error::unknown_variant(&tag, #choices))
+                    #unknown_variant
                 } else {
-                    Err(::serde::de::Error::missing_field("type"))
+                    #invalid_tag
                 }
             }
         }
@@ -87,3 +108,17 @@ pub fn impl_enum_deserialize(input: proc_macro::TokenStream) -> proc_macro::Toke
 
     expanded.into()
 }
+
+pub(crate) fn is_other(attr: &syn::Attribute) -> bool {
+    if !attr.path().is_ident("enum_deserialize") {
+        return false;
+    }
+
+    let mut other = false;
+    let _ = attr.parse_nested_meta(|meta| {
+        other |= meta.path.is_ident("other");
+        Ok(())
+    });
+
+    other
+}
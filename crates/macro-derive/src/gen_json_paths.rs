@@ -165,6 +165,7 @@ fn handle_struct_fields(
     for field in fields.named.iter() {
         if let Some(field_ident) = &field.ident {
             let mut field_name = field_ident.to_string();
+            let mut flatten = false;
 
             if rename_cc {
                 field_name = field_name.to_case(Case::Camel)
@@ -178,6 +179,8 @@ fn handle_struct_fields(
                             let value = meta.value()?;
                             let s: syn::LitStr = value.parse()?;
                             field_name = s.value();
+                        } else if meta.path.is_ident("flatten") {
+                            flatten = true;
                         }
 
                         Ok(())
@@ -185,6 +188,11 @@ fn handle_struct_fields(
                 }
             }
 
+            // A flattened map has no path of its own.
+            if flatten {
+                continue;
+            }
+
             add_schema_data(&mut ctx, src_schema, field_name, &field.ty);
         }
     }
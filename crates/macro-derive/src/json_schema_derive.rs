@@ -0,0 +1,266 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::enum_derive::is_other;
+
+/// Generates `types_common::JsonSchema` from the shape of the type and its serde attributes.
+///
+/// Structs become objects, newtype structs the schema of their field, untagged enums a `oneOf` of their variants
+/// and other enums a string `enum` of their unit variants or a `oneOf` of externally tagged variants.
+pub fn impl_json_schema(input: syn::DeriveInput) -> syn::Result<proc_macro::TokenStream> {
+    let name = &input.ident;
+    let container = SerdeAttrs::parse(&input.attrs)?;
+
+    let schema = match &input.data {
+        syn::Data::Struct(data) => struct_schema(&data.fields, &container)?,
+        syn::Data::Enum(data) => enum_schema(data, &container)?,
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new_spanned(name, "JsonSchema doesn't work on unions"));
+        }
+    };
+    let schema = describe(schema, &input.attrs);
+
+    // Generic types are inlined, their definition differs per type argument.
+    let schema_name = if input.generics.params.is_empty() {
+        quote! { Some(stringify!(#name)) }
+    } else {
+        quote! { None }
+    };
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let expand = quote! {
+        impl #impl_generics types_common::JsonSchema for #name #ty_generics #where_clause {
+            fn schema_name() -> Option<&'static str> {
+                #schema_name
+            }
+
+            fn json_schema(generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+                #schema
+            }
+        }
+    };
+
+    Ok(expand.into())
+}
+
+fn struct_schema(fields: &syn::Fields, container: &SerdeAttrs) -> syn::Result<TokenStream> {
+    let fields = match fields {
+        syn::Fields::Named(fields) => fields,
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            let ty = &fields.unnamed[0].ty;
+            return Ok(quote! { generator.subschema_for::<#ty>() });
+        }
+        syn::Fields::Unnamed(fields) => {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "JsonSchema only works on tuple structs with one field",
+            ));
+        }
+        syn::Fields::Unit => return Ok(quote! { serde_json::json!({ "type": "null" }) }),
+    };
+
+    let mut properties = vec![];
+    let mut required = vec![];
+
+    for field in fields.named.iter() {
+        let attrs = SerdeAttrs::parse(&field.attrs)?;
+        // A flattened map holds the properties the struct doesn't name, which the schema allows anyway.
+        if attrs.flatten {
+            continue;
+        }
+
+        let ident = field.ident.as_ref().expect("named field").to_string();
+        let ident = ident.trim_start_matches("r#");
+        let name = attrs.rename.unwrap_or_else(|| container.rename(ident));
+        let ty = &field.ty;
+
+        let schema = describe(quote! { generator.subschema_for::<#ty>() }, &field.attrs);
+        properties.push(quote! { properties.insert(#name.to_string(), #schema); });
+
+        if !attrs.default && !is_option(ty) {
+            required.push(name);
+        }
+    }
+
+    let required = if required.is_empty() {
+        quote! {}
+    } else {
+        quote! { schema["required"] = serde_json::json!([#(#required),*]); }
+    };
+    let additional_properties = if container.deny_unknown_fields {
+        quote! { schema["additionalProperties"] = false.into(); }
+    } else {
+        quote! {}
+    };
+
+    Ok(quote! {
+        let mut properties = serde_json::Map::new();
+        #(#properties)*
+
+        let mut schema = serde_json::json!({ "type": "object", "properties": properties });
+        #required
+        #additional_properties
+
+        schema
+    })
+}
+
+fn enum_schema(data: &syn::DataEnum, container: &SerdeAttrs) -> syn::Result<TokenStream> {
+    let mut unit_variants = vec![];
+    let mut variants = vec![];
+
+    // `EnumDeserialize` picks a variant by the `type` of an object, its fallback variant takes the objects of any other
+    // type. The fallback schema excludes the typed ones, so an object matches exactly one variant of the `oneOf`.
+    let tags: Vec<String> = data
+        .variants
+        .iter()
+        .filter(|variant| !variant.attrs.iter().any(is_other))
+        .map(|variant| variant.ident.to_string())
+        .collect();
+
+    for variant in data.variants.iter() {
+        let attrs = SerdeAttrs::parse(&variant.attrs)?;
+        let name = attrs
+            .rename
+            .unwrap_or_else(|| container.rename(&variant.ident.to_string()));
+
+        let schema = match &variant.fields {
+            syn::Fields::Unit if container.untagged => quote! { serde_json::json!({ "type": "null" }) },
+            syn::Fields::Unit => {
+                unit_variants.push(name.clone());
+                quote! { serde_json::json!({ "const": #name }) }
+            }
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+                if container.untagged && variant.attrs.iter().any(is_other) {
+                    quote! {
+                        serde_json::json!({
+                            "allOf": [generator.subschema_for::<#ty>()],
+                            "type": "object",
+                            "required": ["type"],
+                            "not": { "properties": { "type": { "enum": [#(#tags),*] } } }
+                        })
+                    }
+                } else if container.untagged {
+                    quote! { generator.subschema_for::<#ty>() }
+                } else {
+                    quote! {
+                        serde_json::json!({
+                            "type": "object",
+                            "properties": { #name: generator.subschema_for::<#ty>() },
+                            "required": [#name],
+                            "additionalProperties": false
+                        })
+                    }
+                }
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "JsonSchema only works on enums with unit or newtype variants",
+                ));
+            }
+        };
+        variants.push(describe(schema, &variant.attrs));
+    }
+
+    if !container.untagged && unit_variants.len() == data.variants.len() {
+        return Ok(quote! { serde_json::json!({ "type": "string", "enum": [#(#unit_variants),*] }) });
+    }
+
+    Ok(quote! { serde_json::json!({ "oneOf": [#(#variants),*] }) })
+}
+
+/// Adds the doc comment of `attrs` as the `description` of `schema`.
+fn describe(schema: TokenStream, attrs: &[syn::Attribute]) -> TokenStream {
+    let lines: Vec<String> = attrs
+        .iter()
+        .filter_map(|attr| match &attr.meta {
+            syn::Meta::NameValue(meta) if meta.path.is_ident("doc") => match &meta.value {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(doc),
+                    ..
+                }) => Some(doc.value().trim().to_string()),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect();
+
+    let description = lines.join("\n").trim().to_string();
+
+    if description.is_empty() {
+        schema
+    } else {
+        quote! { types_common::json_schema::with_description({ #schema }, #description) }
+    }
+}
+
+fn is_option(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(path_type) => path_type
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Option"),
+        _ => false,
+    }
+}
+
+/// The serde attributes that change the JSON of a type.
+#[derive(Default)]
+pub(crate) struct SerdeAttrs {
+    pub rename: Option<String>,
+    pub rename_all: Option<String>,
+    pub default: bool,
+    pub untagged: bool,
+    pub deny_unknown_fields: bool,
+    pub flatten: bool,
+}
+
+impl SerdeAttrs {
+    pub fn parse(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut serde = SerdeAttrs::default();
+
+        for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("rename") {
+                    serde.rename = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("rename_all") {
+                    serde.rename_all = Some(meta.value()?.parse::<syn::LitStr>()?.value());
+                } else if meta.path.is_ident("default") {
+                    serde.default = true;
+                    if meta.input.peek(syn::Token![=]) {
+                        meta.value()?.parse::<syn::LitStr>()?;
+                    }
+                } else if meta.path.is_ident("untagged") {
+                    serde.untagged = true;
+                } else if meta.path.is_ident("deny_unknown_fields") {
+                    serde.deny_unknown_fields = true;
+                } else if meta.path.is_ident("flatten") {
+                    serde.flatten = true;
+                } else if meta.input.peek(syn::Token![=]) {
+                    // Attributes that don't change the schema, e.g. `skip_serializing_if`.
+                    meta.value()?.parse::<syn::Expr>()?;
+                }
+
+                Ok(())
+            })?;
+        }
+
+        Ok(serde)
+    }
+
+    /// The JSON name of a field or variant under `rename_all`.
+    pub fn rename(&self, name: &str) -> String {
+        match self.rename_all.as_deref() {
+            Some("camelCase") => name.to_case(Case::Camel),
+            Some("lowercase") => name.to_lowercase(),
+            Some("UPPERCASE") => name.to_uppercase(),
+            Some("snake_case") => name.to_case(Case::Snake),
+            Some("kebab-case") => name.to_case(Case::Kebab),
+            _ => name.to_string(),
+        }
+    }
+}
@@ -1,10 +1,15 @@
 use syn::parse_macro_input;
 
+mod builder_derive;
 mod enum_derive;
-mod tag_type_derive;
 mod gen_json_paths;
+mod json_schema_derive;
+mod query_derive;
+mod tag_type_derive;
+mod visit_derive;
+mod visitor;
 
-#[proc_macro_derive(EnumDeserialize)]
+#[proc_macro_derive(EnumDeserialize, attributes(enum_deserialize))]
 pub fn enum_deserialize(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     enum_derive::impl_enum_deserialize(input)
 }
@@ -14,8 +19,45 @@ pub fn tag_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     tag_type_derive::impl_tag_type(input)
 }
 
-#[proc_macro_derive(GenPaths)] 
+#[proc_macro_derive(Builder)]
+pub fn builder(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    builder_derive::impl_builder(input).unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+#[proc_macro_derive(GenPaths)]
 pub fn gen_paths(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let input = parse_macro_input!(input as syn::DeriveInput);
     gen_json_paths::gen_paths(input).unwrap()
 }
+
+#[proc_macro_derive(JsonSchema)]
+pub fn json_schema(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    json_schema_derive::impl_json_schema(input).unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+#[proc_macro_derive(Query)]
+pub fn query(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    query_derive::impl_query(input).unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+#[proc_macro_derive(Visit)]
+pub fn visit(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    visit_derive::impl_visit(input).unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+/// Generates a typed visitor for the listed `Visit` types, see `types_common::visit`:
+///
+/// ```ignore
+/// visitor!(pub trait CredentialVisitor, CredentialVisitorMut for Credential, Subject, Address);
+/// ```
+#[proc_macro]
+pub fn visitor(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as visitor::VisitorInput);
+    visitor::impl_visitor(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,134 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::json_schema_derive::SerdeAttrs;
+
+/// Generates `types_common::Query` from the shape of the type and its serde attributes.
+///
+/// Structs expose their fields under their JSON names, newtype structs and untagged enums stand for the value they
+/// hold and other enums are an object with the name of their variant as the only field.
+pub fn impl_query(input: syn::DeriveInput) -> syn::Result<proc_macro::TokenStream> {
+    let name = &input.ident;
+    let container = SerdeAttrs::parse(&input.attrs)?;
+
+    let methods = match &input.data {
+        syn::Data::Struct(data) => struct_query(&data.fields, &container)?,
+        syn::Data::Enum(data) => enum_query(data, &container)?,
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new_spanned(name, "Query doesn't work on unions"));
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(types_common::Query));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let expand = quote! {
+        impl #impl_generics types_common::Query for #name #ty_generics #where_clause {
+            #methods
+        }
+    };
+
+    Ok(expand.into())
+}
+
+fn struct_query(fields: &syn::Fields, container: &SerdeAttrs) -> syn::Result<TokenStream> {
+    let fields = match fields {
+        syn::Fields::Named(fields) => fields,
+        syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+            return Ok(quote! {
+                fn wrapped(&self) -> Option<Option<&dyn types_common::Query>> {
+                    Some(Some(&self.0))
+                }
+            });
+        }
+        syn::Fields::Unnamed(fields) => {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "Query only works on tuple structs with one field",
+            ));
+        }
+        syn::Fields::Unit => return Ok(quote! {}),
+    };
+
+    let mut entries = vec![];
+    let mut flattened = vec![];
+
+    for field in fields.named.iter() {
+        let attrs = SerdeAttrs::parse(&field.attrs)?;
+        let ident = field.ident.as_ref().expect("named field");
+
+        // The entries of a flattened map are fields of the struct itself.
+        if attrs.flatten {
+            flattened.push(quote! {
+                fields.extend(self.#ident.iter().map(|(key, value)| (key.as_str(), value as &dyn types_common::Query)));
+            });
+            continue;
+        }
+        let unraw = ident.to_string();
+        let name = attrs
+            .rename
+            .unwrap_or_else(|| container.rename(unraw.trim_start_matches("r#")));
+
+        entries.push(quote! { (#name, &self.#ident as &dyn types_common::Query) });
+    }
+
+    Ok(quote! {
+        fn fields(&self) -> Vec<(&str, &dyn types_common::Query)> {
+            #[allow(unused_mut)]
+            let mut fields = vec![#(#entries),*];
+            #(#flattened)*
+            fields
+        }
+    })
+}
+
+fn enum_query(data: &syn::DataEnum, container: &SerdeAttrs) -> syn::Result<TokenStream> {
+    let mut arms = vec![];
+
+    for variant in data.variants.iter() {
+        let ident = &variant.ident;
+
+        let arm = match &variant.fields {
+            syn::Fields::Unit if container.untagged => quote! { Self::#ident => Some(None) },
+            syn::Fields::Unit => quote! { Self::#ident => Vec::new() },
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                if container.untagged {
+                    quote! { Self::#ident(value) => Some(Some(value)) }
+                } else {
+                    let attrs = SerdeAttrs::parse(&variant.attrs)?;
+                    let name = attrs.rename.unwrap_or_else(|| container.rename(&ident.to_string()));
+
+                    quote! { Self::#ident(value) => vec![(#name, value as &dyn types_common::Query)] }
+                }
+            }
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    variant,
+                    "Query only works on enums with unit or newtype variants",
+                ));
+            }
+        };
+        arms.push(arm);
+    }
+
+    if container.untagged {
+        Ok(quote! {
+            fn wrapped(&self) -> Option<Option<&dyn types_common::Query>> {
+                match self {
+                    #(#arms),*
+                }
+            }
+        })
+    } else {
+        Ok(quote! {
+            fn fields(&self) -> Vec<(&str, &dyn types_common::Query)> {
+                match self {
+                    #(#arms),*
+                }
+            }
+        })
+    }
+}
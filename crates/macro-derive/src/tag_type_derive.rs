@@ -28,6 +28,32 @@ pub fn impl_tag_type(input: proc_macro::TokenStream) -> proc_macro::TokenStream
 
         impl types_common::AddSchemaTypes for #tag_target {}
 
+        impl types_common::JsonSchema for #tag_target {
+            fn json_schema(_generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+                serde_json::json!({ "const": stringify!(#tag_source) })
+            }
+        }
+
+        impl types_common::Query for #tag_target {
+            fn wrapped(&self) -> Option<Option<&dyn types_common::Query>> {
+                Some(Some(&self.0))
+            }
+        }
+
+        impl types_common::Visit for #tag_target {
+            fn visit<'a>(&'a self, visitor: &mut dyn types_common::Visitor<'a>) {
+                visitor.visit(self);
+            }
+
+            fn visit_mut(&mut self, visitor: &mut dyn types_common::VisitorMut) {
+                visitor.visit_mut(self);
+            }
+        }
+
+        impl<'a, V: ?Sized> types_common::Accept<'a, V> for #tag_target {}
+
+        impl<V: ?Sized> types_common::AcceptMut<V> for #tag_target {}
+
         impl Default for #tag_target {
             fn default() -> Self {
                 Self(stringify!(#tag_source).to_string())
@@ -0,0 +1,147 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+
+/// Generates `types_common::Visit`: the visitor is called with the value itself, then every field of a struct or the
+/// value of an enum variant is visited in declaration order.
+///
+/// `types_common::Walk` and `types_common::WalkMut` are generated too, they pass the same fields to a typed visitor
+/// for every visitor all the field types accept.
+pub fn impl_visit(input: syn::DeriveInput) -> syn::Result<proc_macro::TokenStream> {
+    let name = &input.ident;
+
+    let (visit, visit_mut, walk, walk_mut, field_types) = match &input.data {
+        syn::Data::Struct(data) => struct_visit(&data.fields),
+        syn::Data::Enum(data) => enum_visit(data),
+        syn::Data::Union(_) => {
+            return Err(syn::Error::new_spanned(name, "Visit doesn't work on unions"));
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(syn::parse_quote!(types_common::Visit));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut walk_generics = input.generics.clone();
+    walk_generics.params.insert(0, syn::parse_quote!('__a));
+    walk_generics.params.push(syn::parse_quote!(__V: ?Sized));
+    walk_generics.make_where_clause().predicates.extend(
+        field_types
+            .iter()
+            .map(|ty| -> syn::WherePredicate { syn::parse_quote!(#ty: types_common::Accept<'__a, __V>) }),
+    );
+    let (walk_impl_generics, _, walk_where_clause) = walk_generics.split_for_impl();
+
+    let mut walk_mut_generics = input.generics.clone();
+    walk_mut_generics.params.push(syn::parse_quote!(__V: ?Sized));
+    walk_mut_generics.make_where_clause().predicates.extend(
+        field_types
+            .iter()
+            .map(|ty| -> syn::WherePredicate { syn::parse_quote!(#ty: types_common::AcceptMut<__V>) }),
+    );
+    let (walk_mut_impl_generics, _, walk_mut_where_clause) = walk_mut_generics.split_for_impl();
+
+    // No fields, nothing to pass the visitor to.
+    let visitor = if field_types.is_empty() {
+        format_ident!("_visitor")
+    } else {
+        format_ident!("visitor")
+    };
+
+    let expand = quote! {
+        impl #impl_generics types_common::Visit for #name #ty_generics #where_clause {
+            fn visit<'a>(&'a self, visitor: &mut dyn types_common::Visitor<'a>) {
+                visitor.visit(self);
+                #visit
+            }
+
+            fn visit_mut(&mut self, visitor: &mut dyn types_common::VisitorMut) {
+                visitor.visit_mut(self);
+                #visit_mut
+            }
+        }
+
+        impl #walk_impl_generics types_common::Walk<'__a, __V> for #name #ty_generics #walk_where_clause {
+            fn walk(&'__a self, #visitor: &mut __V) {
+                #walk
+            }
+        }
+
+        impl #walk_mut_impl_generics types_common::WalkMut<__V> for #name #ty_generics #walk_mut_where_clause {
+            fn walk_mut(&mut self, #visitor: &mut __V) {
+                #walk_mut
+            }
+        }
+    };
+
+    Ok(expand.into())
+}
+
+type Expansion = (TokenStream, TokenStream, TokenStream, TokenStream, Vec<syn::Type>);
+
+fn struct_visit(fields: &syn::Fields) -> Expansion {
+    let members: Vec<syn::Member> = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| match &field.ident {
+            Some(ident) => syn::Member::Named(ident.clone()),
+            None => syn::Member::Unnamed(i.into()),
+        })
+        .collect();
+
+    (
+        quote! { #(types_common::Visit::visit(&self.#members, visitor);)* },
+        quote! { #(types_common::Visit::visit_mut(&mut self.#members, visitor);)* },
+        quote! { #(types_common::Accept::accept(&self.#members, visitor);)* },
+        quote! { #(types_common::AcceptMut::accept_mut(&mut self.#members, visitor);)* },
+        fields.iter().map(|field| field.ty.clone()).collect(),
+    )
+}
+
+fn enum_visit(data: &syn::DataEnum) -> Expansion {
+    let field_types = data
+        .variants
+        .iter()
+        .flat_map(|variant| variant.fields.iter().map(|field| field.ty.clone()));
+    let field_types = field_types.collect();
+
+    // `match self {}` doesn't compile for a reference to an empty enum.
+    if data.variants.is_empty() {
+        return (quote! {}, quote! {}, quote! {}, quote! {}, field_types);
+    }
+
+    let mut arms = vec![];
+    let mut arms_mut = vec![];
+    let mut walk_arms = vec![];
+    let mut walk_arms_mut = vec![];
+
+    for variant in data.variants.iter() {
+        let ident = &variant.ident;
+
+        let (pattern, bindings) = match &variant.fields {
+            syn::Fields::Unit => (quote! { Self::#ident }, vec![]),
+            syn::Fields::Unnamed(fields) => {
+                let bindings: Vec<_> = (0..fields.unnamed.len()).map(|i| format_ident!("field_{i}")).collect();
+                (quote! { Self::#ident(#(#bindings),*) }, bindings)
+            }
+            syn::Fields::Named(fields) => {
+                let bindings: Vec<_> = fields.named.iter().filter_map(|field| field.ident.clone()).collect();
+                (quote! { Self::#ident { #(#bindings),* } }, bindings)
+            }
+        };
+
+        arms.push(quote! { #pattern => { #(types_common::Visit::visit(#bindings, visitor);)* } });
+        arms_mut.push(quote! { #pattern => { #(types_common::Visit::visit_mut(#bindings, visitor);)* } });
+        walk_arms.push(quote! { #pattern => { #(types_common::Accept::accept(#bindings, visitor);)* } });
+        walk_arms_mut.push(quote! { #pattern => { #(types_common::AcceptMut::accept_mut(#bindings, visitor);)* } });
+    }
+
+    (
+        quote! { match self { #(#arms),* } },
+        quote! { match self { #(#arms_mut),* } },
+        quote! { match self { #(#walk_arms),* } },
+        quote! { match self { #(#walk_arms_mut),* } },
+        field_types,
+    )
+}
@@ -0,0 +1,97 @@
+use convert_case::{Case, Casing};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+
+/// `pub trait Visitor, VisitorMut for Type, other::Type, ...`
+pub struct VisitorInput {
+    vis: syn::Visibility,
+    visitor: syn::Ident,
+    visitor_mut: syn::Ident,
+    types: Punctuated<syn::Path, syn::Token![,]>,
+}
+
+impl Parse for VisitorInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let vis = input.parse()?;
+        input.parse::<syn::Token![trait]>()?;
+        let visitor = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        let visitor_mut = input.parse()?;
+        input.parse::<syn::Token![for]>()?;
+        let types = Punctuated::parse_terminated(input)?;
+
+        Ok(Self {
+            vis,
+            visitor,
+            visitor_mut,
+            types,
+        })
+    }
+}
+
+/// Generates a typed visitor with a `visit_<type>` method per type and its `_mut` counterpart, both walking the
+/// children of the value by default, and the `types_common::Accept` impls that call them.
+pub fn impl_visitor(input: VisitorInput) -> syn::Result<TokenStream> {
+    let VisitorInput {
+        vis,
+        visitor,
+        visitor_mut,
+        types,
+    } = input;
+
+    let mut methods = vec![];
+    let mut methods_mut = vec![];
+    let mut accepts = vec![];
+
+    for ty in types.iter() {
+        let Some(last) = ty.segments.last() else {
+            return Err(syn::Error::new_spanned(ty, "expected a type"));
+        };
+        let snake = last.ident.to_string().to_case(Case::Snake);
+        let method = format_ident!("visit_{snake}");
+        let method_mut = format_ident!("visit_{snake}_mut");
+
+        methods.push(quote! {
+            fn #method(&mut self, node: &'a #ty) {
+                types_common::Walk::walk(node, self);
+            }
+        });
+        methods_mut.push(quote! {
+            fn #method_mut(&mut self, node: &mut #ty) {
+                types_common::WalkMut::walk_mut(node, self);
+            }
+        });
+        accepts.push(quote! {
+            impl<'a, V: #visitor<'a> + ?Sized> types_common::Accept<'a, V> for #ty {
+                fn accept(&'a self, visitor: &mut V) {
+                    visitor.#method(self);
+                }
+            }
+
+            impl<V: #visitor_mut + ?Sized> types_common::AcceptMut<V> for #ty {
+                fn accept_mut(&mut self, visitor: &mut V) {
+                    visitor.#method_mut(self);
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        /// Visits the values of a credential by type, parents before their children. Every method walks the children
+        /// of its value by default, an override that doesn't call `types_common::Walk::walk` skips them.
+        #vis trait #visitor<'a> {
+            #(#methods)*
+        }
+
+        /// Visits the values of a credential by type to change them. Every method walks the children of its value
+        /// after the override has changed it, an override that doesn't call `types_common::WalkMut::walk_mut` skips
+        /// them.
+        #vis trait #visitor_mut {
+            #(#methods_mut)*
+        }
+
+        #(#accepts)*
+    })
+}
@@ -0,0 +1,551 @@
+//! Credential status lists ([Bitstring Status List](https://www.w3.org/TR/vc-bitstring-status-list/) and its
+//! predecessor StatusList2021) for OBv3 and ELM credentials.
+//!
+//! A [`StatusListCredential`] publishes one compressed [`StatusList`] for one [`StatusPurpose`]. It hands out the
+//! indexes and [`StatusListEntry`]s that go into the `credentialStatus` of issued credentials, and answers status
+//! lookups from a locally supplied copy, no network needed.
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{Read, Write},
+    ops::Range,
+};
+
+use base64::{
+    alphabet,
+    engine::{DecodePaddingMode, GeneralPurpose, GeneralPurposeConfig},
+    Engine,
+};
+use chrono::{DateTime, Utc};
+use flate2::{read::GzDecoder, write::GzEncoder, Compression};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use types_elm_v3::{
+    verification::{CredentialState, StatusResolver},
+    CredentialStatusValue,
+};
+
+/// The minimum number of entries of a list, so that a single status doesn't point to a small group of holders.
+pub const MIN_LENGTH: usize = 131_072;
+
+/// The largest decompressed list that is accepted, against lists that inflate to gigabytes.
+const MAX_DECODED_BYTES: u64 = 16 * 1024 * 1024;
+
+/// base64url without padding, decoding also accepts padding.
+const BASE64URL: GeneralPurpose = GeneralPurpose::new(
+    &alphabet::URL_SAFE,
+    GeneralPurposeConfig::new()
+        .with_encode_padding(false)
+        .with_decode_padding_mode(DecodePaddingMode::Indifferent),
+);
+
+#[derive(Debug)]
+pub enum StatusError {
+    IndexOutOfRange(usize),
+    /// The `statusSize` isn't between 1 and 64 bits.
+    InvalidStatusSize(usize),
+    /// The entry has no `id`, which the `credentialStatus` of OBv3 requires.
+    MissingId,
+    /// Every index of the list has been allocated.
+    ListFull,
+    /// The `encodedList` isn't gzipped base64url, or is shorter than [`MIN_LENGTH`] or longer than 16 MiB decoded.
+    InvalidEncoding(String),
+    /// The status list credential lacks a field or has a field of the wrong type.
+    InvalidCredential(&'static str),
+    /// The entry doesn't point to the given list, or has a different purpose than the list.
+    ListMismatch(String),
+    UnknownList(String),
+    InvalidUri(String),
+}
+
+impl fmt::Display for StatusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatusError::IndexOutOfRange(index) => write!(f, "Status list index {index} is out of range"),
+            StatusError::InvalidStatusSize(size) => write!(f, "Invalid status size {size}"),
+            StatusError::MissingId => write!(f, "Status entry has no id"),
+            StatusError::ListFull => write!(f, "Status list has no free index left"),
+            StatusError::InvalidEncoding(reason) => write!(f, "Invalid encoded status list: {reason}"),
+            StatusError::InvalidCredential(field) => write!(f, "Status list credential has an invalid `{field}`"),
+            StatusError::ListMismatch(reason) => write!(f, "Status entry doesn't match the list: {reason}"),
+            StatusError::UnknownList(id) => write!(f, "Unknown status list credential: {id}"),
+            StatusError::InvalidUri(uri) => write!(f, "Not a valid URI: {uri}"),
+        }
+    }
+}
+
+impl std::error::Error for StatusError {}
+
+/// The flavour of status list, they differ in type names, context and list encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusListType {
+    Bitstring,
+    StatusList2021,
+}
+
+impl StatusListType {
+    pub fn list_type(&self) -> &'static str {
+        match self {
+            StatusListType::Bitstring => "BitstringStatusList",
+            StatusListType::StatusList2021 => "StatusList2021",
+        }
+    }
+
+    pub fn entry_type(&self) -> &'static str {
+        match self {
+            StatusListType::Bitstring => "BitstringStatusListEntry",
+            StatusListType::StatusList2021 => "StatusList2021Entry",
+        }
+    }
+
+    pub fn credential_type(&self) -> &'static str {
+        match self {
+            StatusListType::Bitstring => "BitstringStatusListCredential",
+            StatusListType::StatusList2021 => "StatusList2021Credential",
+        }
+    }
+
+    fn context(&self) -> Value {
+        match self {
+            StatusListType::Bitstring => json!(["https://www.w3.org/ns/credentials/v2"]),
+            StatusListType::StatusList2021 => json!([
+                "https://www.w3.org/2018/credentials/v1",
+                "https://w3id.org/vc/status-list/2021/v1"
+            ]),
+        }
+    }
+
+    fn from_list_type(list_type: &str) -> Option<Self> {
+        [StatusListType::Bitstring, StatusListType::StatusList2021]
+            .into_iter()
+            .find(|type_| type_.list_type() == list_type)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusPurpose {
+    Revocation,
+    Suspension,
+}
+
+impl StatusPurpose {
+    /// The state of a credential whose bit is set in a list with this purpose.
+    pub fn state(&self, set: bool) -> CredentialState {
+        match (self, set) {
+            (_, false) => CredentialState::Active,
+            (StatusPurpose::Revocation, true) => CredentialState::Revoked,
+            (StatusPurpose::Suspension, true) => CredentialState::Suspended,
+        }
+    }
+}
+
+/// A bitstring of statuses, index 0 is the most significant bit of the first byte.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusList {
+    bytes: Vec<u8>,
+}
+
+impl Default for StatusList {
+    fn default() -> Self {
+        Self::new(MIN_LENGTH)
+    }
+}
+
+impl StatusList {
+    /// A list of at least `len` and at least [`MIN_LENGTH`] unset entries, rounded up to whole bytes.
+    pub fn new(len: usize) -> Self {
+        Self {
+            bytes: vec![0; len.max(MIN_LENGTH).div_ceil(8)],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.bytes.len() * 8
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bytes.is_empty()
+    }
+
+    pub fn get(&self, index: usize) -> Result<bool, StatusError> {
+        let byte = self.bytes.get(index / 8).ok_or(StatusError::IndexOutOfRange(index))?;
+
+        Ok(byte & mask(index) != 0)
+    }
+
+    pub fn set(&mut self, index: usize, value: bool) -> Result<(), StatusError> {
+        let byte = self
+            .bytes
+            .get_mut(index / 8)
+            .ok_or(StatusError::IndexOutOfRange(index))?;
+
+        if value {
+            *byte |= mask(index);
+        } else {
+            *byte &= !mask(index);
+        }
+
+        Ok(())
+    }
+
+    /// The status of entry `index` of a list with `size` bits per entry, its first bit is the most significant one.
+    pub fn value(&self, index: usize, size: usize) -> Result<u64, StatusError> {
+        self.bits(index, size)?
+            .try_fold(0, |value, bit| Ok(value << 1 | u64::from(self.get(bit)?)))
+    }
+
+    /// Sets the status of entry `index` of a list with `size` bits per entry, `value` is cut to its `size` lowest bits.
+    pub fn set_value(&mut self, index: usize, size: usize, value: u64) -> Result<(), StatusError> {
+        let bits = self.bits(index, size)?;
+
+        for (shift, bit) in bits.rev().enumerate() {
+            self.set(bit, value >> shift & 1 == 1)?;
+        }
+
+        Ok(())
+    }
+
+    /// The bits of entry `index` of a list with `size` bits per entry.
+    fn bits(&self, index: usize, size: usize) -> Result<Range<usize>, StatusError> {
+        if !(1..=64).contains(&size) {
+            return Err(StatusError::InvalidStatusSize(size));
+        }
+
+        index
+            .checked_mul(size)
+            .and_then(|start| Some(start..start.checked_add(size)?))
+            .filter(|bits| bits.end <= self.len())
+            .ok_or(StatusError::IndexOutOfRange(index))
+    }
+
+    /// The `encodedList`: gzipped and base64url encoded, with the multibase `u` prefix for Bitstring Status Lists.
+    pub fn encode(&self, type_: StatusListType) -> String {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(&self.bytes).expect("writing to a Vec doesn't fail");
+        let gzipped = encoder.finish().expect("writing to a Vec doesn't fail");
+
+        match type_ {
+            StatusListType::Bitstring => format!("u{}", BASE64URL.encode(gzipped)),
+            StatusListType::StatusList2021 => BASE64URL.encode(gzipped),
+        }
+    }
+
+    pub fn decode(encoded: &str, type_: StatusListType) -> Result<Self, StatusError> {
+        let encoded = match type_ {
+            StatusListType::Bitstring => encoded
+                .strip_prefix('u')
+                .ok_or_else(|| StatusError::InvalidEncoding("missing multibase prefix `u`".to_string()))?,
+            StatusListType::StatusList2021 => encoded,
+        };
+
+        let gzipped = BASE64URL
+            .decode(encoded)
+            .map_err(|err| StatusError::InvalidEncoding(err.to_string()))?;
+
+        let mut bytes = Vec::new();
+        GzDecoder::new(gzipped.as_slice())
+            .take(MAX_DECODED_BYTES + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|err| StatusError::InvalidEncoding(err.to_string()))?;
+
+        if bytes.len() as u64 > MAX_DECODED_BYTES {
+            return Err(StatusError::InvalidEncoding("list is too large".to_string()));
+        }
+        if bytes.len() * 8 < MIN_LENGTH {
+            return Err(StatusError::InvalidEncoding(format!(
+                "list has {} entries, less than {MIN_LENGTH}",
+                bytes.len() * 8
+            )));
+        }
+
+        Ok(Self { bytes })
+    }
+}
+
+fn mask(index: usize) -> u8 {
+    0b1000_0000 >> (index % 8)
+}
+
+/// The `credentialStatus` of a credential whose status is in a status list.
+///
+/// The status is looked up by `statusListCredential` and `statusListIndex` only. The `id` is optional, the entries of
+/// [`StatusListCredential::entry`] have the list credential id with the index as fragment, as in the examples of the
+/// specs, e.g. `https://example.org/status/1#94567`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusListEntry {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: String,
+    pub status_purpose: StatusPurpose,
+    /// A number as a string, as the specs require.
+    pub status_list_index: String,
+    pub status_list_credential: String,
+    /// The number of bits of the status, 1 when absent.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_size: Option<usize>,
+}
+
+impl StatusListEntry {
+    pub fn index(&self) -> Result<usize, StatusError> {
+        self.status_list_index
+            .parse()
+            .map_err(|_| StatusError::ListMismatch(format!("invalid index \"{}\"", self.status_list_index)))
+    }
+
+    pub fn size(&self) -> usize {
+        self.status_size.unwrap_or(1)
+    }
+
+    /// Adds the entry to the `credentialStatus` of a credential in JSON, next to the statuses already there.
+    pub fn insert_into(&self, credential: &mut Value) {
+        let entry = serde_json::to_value(self).expect("entry serializes");
+
+        credential["credentialStatus"] = match credential["credentialStatus"].take() {
+            Value::Null => entry,
+            Value::Array(mut statuses) => {
+                statuses.push(entry);
+                statuses.into()
+            }
+            status => json!([status, entry]),
+        };
+    }
+}
+
+/// Fails when the entry has no `id`. The typed `credentialStatus` of OBv3 only keeps `id` and `type`, so the status
+/// can't be resolved from it, [`LocalStatusLists::state_of_credential`] reads it from the JSON of the credential.
+impl TryFrom<&StatusListEntry> for types_ob_v3::prelude::CredentialStatus {
+    type Error = StatusError;
+
+    fn try_from(entry: &StatusListEntry) -> Result<Self, StatusError> {
+        Ok(Self {
+            id: entry.id.clone().ok_or(StatusError::MissingId)?,
+            type_: entry.type_.clone(),
+        })
+    }
+}
+
+/// Fails when the entry or list id isn't a URI.
+impl TryFrom<&StatusListEntry> for CredentialStatusValue {
+    type Error = StatusError;
+
+    fn try_from(entry: &StatusListEntry) -> Result<Self, StatusError> {
+        let value = serde_json::to_value(entry).expect("entry serializes");
+
+        serde_json::from_value(value)
+            .map_err(|_| StatusError::InvalidUri(entry.id.clone().unwrap_or(entry.status_list_credential.clone())))
+    }
+}
+
+/// A status list credential before it is secured, the proof or JWT is added by the issuer.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatusListCredential {
+    pub id: String,
+    pub issuer: String,
+    pub valid_from: DateTime<Utc>,
+    pub purpose: StatusPurpose,
+    pub type_: StatusListType,
+    pub list: StatusList,
+    /// The next index [`allocate`](Self::allocate) hands out, to be persisted by the issuer.
+    pub next_index: usize,
+}
+
+impl StatusListCredential {
+    pub fn new(
+        id: impl Into<String>,
+        issuer: impl Into<String>,
+        purpose: StatusPurpose,
+        type_: StatusListType,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            issuer: issuer.into(),
+            valid_from: Utc::now(),
+            purpose,
+            type_,
+            list: StatusList::default(),
+            next_index: 0,
+        }
+    }
+
+    /// Reserves the next free index and returns the entry for the credential it is for.
+    pub fn allocate(&mut self) -> Result<StatusListEntry, StatusError> {
+        if self.next_index >= self.list.len() {
+            return Err(StatusError::ListFull);
+        }
+
+        let entry = self.entry(self.next_index);
+        self.next_index += 1;
+
+        Ok(entry)
+    }
+
+    pub fn entry(&self, index: usize) -> StatusListEntry {
+        StatusListEntry {
+            id: Some(format!("{}#{index}", self.id)),
+            type_: self.type_.entry_type().to_string(),
+            status_purpose: self.purpose,
+            status_list_index: index.to_string(),
+            status_list_credential: self.id.clone(),
+            status_size: None,
+        }
+    }
+
+    /// Sets or clears the status of `entry`, e.g. to revoke the credential. A status of several bits is set to 1.
+    pub fn set_status(&mut self, entry: &StatusListEntry, value: bool) -> Result<(), StatusError> {
+        let index = self.index_of(entry)?;
+        self.list.set_value(index, entry.size(), value.into())
+    }
+
+    /// Whether the status of `entry` is set, e.g. whether the credential is revoked. A status of several bits is set
+    /// when any of them is.
+    pub fn status(&self, entry: &StatusListEntry) -> Result<bool, StatusError> {
+        Ok(self.list.value(self.index_of(entry)?, entry.size())? != 0)
+    }
+
+    fn index_of(&self, entry: &StatusListEntry) -> Result<usize, StatusError> {
+        if entry.status_list_credential != self.id {
+            return Err(StatusError::ListMismatch(format!(
+                "entry points to {}",
+                entry.status_list_credential
+            )));
+        }
+
+        if entry.status_purpose != self.purpose {
+            return Err(StatusError::ListMismatch("different status purpose".to_string()));
+        }
+
+        entry.index()
+    }
+
+    pub fn to_value(&self) -> Value {
+        let date_field = match self.type_ {
+            StatusListType::Bitstring => "validFrom",
+            StatusListType::StatusList2021 => "issuanceDate",
+        };
+
+        json!({
+            "@context": self.type_.context(),
+            "id": self.id,
+            "type": ["VerifiableCredential", self.type_.credential_type()],
+            "issuer": self.issuer,
+            date_field: self.valid_from,
+            "credentialSubject": {
+                "id": format!("{}#list", self.id),
+                "type": self.type_.list_type(),
+                "statusPurpose": self.purpose,
+                "encodedList": self.list.encode(self.type_),
+            }
+        })
+    }
+
+    /// Reads a status list credential, e.g. one that was fetched by the holder. Its proof isn't verified.
+    ///
+    /// `next_index` is set to the end of the list, as the allocated indexes can't be known from the list.
+    pub fn from_value(credential: &Value) -> Result<Self, StatusError> {
+        let str_field = |value: &Value, name: &'static str| {
+            value[name]
+                .as_str()
+                .map(String::from)
+                .ok_or(StatusError::InvalidCredential(name))
+        };
+
+        let subject = &credential["credentialSubject"];
+
+        let type_ = subject["type"]
+            .as_str()
+            .and_then(StatusListType::from_list_type)
+            .ok_or(StatusError::InvalidCredential("type"))?;
+
+        let issuer = match &credential["issuer"] {
+            Value::Object(issuer) => issuer.get("id").and_then(Value::as_str).map(String::from),
+            issuer => issuer.as_str().map(String::from),
+        }
+        .ok_or(StatusError::InvalidCredential("issuer"))?;
+
+        let valid_from = serde_json::from_value(credential["validFrom"].clone())
+            .or_else(|_| serde_json::from_value(credential["issuanceDate"].clone()))
+            .map_err(|_| StatusError::InvalidCredential("validFrom"))?;
+
+        let purpose = serde_json::from_value(subject["statusPurpose"].clone())
+            .map_err(|_| StatusError::InvalidCredential("statusPurpose"))?;
+
+        let list = StatusList::decode(&str_field(subject, "encodedList")?, type_)?;
+
+        Ok(Self {
+            id: str_field(credential, "id")?,
+            issuer,
+            valid_from,
+            purpose,
+            type_,
+            next_index: list.len(),
+            list,
+        })
+    }
+}
+
+/// Status list credentials supplied by the verifier, looked up by id.
+#[derive(Debug, Clone, Default)]
+pub struct LocalStatusLists {
+    lists: HashMap<String, StatusListCredential>,
+}
+
+impl LocalStatusLists {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, list: StatusListCredential) {
+        self.lists.insert(list.id.clone(), list);
+    }
+
+    pub fn state(&self, entry: &StatusListEntry) -> Result<CredentialState, StatusError> {
+        let list = self
+            .lists
+            .get(&entry.status_list_credential)
+            .ok_or_else(|| StatusError::UnknownList(entry.status_list_credential.clone()))?;
+
+        Ok(list.purpose.state(list.status(entry)?))
+    }
+
+    /// The state of a credential in JSON: revoked or suspended when any of its status list entries is set.
+    ///
+    /// `credentialStatus` items that aren't status list entries are ignored.
+    pub fn state_of_credential(&self, credential: &Value) -> Result<CredentialState, StatusError> {
+        let statuses = match &credential["credentialStatus"] {
+            Value::Array(statuses) => statuses.iter().collect(),
+            Value::Null => vec![],
+            status => vec![status],
+        };
+
+        let mut state = CredentialState::Active;
+
+        for status in statuses {
+            let Ok(entry) = serde_json::from_value::<StatusListEntry>(status.clone()) else {
+                continue;
+            };
+
+            match self.state(&entry)? {
+                CredentialState::Active => {}
+                CredentialState::Revoked => return Ok(CredentialState::Revoked),
+                CredentialState::Suspended => state = CredentialState::Suspended,
+            }
+        }
+
+        Ok(state)
+    }
+}
+
+impl StatusResolver for LocalStatusLists {
+    /// Status list entries are looked up by their `statusListCredential` and `statusListIndex`, other statuses can't be
+    /// resolved.
+    fn resolve(&self, status: &CredentialStatusValue) -> Result<CredentialState, String> {
+        let entry = serde_json::to_value(status)
+            .and_then(serde_json::from_value::<StatusListEntry>)
+            .map_err(|_| "Credential status isn't a status list entry".to_string())?;
+
+        self.state(&entry).map_err(|err| err.to_string())
+    }
+}
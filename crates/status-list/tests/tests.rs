@@ -0,0 +1,253 @@
+#[cfg(test)]
+mod tests {
+    use status_list::*;
+    use types_elm_v3::verification::{CredentialState, StatusResolver};
+
+    #[test]
+    fn test_status_list() {
+        // Lists have at least the minimum length.
+        let mut list = StatusList::new(16);
+        assert_eq!(list.len(), MIN_LENGTH);
+
+        list.set(0, true).unwrap();
+        list.set(9, true).unwrap();
+        assert!(list.get(0).unwrap());
+        assert!(!list.get(1).unwrap());
+        assert!(matches!(
+            list.get(MIN_LENGTH),
+            Err(StatusError::IndexOutOfRange(MIN_LENGTH))
+        ));
+
+        let encoded = list.encode(StatusListType::Bitstring);
+        assert!(encoded.starts_with('u'));
+        assert_eq!(StatusList::decode(&encoded, StatusListType::Bitstring).unwrap(), list);
+
+        let encoded = list.encode(StatusListType::StatusList2021);
+        assert_eq!(
+            StatusList::decode(&encoded, StatusListType::StatusList2021).unwrap(),
+            list
+        );
+
+        // Index 0 is the leftmost bit.
+        let decoded = StatusList::decode(&encoded, StatusListType::StatusList2021).unwrap();
+        assert!(decoded.get(0).unwrap() && decoded.get(9).unwrap());
+    }
+
+    #[test]
+    fn test_status_list_credential() {
+        let mut revocations = StatusListCredential::new(
+            "https://example.org/status/1",
+            "did:example:issuer",
+            StatusPurpose::Revocation,
+            StatusListType::Bitstring,
+        );
+
+        let first = revocations.allocate().unwrap();
+        let second = revocations.allocate().unwrap();
+        assert_eq!(second.id.as_deref(), Some("https://example.org/status/1#1"));
+        assert_eq!(second.type_, "BitstringStatusListEntry");
+
+        revocations.set_status(&second, true).unwrap();
+
+        // The holder or verifier only has the published credential.
+        let published = revocations.to_value();
+        assert_eq!(published["credentialSubject"]["statusPurpose"], "revocation");
+        let mut lists = LocalStatusLists::new();
+        lists.insert(StatusListCredential::from_value(&published).unwrap());
+
+        assert_eq!(lists.state(&first).unwrap(), CredentialState::Active);
+        assert_eq!(lists.state(&second).unwrap(), CredentialState::Revoked);
+
+        let mut credential = serde_json::json!({ "id": "urn:credential" });
+        first.insert_into(&mut credential);
+        assert_eq!(lists.state_of_credential(&credential).unwrap(), CredentialState::Active);
+        second.insert_into(&mut credential);
+        assert_eq!(credential["credentialStatus"][1]["statusListIndex"], "1");
+        assert_eq!(
+            lists.state_of_credential(&credential).unwrap(),
+            CredentialState::Revoked
+        );
+
+        // The state comes from the list and index, the id is optional.
+        let anonymous = StatusListEntry {
+            id: None,
+            ..second.clone()
+        };
+        assert_eq!(lists.state(&anonymous).unwrap(), CredentialState::Revoked);
+        assert!(serde_json::to_value(&anonymous).unwrap().get("id").is_none());
+        assert!(matches!(
+            types_ob_v3::prelude::CredentialStatus::try_from(&anonymous),
+            Err(StatusError::MissingId)
+        ));
+
+        let ob_status = types_ob_v3::prelude::CredentialStatus::try_from(&second).unwrap();
+        assert_eq!(Some(ob_status.id), second.id);
+
+        let elm_status = types_elm_v3::CredentialStatusValue::try_from(&second).unwrap();
+        assert!(matches!(
+            elm_status,
+            types_elm_v3::CredentialStatusValue::BitstringStatusListEntry(_)
+        ));
+        assert_eq!(lists.resolve(&elm_status), Ok(CredentialState::Revoked));
+
+        // A status that isn't a status list entry can't be resolved, whatever its id.
+        let elm_status: types_elm_v3::CredentialStatusValue = serde_json::from_value(serde_json::json!({
+            "id": second.id,
+            "type": "CredentialStatus"
+        }))
+        .unwrap();
+        assert!(lists.resolve(&elm_status).is_err());
+
+        let other = StatusListCredential::new(
+            "https://example.org/status/2",
+            "did:example:issuer",
+            StatusPurpose::Suspension,
+            StatusListType::StatusList2021,
+        );
+        assert!(matches!(other.status(&first), Err(StatusError::ListMismatch(_))));
+        assert!(matches!(lists.state(&other.entry(0)), Err(StatusError::UnknownList(_))));
+    }
+
+    #[test]
+    fn test_status_list_full() {
+        let mut suspensions = StatusListCredential::new(
+            "https://example.org/status/3",
+            "did:example:issuer",
+            StatusPurpose::Suspension,
+            StatusListType::StatusList2021,
+        );
+        suspensions.next_index = MIN_LENGTH - 8;
+
+        for _ in 0..8 {
+            suspensions.allocate().unwrap();
+        }
+        assert!(matches!(suspensions.allocate(), Err(StatusError::ListFull)));
+
+        let last = suspensions.entry(MIN_LENGTH - 1);
+        suspensions.set_status(&last, true).unwrap();
+        let published = suspensions.to_value();
+        assert!(published.get("issuanceDate").is_some());
+
+        let mut lists = LocalStatusLists::new();
+        lists.insert(StatusListCredential::from_value(&published).unwrap());
+        assert_eq!(lists.state(&last).unwrap(), CredentialState::Suspended);
+    }
+
+    fn gzip(bytes: &[u8]) -> Vec<u8> {
+        use std::io::Write;
+
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(bytes).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn base64url(bytes: &[u8]) -> String {
+        use base64::Engine;
+
+        base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(bytes)
+    }
+
+    #[test]
+    fn test_status_list_decoding() {
+        let invalid =
+            |encoded: &str, type_| matches!(StatusList::decode(encoded, type_), Err(StatusError::InvalidEncoding(_)));
+
+        // A list of the minimum length decodes, a shorter one doesn't.
+        let encoded = base64url(&gzip(&vec![0; MIN_LENGTH / 8]));
+        assert_eq!(
+            StatusList::decode(&encoded, StatusListType::StatusList2021)
+                .unwrap()
+                .len(),
+            MIN_LENGTH
+        );
+        assert!(invalid(
+            &base64url(&gzip(&vec![0; MIN_LENGTH / 8 - 1])),
+            StatusListType::StatusList2021
+        ));
+        assert!(invalid(
+            &format!("u{}", base64url(&gzip(&[0; 2]))),
+            StatusListType::Bitstring
+        ));
+
+        // Lists that inflate to more than 16 MiB are rejected.
+        assert!(!invalid(
+            &base64url(&gzip(&vec![0; 16 * 1024 * 1024])),
+            StatusListType::StatusList2021
+        ));
+        assert!(invalid(
+            &base64url(&gzip(&vec![0; 16 * 1024 * 1024 + 1])),
+            StatusListType::StatusList2021
+        ));
+
+        // Not base64url, or no multibase prefix.
+        assert!(invalid("u!!!", StatusListType::Bitstring));
+        assert!(invalid("u+/+/", StatusListType::Bitstring));
+        assert!(invalid(
+            &base64url(&gzip(&vec![0; MIN_LENGTH / 8])),
+            StatusListType::Bitstring
+        ));
+
+        // Not gzipped, or truncated.
+        assert!(invalid(
+            &base64url(&vec![0; MIN_LENGTH / 8]),
+            StatusListType::StatusList2021
+        ));
+        let gzipped = gzip(&vec![0; MIN_LENGTH / 8]);
+        assert!(invalid(
+            &base64url(&gzipped[..gzipped.len() / 2]),
+            StatusListType::StatusList2021
+        ));
+    }
+
+    #[test]
+    fn test_status_size() {
+        let mut list = StatusList::default();
+
+        // Entry 3 of a list of 2 bit statuses is bits 6 and 7.
+        list.set_value(3, 2, 0b10).unwrap();
+        assert_eq!(list.value(3, 2).unwrap(), 0b10);
+        assert!(list.get(6).unwrap() && !list.get(7).unwrap());
+        assert_eq!(list.value(2, 2).unwrap(), 0);
+        assert_eq!(list.value(1, 4).unwrap(), 0b0010);
+
+        list.set_value(3, 2, 0b111).unwrap();
+        assert_eq!(list.value(3, 2).unwrap(), 0b11);
+
+        assert!(matches!(
+            list.value(MIN_LENGTH / 2, 2),
+            Err(StatusError::IndexOutOfRange(_))
+        ));
+        assert!(matches!(
+            list.value(usize::MAX, 2),
+            Err(StatusError::IndexOutOfRange(_))
+        ));
+        assert!(matches!(list.value(0, 0), Err(StatusError::InvalidStatusSize(0))));
+        assert!(matches!(list.value(0, 65), Err(StatusError::InvalidStatusSize(65))));
+
+        let mut revocations = StatusListCredential::new(
+            "https://example.org/status/4",
+            "did:example:issuer",
+            StatusPurpose::Revocation,
+            StatusListType::Bitstring,
+        );
+        let entry = StatusListEntry {
+            status_size: Some(2),
+            ..revocations.entry(5)
+        };
+        revocations.set_status(&entry, true).unwrap();
+        assert_eq!(revocations.list.value(5, 2).unwrap(), 1);
+        assert!(!revocations.list.get(5).unwrap());
+
+        let mut lists = LocalStatusLists::new();
+        lists.insert(StatusListCredential::from_value(&revocations.to_value()).unwrap());
+
+        let mut credential = serde_json::json!({ "id": "urn:credential" });
+        entry.insert_into(&mut credential);
+        assert_eq!(credential["credentialStatus"]["statusSize"], 2);
+        assert_eq!(
+            lists.state_of_credential(&credential).unwrap(),
+            CredentialState::Revoked
+        );
+        assert_eq!(lists.state(&revocations.entry(5)).unwrap(), CredentialState::Active);
+    }
+}
@@ -0,0 +1,156 @@
+//! JSON Schema ([draft 2020-12](https://json-schema.org/draft/2020-12/schema)) of the models.
+//!
+//! `#[derive(JsonSchema)]` follows the serde attributes the models use: `rename`, `rename_all`, `default`, `untagged`
+//! and `deny_unknown_fields`. Named types become a definition in `$defs` and are referenced with `$ref`. An `Option`
+//! field isn't required, but `null` isn't part of its schema.
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use email_address::EmailAddress;
+use serde_json::{json, Map, Value};
+
+use crate::{DurationType, LangKey, OneOrMany, PositiveInteger};
+
+pub const DRAFT_2020_12: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub trait JsonSchema {
+    /// The name of the definition in `$defs`, `None` inlines the schema, e.g. for `String` or `Vec<T>`.
+    fn schema_name() -> Option<&'static str> {
+        None
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Value;
+}
+
+/// Collects the definitions of the named types while a schema is generated.
+#[derive(Debug, Default)]
+pub struct SchemaGenerator {
+    definitions: Map<String, Value>,
+}
+
+impl SchemaGenerator {
+    /// The schema of `T`, a `$ref` to its definition when it's named.
+    pub fn subschema_for<T: JsonSchema + ?Sized>(&mut self) -> Value {
+        let Some(name) = T::schema_name() else {
+            return T::json_schema(self);
+        };
+
+        if !self.definitions.contains_key(name) {
+            // The placeholder ends the recursion of recursive types.
+            self.definitions.insert(name.to_string(), Value::Null);
+
+            let schema = T::json_schema(self);
+            self.definitions.insert(name.to_string(), schema);
+        }
+
+        json!({ "$ref": format!("#/$defs/{name}") })
+    }
+
+    pub fn definitions(&self) -> &Map<String, Value> {
+        &self.definitions
+    }
+
+    /// A schema document for `T`, with the definitions of every named type it uses.
+    pub fn root_schema_for<T: JsonSchema + ?Sized>(mut self) -> Value {
+        let mut schema = json!({ "$schema": DRAFT_2020_12 });
+
+        match self.subschema_for::<T>() {
+            Value::Object(subschema) => schema.as_object_mut().expect("schema is an object").extend(subschema),
+            subschema => schema["allOf"] = json!([subschema]),
+        }
+
+        if !self.definitions.is_empty() {
+            schema["$defs"] = Value::Object(self.definitions);
+        }
+
+        schema
+    }
+}
+
+/// A schema document for `T`, e.g. `schema_for::<EuropassEdcCredential>()`.
+pub fn schema_for<T: JsonSchema + ?Sized>() -> Value {
+    SchemaGenerator::default().root_schema_for::<T>()
+}
+
+/// Adds a `description` to `schema`, used for doc comments.
+pub fn with_description(mut schema: Value, description: &str) -> Value {
+    if let Some(object) = schema.as_object_mut() {
+        object.insert("description".to_string(), description.into());
+    }
+
+    schema
+}
+
+macro_rules! impl_schema {
+    ($schema:tt for $($t:ty),+) => {
+        $(impl JsonSchema for $t {
+            fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+                json!($schema)
+            }
+        })*
+    }
+}
+
+impl_schema!({ "type": "string" } for String, str, PathBuf);
+impl_schema!({ "type": "boolean" } for bool);
+impl_schema!({ "type": "integer", "minimum": 0 } for usize, u8, u16, u32, u64, u128);
+impl_schema!({ "type": "integer" } for isize, i8, i16, i32, i64, i128);
+impl_schema!({ "type": "number" } for f32, f64);
+impl_schema!({ "type": "string", "format": "date-time" } for DateTime<Utc>);
+impl_schema!({ "type": "string", "format": "date" } for NaiveDate);
+impl_schema!({ "type": "string", "format": "email" } for EmailAddress);
+impl_schema!({ "type": "string", "format": "duration" } for DurationType);
+impl_schema!({ "type": "integer", "minimum": 0 } for PositiveInteger);
+impl_schema!({} for Value);
+impl_schema!({ "type": "object" } for Map<String, Value>);
+
+impl JsonSchema for LangKey {
+    fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "string", "pattern": "^[A-Za-z]{2,8}(-[A-Za-z0-9]{1,8})*$" })
+    }
+}
+
+impl<T: JsonSchema + ?Sized> JsonSchema for Box<T> {
+    fn json_schema(generator: &mut SchemaGenerator) -> Value {
+        generator.subschema_for::<T>()
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Option<T> {
+    fn json_schema(generator: &mut SchemaGenerator) -> Value {
+        generator.subschema_for::<T>()
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for Vec<T> {
+    fn json_schema(generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "array", "items": generator.subschema_for::<T>() })
+    }
+}
+
+impl<T: JsonSchema> JsonSchema for OneOrMany<T> {
+    fn json_schema(generator: &mut SchemaGenerator) -> Value {
+        let one = generator.subschema_for::<T>();
+
+        // Any value takes arrays too, one or many of any value is any value and not two matching `oneOf` branches.
+        if one == json!({}) {
+            return one;
+        }
+
+        json!({ "oneOf": [one, { "type": "array", "items": one }] })
+    }
+}
+
+impl<K, V: JsonSchema> JsonSchema for HashMap<K, V> {
+    fn json_schema(generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "object", "additionalProperties": generator.subschema_for::<V>() })
+    }
+}
+
+impl<K, V: JsonSchema> JsonSchema for BTreeMap<K, V> {
+    fn json_schema(generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "object", "additionalProperties": generator.subschema_for::<V>() })
+    }
+}
@@ -0,0 +1,406 @@
+//! BCP 47 language tags (RFC 5646) with the matching schemes of RFC 4647.
+
+use serde::{de, Serialize};
+use std::fmt;
+
+use crate::{types_common, GenPaths};
+
+/// ISO 639-1 codes, two-letter primary language subtags must be one of these.
+const ISO_639_1: &[&str] = &[
+    "aa", "ab", "ae", "af", "ak", "am", "an", "ar", "as", "av", "ay", "az", "ba", "be", "bg", "bh", "bi", "bm", "bn",
+    "bo", "br", "bs", "ca", "ce", "ch", "co", "cr", "cs", "cu", "cv", "cy", "da", "de", "dv", "dz", "ee", "el", "en",
+    "eo", "es", "et", "eu", "fa", "ff", "fi", "fj", "fo", "fr", "fy", "ga", "gd", "gl", "gn", "gu", "gv", "ha", "he",
+    "hi", "ho", "hr", "ht", "hu", "hy", "hz", "ia", "id", "ie", "ig", "ii", "ik", "in", "io", "is", "it", "iu", "iw",
+    "ja", "ji", "jv", "jw", "ka", "kg", "ki", "kj", "kk", "kl", "km", "kn", "ko", "kr", "ks", "ku", "kv", "kw", "ky",
+    "la", "lb", "lg", "li", "ln", "lo", "lt", "lu", "lv", "mg", "mh", "mi", "mk", "ml", "mn", "mo", "mr", "ms", "mt",
+    "my", "na", "nb", "nd", "ne", "ng", "nl", "nn", "no", "nr", "nv", "ny", "oc", "oj", "om", "or", "os", "pa", "pi",
+    "pl", "ps", "pt", "qu", "rm", "rn", "ro", "ru", "rw", "sa", "sc", "sd", "se", "sg", "sh", "si", "sk", "sl", "sm",
+    "sn", "so", "sq", "sr", "ss", "st", "su", "sv", "sw", "ta", "te", "tg", "th", "ti", "tk", "tl", "tn", "to", "tr",
+    "ts", "tt", "tw", "ty", "ug", "uk", "ur", "uz", "ve", "vi", "vo", "wa", "wo", "xh", "yi", "yo", "za", "zh", "zu",
+];
+
+/// Grandfathered tags of the IANA registry and their preferred values.
+const GRANDFATHERED: &[(&str, Option<&str>)] = &[
+    ("art-lojban", Some("jbo")),
+    ("cel-gaulish", None),
+    ("en-GB-oed", Some("en-GB-oxendict")),
+    ("i-ami", Some("ami")),
+    ("i-bnn", Some("bnn")),
+    ("i-default", None),
+    ("i-enochian", None),
+    ("i-hak", Some("hak")),
+    ("i-klingon", Some("tlh")),
+    ("i-lux", Some("lb")),
+    ("i-mingo", None),
+    ("i-navajo", Some("nv")),
+    ("i-pwn", Some("pwn")),
+    ("i-tao", Some("tao")),
+    ("i-tay", Some("tay")),
+    ("i-tsu", Some("tsu")),
+    ("no-bok", Some("nb")),
+    ("no-nyn", Some("nn")),
+    ("sgn-BE-FR", Some("sfb")),
+    ("sgn-BE-NL", Some("vgt")),
+    ("sgn-CH-DE", Some("sgg")),
+    ("zh-guoyu", Some("cmn")),
+    ("zh-hakka", Some("hak")),
+    ("zh-min", None),
+    ("zh-min-nan", Some("nan")),
+    ("zh-xiang", Some("hsn")),
+];
+
+/// Deprecated language subtags of the IANA registry and their preferred values.
+const DEPRECATED_LANGUAGES: &[(&str, &str)] = &[("in", "id"), ("iw", "he"), ("ji", "yi"), ("jw", "jv"), ("mo", "ro")];
+
+/// Deprecated region subtags of the IANA registry and their preferred values.
+const DEPRECATED_REGIONS: &[(&str, &str)] = &[
+    ("BU", "MM"),
+    ("DD", "DE"),
+    ("FX", "FR"),
+    ("TP", "TL"),
+    ("YD", "YE"),
+    ("ZR", "CD"),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LangKeyError {
+    Empty,
+    /// A subtag that is malformed or out of place.
+    InvalidSubtag(String),
+    /// A two-letter language that isn't an ISO 639-1 code.
+    UnknownLanguage(String),
+    DuplicateVariant(String),
+    DuplicateExtension(char),
+}
+
+impl fmt::Display for LangKeyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LangKeyError::Empty => write!(f, "Empty language tag"),
+            LangKeyError::InvalidSubtag(subtag) => write!(f, "Invalid language tag subtag: \"{subtag}\""),
+            LangKeyError::UnknownLanguage(language) => write!(f, "Unknown language: \"{language}\""),
+            LangKeyError::DuplicateVariant(variant) => write!(f, "Duplicate language tag variant: \"{variant}\""),
+            LangKeyError::DuplicateExtension(singleton) => {
+                write!(f, "Duplicate language tag extension: \"{singleton}\"")
+            }
+        }
+    }
+}
+
+impl std::error::Error for LangKeyError {}
+
+/// A well-formed BCP 47 language tag, e.g. `en`, `en-GB`, `sr-Latn` or `und`.
+///
+/// Tags are case insensitive and kept in their canonical case: `zh-hant-tw` becomes `zh-Hant-TW`.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+pub struct LangKey(String);
+
+impl LangKey {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The primary language subtag, none for grandfathered and private use tags.
+    pub fn language(&self) -> Option<&str> {
+        self.subtags().language
+    }
+
+    pub fn script(&self) -> Option<&str> {
+        self.subtags().script
+    }
+
+    pub fn region(&self) -> Option<&str> {
+        self.subtags().region
+    }
+
+    pub fn variants(&self) -> Vec<&str> {
+        self.subtags().variants
+    }
+
+    /// Replaces deprecated subtags and grandfathered tags by their preferred values, e.g. `iw` by `he` and
+    /// `i-klingon` by `tlh`.
+    pub fn canonicalize(&self) -> LangKey {
+        if let Some((_, preferred)) = grandfathered(&self.0) {
+            return LangKey(preferred.unwrap_or(&self.0).to_string());
+        }
+
+        let mut subtags = self.subtags();
+        subtags.language = subtags
+            .language
+            .map(|language| preferred(DEPRECATED_LANGUAGES, language));
+        subtags.region = subtags.region.map(|region| preferred(DEPRECATED_REGIONS, region));
+
+        LangKey(subtags.to_string())
+    }
+
+    /// Basic filtering of RFC 4647: `*` matches every tag, other ranges match the tag or a prefix of it that ends
+    /// at a subtag, e.g. `en` matches `en-GB` but not `eng`.
+    pub fn matches(&self, range: &str) -> bool {
+        let tag = self.0.as_bytes();
+        let range = range.as_bytes();
+
+        range == b"*"
+            || tag.eq_ignore_ascii_case(range)
+            || (tag.len() > range.len() && tag[range.len()] == b'-' && tag[..range.len()].eq_ignore_ascii_case(range))
+    }
+
+    /// The tag and its truncations in the order of the lookup of RFC 4647, e.g. `sr-Latn-RS`, `sr-Latn` and `sr`.
+    pub fn fallbacks(&self) -> impl Iterator<Item = &str> {
+        std::iter::successors(Some(self.0.as_str()), |tag| {
+            let (rest, _) = tag.rsplit_once('-')?;
+
+            // A singleton never ends a fallback, it's dropped with its subtag.
+            match rest.rsplit_once('-') {
+                Some((before, singleton)) if singleton.len() == 1 => Some(before),
+                None if rest.len() == 1 => None,
+                _ => Some(rest),
+            }
+        })
+    }
+
+    /// The first of `tags` that equals the tag or one of its fallbacks.
+    pub fn lookup<'a>(&self, tags: impl IntoIterator<Item = &'a LangKey>) -> Option<&'a LangKey> {
+        let tags: Vec<_> = tags.into_iter().collect();
+
+        self.fallbacks()
+            .find_map(|fallback| tags.iter().find(|tag| tag.0.eq_ignore_ascii_case(fallback)).copied())
+    }
+
+    fn subtags(&self) -> Subtags<'_> {
+        if grandfathered(&self.0).is_some() {
+            return Subtags::default();
+        }
+
+        Subtags::parse(&self.0).unwrap_or_default()
+    }
+}
+
+impl std::ops::Deref for LangKey {
+    type Target = String;
+    fn deref(&self) -> &String {
+        &self.0
+    }
+}
+
+impl AsRef<str> for LangKey {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for LangKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<LangKey> for String {
+    fn from(value: LangKey) -> Self {
+        value.0
+    }
+}
+
+impl From<&LangKey> for LangKey {
+    fn from(value: &LangKey) -> Self {
+        value.clone()
+    }
+}
+
+impl std::str::FromStr for LangKey {
+    type Err = LangKeyError;
+    fn from_str(value: &str) -> Result<Self, LangKeyError> {
+        if let Some((tag, _)) = grandfathered(value) {
+            return Ok(Self(tag.to_string()));
+        }
+
+        Ok(Self(Subtags::parse(value)?.to_string()))
+    }
+}
+
+impl std::convert::TryFrom<&str> for LangKey {
+    type Error = LangKeyError;
+    fn try_from(value: &str) -> Result<Self, LangKeyError> {
+        value.parse()
+    }
+}
+
+impl std::convert::TryFrom<&String> for LangKey {
+    type Error = LangKeyError;
+    fn try_from(value: &String) -> Result<Self, LangKeyError> {
+        value.parse()
+    }
+}
+
+impl std::convert::TryFrom<String> for LangKey {
+    type Error = LangKeyError;
+    fn try_from(value: String) -> Result<Self, LangKeyError> {
+        value.parse()
+    }
+}
+
+impl<'de> de::Deserialize<'de> for LangKey {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: LangKeyError| <D::Error as de::Error>::custom(e.to_string()))
+    }
+}
+
+/// The subtags of a tag that isn't grandfathered, in the order of RFC 5646. Extensions and private use keep their
+/// singletons.
+#[derive(Default)]
+struct Subtags<'a> {
+    language: Option<&'a str>,
+    extlangs: Vec<&'a str>,
+    script: Option<&'a str>,
+    region: Option<&'a str>,
+    variants: Vec<&'a str>,
+    extensions: Vec<&'a str>,
+    private_use: Vec<&'a str>,
+}
+
+impl<'a> Subtags<'a> {
+    fn parse(value: &'a str) -> Result<Self, LangKeyError> {
+        if value.is_empty() {
+            return Err(LangKeyError::Empty);
+        }
+
+        let mut parts = value.split('-').peekable();
+        let mut subtags = Subtags::default();
+
+        if !parts.peek().is_some_and(|part| part.eq_ignore_ascii_case("x")) {
+            let language = parts.next().unwrap_or_default();
+            if !is_alpha(language, 2, 3) {
+                return Err(LangKeyError::InvalidSubtag(language.to_string()));
+            }
+            if language.len() == 2 && !ISO_639_1.contains(&language.to_ascii_lowercase().as_str()) {
+                return Err(LangKeyError::UnknownLanguage(language.to_string()));
+            }
+            subtags.language = Some(language);
+
+            while subtags.extlangs.len() < 3 {
+                match parts.next_if(|part| is_alpha(part, 3, 3)) {
+                    Some(extlang) => subtags.extlangs.push(extlang),
+                    None => break,
+                }
+            }
+
+            subtags.script = parts.next_if(|part| is_alpha(part, 4, 4));
+            subtags.region = parts.next_if(|part| is_alpha(part, 2, 2) || is_digit(part, 3));
+
+            while let Some(variant) = parts.next_if(|part| is_variant(part)) {
+                if subtags.variants.iter().any(|other| other.eq_ignore_ascii_case(variant)) {
+                    return Err(LangKeyError::DuplicateVariant(variant.to_string()));
+                }
+                subtags.variants.push(variant);
+            }
+
+            while let Some(singleton) = parts.next_if(|part| is_singleton(part)) {
+                let singleton_char = singleton.chars().next().unwrap_or_default().to_ascii_lowercase();
+                if subtags
+                    .extensions
+                    .iter()
+                    .any(|other| other.eq_ignore_ascii_case(singleton))
+                {
+                    return Err(LangKeyError::DuplicateExtension(singleton_char));
+                }
+                subtags.extensions.push(singleton);
+
+                let start = subtags.extensions.len();
+                while let Some(extension) = parts.next_if(|part| is_alphanumeric(part, 2, 8)) {
+                    subtags.extensions.push(extension);
+                }
+                if subtags.extensions.len() == start {
+                    return Err(LangKeyError::InvalidSubtag(singleton.to_string()));
+                }
+            }
+        }
+
+        if let Some(x) = parts.next_if(|part| part.eq_ignore_ascii_case("x")) {
+            subtags.private_use.push(x);
+            while let Some(private_use) = parts.next_if(|part| is_alphanumeric(part, 1, 8)) {
+                subtags.private_use.push(private_use);
+            }
+            if subtags.private_use.len() == 1 {
+                return Err(LangKeyError::InvalidSubtag(x.to_string()));
+            }
+        }
+
+        match parts.next() {
+            Some(part) => Err(LangKeyError::InvalidSubtag(part.to_string())),
+            None => Ok(subtags),
+        }
+    }
+}
+
+/// Joins the subtags in their canonical case.
+impl fmt::Display for Subtags<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let script = self.script.map(|script| {
+            let (first, rest) = script.split_at(1);
+            first.to_ascii_uppercase() + &rest.to_ascii_lowercase()
+        });
+
+        let subtags = self
+            .language
+            .iter()
+            .chain(&self.extlangs)
+            .map(|subtag| subtag.to_ascii_lowercase())
+            .chain(script)
+            .chain(self.region.map(str::to_ascii_uppercase))
+            .chain(
+                self.variants
+                    .iter()
+                    .chain(&self.extensions)
+                    .chain(&self.private_use)
+                    .map(|subtag| subtag.to_ascii_lowercase()),
+            );
+
+        for (i, subtag) in subtags.enumerate() {
+            if i > 0 {
+                f.write_str("-")?;
+            }
+            f.write_str(&subtag)?;
+        }
+
+        Ok(())
+    }
+}
+
+fn grandfathered(value: &str) -> Option<(&'static str, Option<&'static str>)> {
+    GRANDFATHERED
+        .iter()
+        .find(|(tag, _)| tag.eq_ignore_ascii_case(value))
+        .copied()
+}
+
+fn preferred<'a>(table: &[(&str, &'static str)], subtag: &'a str) -> &'a str {
+    table
+        .iter()
+        .find(|(deprecated, _)| deprecated.eq_ignore_ascii_case(subtag))
+        .map_or(subtag, |(_, preferred)| preferred)
+}
+
+fn is_alpha(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_digit(subtag: &str, len: usize) -> bool {
+    subtag.len() == len && subtag.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_alphanumeric(subtag: &str, min: usize, max: usize) -> bool {
+    (min..=max).contains(&subtag.len()) && subtag.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+/// Five to eight characters, or four starting with a digit, e.g. `1901`.
+fn is_variant(subtag: &str) -> bool {
+    is_alphanumeric(subtag, 5, 8) || (is_alphanumeric(subtag, 4, 4) && subtag.as_bytes()[0].is_ascii_digit())
+}
+
+fn is_singleton(subtag: &str) -> bool {
+    is_alphanumeric(subtag, 1, 1) && !subtag.eq_ignore_ascii_case("x")
+}
@@ -1,13 +1,23 @@
 pub use email_address::*;
+pub use json_schema::{schema_for, JsonSchema, SchemaGenerator};
+pub use lang_key::{LangKey, LangKeyError};
 pub use macro_derive::*;
 pub use macro_derive::{EnumDeserialize, TagType};
+pub use query::{JsonPath, Query, QueryError};
+pub use schema_graph::SchemaGraph;
 use serde::Serialize;
 use serde::{de, de::DeserializeOwned, de::Unexpected, Deserializer};
 use std::fmt;
 use traits as types_common;
 pub use traits::*;
+pub use visit::{Accept, AcceptMut, Visit, Visitor, VisitorMut, Walk, WalkMut};
 
+pub mod json_schema;
+mod lang_key;
+pub mod query;
+mod schema_graph;
 mod traits;
+pub mod visit;
 
 #[derive(Clone, Debug)]
 pub enum OneOrMany<T> {
@@ -15,6 +25,51 @@ pub enum OneOrMany<T> {
     Many(Vec<T>),
 }
 
+impl<T> OneOrMany<T> {
+    pub fn as_slice(&self) -> &[T] {
+        match self {
+            OneOrMany::One(value) => std::slice::from_ref(value),
+            OneOrMany::Many(values) => values,
+        }
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Appends `value`, a `One` becomes a `Many`.
+    pub fn push(&mut self, value: T) {
+        match std::mem::replace(self, OneOrMany::Many(vec![])) {
+            OneOrMany::One(first) => *self = OneOrMany::Many(vec![*first, value]),
+            OneOrMany::Many(mut values) => {
+                values.push(value);
+                *self = OneOrMany::Many(values);
+            }
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a OneOrMany<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T> From<T> for OneOrMany<T> {
+    fn from(value: T) -> Self {
+        Self::One(Box::new(value))
+    }
+}
+
+impl<T> From<Vec<T>> for OneOrMany<T> {
+    fn from(values: Vec<T>) -> Self {
+        Self::Many(values)
+    }
+}
+
 impl<'de, T: DeserializeOwned + fmt::Debug> de::Deserialize<'de> for OneOrMany<T> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
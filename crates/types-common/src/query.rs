@@ -0,0 +1,383 @@
+//! JSONPath queries over deserialized credentials, without serializing them to a `serde_json::Value` first.
+//!
+//! `#[derive(Query)]` exposes the fields of a struct under their JSON names. A query returns references into the
+//! credential, [`Query::query_as`] only the matches of a given type:
+//!
+//! ```ignore
+//! let titles = credential.query_as::<serde_json::Value>("$.credentialSubject.hasClaim[*].title.en")?;
+//! ```
+//!
+//! The supported syntax is `$`, `.name`, `['name']`, `[0]`, `[-1]`, `[*]`, `.*` and `..name`. Like in the JSON, an
+//! `Option`, a `Box` or an untagged enum stands for the value it holds and a `OneOrMany` holding one value can be
+//! indexed like an array of one.
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::path::PathBuf;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use email_address::EmailAddress;
+use serde_json::{Map, Value};
+
+use crate::{DurationType, LangKey, OneOrMany, PositiveInteger};
+
+pub trait Query: Any {
+    /// The fields of an object by their JSON names, or the entries of a map.
+    fn fields(&self) -> Vec<(&str, &dyn Query)> {
+        Vec::new()
+    }
+
+    /// The elements of an array, `None` when this isn't one.
+    fn elements(&self) -> Option<Vec<&dyn Query>> {
+        None
+    }
+
+    /// The value this one stands for in the JSON, e.g. the content of a `Box` or the variant of an untagged enum.
+    /// `Some(None)` for an empty `Option`, `None` when this is a value itself.
+    fn wrapped(&self) -> Option<Option<&dyn Query>> {
+        None
+    }
+
+    /// The values that match `path`, see [`JsonPath`].
+    fn query(&self, path: &str) -> Result<Vec<&dyn Query>, QueryError>
+    where
+        Self: Sized,
+    {
+        Ok(JsonPath::parse(path)?.select(self))
+    }
+
+    /// The values of type `T` that match `path`.
+    fn query_as<T: Any>(&self, path: &str) -> Result<Vec<&T>, QueryError>
+    where
+        Self: Sized,
+    {
+        Ok(JsonPath::parse(path)?.select_as(self))
+    }
+}
+
+impl dyn Query {
+    /// The value this one stands for, `None` for an empty `Option`.
+    pub fn value(&self) -> Option<&dyn Query> {
+        let mut value = self;
+
+        while let Some(wrapped) = value.wrapped() {
+            value = wrapped?;
+        }
+
+        Some(value)
+    }
+
+    /// This value as a `T`, or the value it stands for if that is a `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        let mut value = self;
+
+        loop {
+            let any: &dyn Any = value;
+            if let Some(value) = any.downcast_ref() {
+                return Some(value);
+            }
+
+            value = value.wrapped()??;
+        }
+    }
+
+    /// The field `name` of this value.
+    pub fn field(&self, name: &str) -> Option<&dyn Query> {
+        self.value()?
+            .fields()
+            .into_iter()
+            .find(|(field, _)| *field == name)
+            .map(|(_, value)| value)
+    }
+
+    /// The elements of the first array on the way to the value this one stands for.
+    fn array(&self) -> Option<Vec<&dyn Query>> {
+        let mut value = self;
+
+        loop {
+            if let Some(elements) = value.elements() {
+                return Some(elements);
+            }
+
+            value = value.wrapped()??;
+        }
+    }
+
+    /// The elements of an array or the field values of an object.
+    fn children(&self) -> Vec<&dyn Query> {
+        match self.array() {
+            Some(elements) => elements,
+            None => self
+                .value()
+                .map(|value| value.fields().into_iter().map(|(_, value)| value).collect())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryError {
+    pub path: String,
+    pub position: usize,
+    pub message: String,
+}
+
+impl fmt::Display for QueryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Invalid JSONPath {:?} at {}: {}",
+            self.path, self.position, self.message
+        )
+    }
+}
+
+impl std::error::Error for QueryError {}
+
+/// A parsed JSONPath, parse a path once to select it from many credentials.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JsonPath {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Child(Selector),
+    /// `..`, the selector applies to the value and all its descendants.
+    Descendant(Selector),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Selector {
+    Name(String),
+    Index(i64),
+    Wildcard,
+}
+
+impl JsonPath {
+    pub fn parse(path: &str) -> Result<Self, QueryError> {
+        let error = |position: usize, message: &str| QueryError {
+            path: path.to_string(),
+            position,
+            message: message.to_string(),
+        };
+
+        let Some(mut rest) = path.strip_prefix('$') else {
+            return Err(error(0, "A path starts with $"));
+        };
+        let mut segments = vec![];
+
+        while !rest.is_empty() {
+            let position = path.len() - rest.len();
+
+            let (descendant, after_dots) = if let Some(after) = rest.strip_prefix("..") {
+                (true, after)
+            } else if let Some(after) = rest.strip_prefix('.') {
+                (false, after)
+            } else if rest.starts_with('[') {
+                (false, rest)
+            } else {
+                return Err(error(position, "Expected ., .. or ["));
+            };
+
+            let (selector, after) = if let Some(bracket) = after_dots.strip_prefix('[') {
+                let end = bracket.find(']').ok_or_else(|| error(position, "Missing ]"))?;
+                let selector =
+                    parse_bracket(bracket[..end].trim()).ok_or_else(|| error(position, "Invalid selector"))?;
+
+                (selector, &bracket[end + 1..])
+            } else {
+                let end = after_dots.find(['.', '[']).unwrap_or(after_dots.len());
+                let selector = match &after_dots[..end] {
+                    "" => return Err(error(position, "Missing field name")),
+                    "*" => Selector::Wildcard,
+                    name => Selector::Name(name.to_string()),
+                };
+
+                (selector, &after_dots[end..])
+            };
+
+            segments.push(if descendant {
+                Segment::Descendant(selector)
+            } else {
+                Segment::Child(selector)
+            });
+            rest = after;
+        }
+
+        Ok(Self { segments })
+    }
+
+    /// The values that match this path in `root`.
+    pub fn select<'a>(&self, root: &'a dyn Query) -> Vec<&'a dyn Query> {
+        self.matches(root)
+            .into_iter()
+            .filter_map(|value| value.value())
+            .collect()
+    }
+
+    /// The values of type `T` that match this path in `root`.
+    pub fn select_as<'a, T: Any>(&self, root: &'a dyn Query) -> Vec<&'a T> {
+        self.matches(root)
+            .into_iter()
+            .filter_map(|value| value.downcast_ref())
+            .collect()
+    }
+
+    fn matches<'a>(&self, root: &'a dyn Query) -> Vec<&'a dyn Query> {
+        let mut current = vec![root];
+
+        for segment in &self.segments {
+            current = match segment {
+                Segment::Child(selector) => current.into_iter().flat_map(|value| selector.apply(value)).collect(),
+                Segment::Descendant(selector) => current
+                    .into_iter()
+                    .flat_map(descendants)
+                    .flat_map(|value| selector.apply(value))
+                    .collect(),
+            };
+        }
+
+        current
+    }
+}
+
+impl Selector {
+    fn apply<'a>(&self, value: &'a dyn Query) -> Vec<&'a dyn Query> {
+        match self {
+            Selector::Name(name) => value.field(name).into_iter().collect(),
+            Selector::Index(index) => {
+                let elements = value.array().unwrap_or_default();
+                let index = if *index < 0 {
+                    elements.len().checked_sub(index.unsigned_abs() as usize)
+                } else {
+                    Some(*index as usize)
+                };
+
+                index
+                    .and_then(|index| elements.get(index).copied())
+                    .into_iter()
+                    .collect()
+            }
+            Selector::Wildcard => value.children(),
+        }
+    }
+}
+
+/// `value` and everything below it, parents before their children.
+fn descendants(value: &dyn Query) -> Vec<&dyn Query> {
+    let mut descendants = vec![value];
+    let mut i = 0;
+
+    while i < descendants.len() {
+        let children = descendants[i].children();
+        descendants.splice(i + 1..i + 1, children);
+        i += 1;
+    }
+
+    descendants
+}
+
+fn parse_bracket(selector: &str) -> Option<Selector> {
+    if selector == "*" {
+        return Some(Selector::Wildcard);
+    }
+
+    for quote in ['\'', '"'] {
+        if let Some(name) = selector.strip_prefix(quote).and_then(|s| s.strip_suffix(quote)) {
+            return Some(Selector::Name(name.to_string()));
+        }
+    }
+
+    selector.parse().ok().map(Selector::Index)
+}
+
+macro_rules! impl_query {
+    (for $($t:ty),+) => {
+        $(impl Query for $t {})*
+    }
+}
+
+impl_query!(for String, bool, PathBuf);
+impl_query!(for usize, u8, u16, u32, u64, u128);
+impl_query!(for isize, i8, i16, i32, i64, i128);
+impl_query!(for f32, f64);
+impl_query!(for DateTime<Utc>, NaiveDate, EmailAddress, DurationType, LangKey);
+
+impl Query for PositiveInteger {
+    fn wrapped(&self) -> Option<Option<&dyn Query>> {
+        Some(Some(&self.0))
+    }
+}
+
+impl Query for Value {
+    fn fields(&self) -> Vec<(&str, &dyn Query)> {
+        match self {
+            Value::Object(object) => object.fields(),
+            _ => Vec::new(),
+        }
+    }
+
+    fn elements(&self) -> Option<Vec<&dyn Query>> {
+        match self {
+            Value::Array(values) => values.elements(),
+            _ => None,
+        }
+    }
+}
+
+impl Query for Map<String, Value> {
+    fn fields(&self) -> Vec<(&str, &dyn Query)> {
+        self.iter()
+            .map(|(key, value)| (key.as_str(), value as &dyn Query))
+            .collect()
+    }
+}
+
+impl<T: Query> Query for Box<T> {
+    fn wrapped(&self) -> Option<Option<&dyn Query>> {
+        Some(Some(self.as_ref()))
+    }
+}
+
+impl<T: Query> Query for Option<T> {
+    fn wrapped(&self) -> Option<Option<&dyn Query>> {
+        Some(self.as_ref().map(|value| value as &dyn Query))
+    }
+}
+
+impl<T: Query> Query for Vec<T> {
+    fn elements(&self) -> Option<Vec<&dyn Query>> {
+        Some(self.iter().map(|value| value as &dyn Query).collect())
+    }
+}
+
+impl<T: Query> Query for OneOrMany<T> {
+    fn elements(&self) -> Option<Vec<&dyn Query>> {
+        Some(self.iter().map(|value| value as &dyn Query).collect())
+    }
+
+    fn wrapped(&self) -> Option<Option<&dyn Query>> {
+        match self {
+            OneOrMany::One(value) => Some(Some(value.as_ref())),
+            OneOrMany::Many(_) => None,
+        }
+    }
+}
+
+impl<K: AsRef<str> + 'static, V: Query> Query for HashMap<K, V> {
+    fn fields(&self) -> Vec<(&str, &dyn Query)> {
+        self.iter()
+            .map(|(key, value)| (key.as_ref(), value as &dyn Query))
+            .collect()
+    }
+}
+
+impl<K: AsRef<str> + 'static, V: Query> Query for BTreeMap<K, V> {
+    fn fields(&self) -> Vec<(&str, &dyn Query)> {
+        self.iter()
+            .map(|(key, value)| (key.as_ref(), value as &dyn Query))
+            .collect()
+    }
+}
@@ -0,0 +1,220 @@
+//! Renders the rows of `#[derive(GenPaths)]` as a class diagram (Graphviz DOT, Mermaid) or a table (CSV, JSON).
+//!
+//! Every schema with rows is a class. A field whose target is a class is drawn as an association, any other field is
+//! an attribute. A schema whose rows have no field is an enum: its unit variants are listed as values and the classes
+//! of its newtype variants are drawn as specializations of it. The rows don't tell a unit variant from a newtype
+//! variant, so a unit variant named like a class, e.g. `ProofType::Proof`, is drawn as a specialization too.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+
+use crate::{AddSchemaTypes, Multiplicity, SchemaData, SchemaList};
+
+pub struct SchemaGraph {
+    rows: Vec<SchemaData>,
+}
+
+impl SchemaGraph {
+    /// The graph of `T` and every schema reachable from it.
+    pub fn of<T: AddSchemaTypes>() -> Self {
+        let mut rows = vec![];
+        T::add_schema_types(&mut rows);
+
+        Self::from_rows(rows)
+    }
+
+    /// Sorts the rows by source schema, keeping the field order, and merges their multiplicities.
+    pub fn from_rows(mut rows: Vec<SchemaData>) -> Self {
+        rows.sort();
+        rows.merge_multiplicity();
+
+        Self { rows }
+    }
+
+    pub fn rows(&self) -> &[SchemaData] {
+        &self.rows
+    }
+
+    /// The schemas with rows, the classes of the diagrams.
+    pub fn schemas(&self) -> BTreeSet<&str> {
+        self.rows.iter().map(|row| row.src_schema.as_str()).collect()
+    }
+
+    pub fn to_dot(&self) -> String {
+        let classes = self.schemas();
+        let mut dot = String::from("digraph schema {\n    rankdir=LR;\n    node [shape=record];\n\n");
+
+        for (schema, rows) in self.by_schema() {
+            let enumeration = is_enumeration(&rows);
+            let members: String = rows
+                .iter()
+                .filter(|row| !classes.contains(row.tgt_schema.as_str()))
+                .map(|row| {
+                    if enumeration {
+                        format!("{}\\l", dot_escape(&row.tgt_schema))
+                    } else {
+                        format!(
+                            "{}: {} [{}]\\l",
+                            dot_escape(&row.src_field),
+                            dot_escape(&row.tgt_schema),
+                            cardinality(row)
+                        )
+                    }
+                })
+                .collect();
+            let title = if enumeration {
+                format!("\\<\\<enumeration\\>\\>\\n{schema}")
+            } else {
+                schema.to_string()
+            };
+
+            let _ = writeln!(dot, "    \"{schema}\" [label=\"{{{title}|{members}}}\"];");
+        }
+
+        dot.push('\n');
+
+        for row in self.associations() {
+            let _ = if row.src_field.is_empty() {
+                writeln!(
+                    dot,
+                    "    \"{}\" -> \"{}\" [arrowhead=empty];",
+                    row.tgt_schema, row.src_schema
+                )
+            } else {
+                writeln!(
+                    dot,
+                    "    \"{}\" -> \"{}\" [label=\"{} [{}]\"];",
+                    row.src_schema,
+                    row.tgt_schema,
+                    dot_escape(&row.src_field),
+                    cardinality(row)
+                )
+            };
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    pub fn to_mermaid(&self) -> String {
+        let classes = self.schemas();
+        let mut mermaid = String::from("classDiagram\n");
+
+        for (schema, rows) in self.by_schema() {
+            let enumeration = is_enumeration(&rows);
+            let _ = writeln!(mermaid, "    class {schema} {{");
+
+            if enumeration {
+                mermaid.push_str("        <<enumeration>>\n");
+            }
+
+            for row in rows.iter().filter(|row| !classes.contains(row.tgt_schema.as_str())) {
+                let _ = if enumeration {
+                    writeln!(mermaid, "        {}", row.tgt_schema)
+                } else {
+                    writeln!(
+                        mermaid,
+                        "        +{} {} [{}]",
+                        row.tgt_schema,
+                        row.src_field,
+                        cardinality(row)
+                    )
+                };
+            }
+
+            mermaid.push_str("    }\n");
+        }
+
+        for row in self.associations() {
+            let _ = if row.src_field.is_empty() {
+                writeln!(mermaid, "    {} <|-- {}", row.src_schema, row.tgt_schema)
+            } else {
+                writeln!(
+                    mermaid,
+                    "    {} --> \"{}\" {} : {}",
+                    row.src_schema,
+                    cardinality(row),
+                    row.tgt_schema,
+                    row.src_field
+                )
+            };
+        }
+
+        mermaid
+    }
+
+    /// One line per row with a header, `src_schema,src_field,tgt_schema,multiplicity,required`.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("src_schema,src_field,tgt_schema,multiplicity,required\n");
+
+        for row in &self.rows {
+            let _ = writeln!(
+                csv,
+                "{},{},{},{},{}",
+                csv_escape(&row.src_schema),
+                csv_escape(&row.src_field),
+                csv_escape(&row.tgt_schema),
+                row.multiplicity,
+                row.required
+            );
+        }
+
+        csv
+    }
+
+    /// The rows as an array of objects with the fields of [`SchemaData`].
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(&self.rows).expect("schema rows serialize")
+    }
+
+    fn by_schema(&self) -> BTreeMap<&str, Vec<&SchemaData>> {
+        let mut schemas: BTreeMap<&str, Vec<&SchemaData>> = BTreeMap::new();
+
+        for row in &self.rows {
+            schemas.entry(&row.src_schema).or_default().push(row);
+        }
+
+        schemas
+    }
+
+    /// The rows whose target is a class.
+    fn associations(&self) -> impl Iterator<Item = &SchemaData> {
+        let classes = self.schemas();
+
+        self.rows
+            .iter()
+            .filter(move |row| classes.contains(row.tgt_schema.as_str()))
+    }
+}
+
+fn is_enumeration(rows: &[&SchemaData]) -> bool {
+    rows.iter().all(|row| row.src_field.is_empty())
+}
+
+/// The UML multiplicity of a row, e.g. `0..1` for an `Option`.
+fn cardinality(row: &SchemaData) -> &'static str {
+    match (&row.multiplicity, row.required) {
+        (Multiplicity::One, true) => "1",
+        (Multiplicity::One, false) => "0..1",
+        (Multiplicity::OneOrMany, true) => "1..*",
+        (Multiplicity::Many, _) | (Multiplicity::OneOrMany, false) => "0..*",
+    }
+}
+
+fn dot_escape(value: &str) -> String {
+    value
+        .chars()
+        .flat_map(|c| match c {
+            '{' | '}' | '|' | '<' | '>' | '"' | '\\' => vec!['\\', c],
+            c => vec![c],
+        })
+        .collect()
+}
+
+fn csv_escape(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
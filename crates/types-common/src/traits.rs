@@ -1,8 +1,9 @@
 use chrono::{NaiveDate, Utc};
 use email_address::EmailAddress;
+use serde::{Serialize, Serializer};
 use std::{fmt, path::PathBuf};
 
-#[derive(PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Multiplicity {
     One,
     Many,
@@ -19,7 +20,13 @@ impl fmt::Display for Multiplicity {
     }
 }
 
-#[derive(PartialEq, Eq)]
+impl Serialize for Multiplicity {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
 pub struct SchemaData {
     pub src_schema: String,
     pub src_field: String,
@@ -0,0 +1,342 @@
+//! Depth-first traversal of deserialized credentials.
+//!
+//! `#[derive(Visit)]` walks every field of a struct and the value of an enum variant, so a visitor sees each value of
+//! the credential graph: the structs, the `OneOrMany`, `Option` and `Vec` around them and the values they end in. A
+//! visitor gets every value as [`Any`], [`Visit::for_each`] and [`Visit::for_each_mut`] only call back for one type:
+//!
+//! ```ignore
+//! let mut concepts = vec![];
+//! credential.for_each(|concept: &Concept| concepts.push(concept));
+//!
+//! credential.for_each_mut(|person: &mut Person| person.date_of_birth = None);
+//! ```
+//!
+//! The credential crates also generate a typed visitor with [`crate::visitor!`], a `visit_<type>` and
+//! `visit_<type>_mut` method per type. The default methods [`Walk`] the children of the value, an override decides
+//! whether to walk further by calling `walk` itself:
+//!
+//! ```ignore
+//! struct Outcomes(usize);
+//!
+//! impl<'a> ElmVisitor<'a> for Outcomes {
+//!     fn visit_learning_outcome(&mut self, _: &'a LearningOutcome) {
+//!         self.0 += 1;
+//!     }
+//! }
+//!
+//! let mut outcomes = Outcomes(0);
+//! outcomes.visit_europass_edc_credential(&credential);
+//! ```
+
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use email_address::EmailAddress;
+use serde_json::{Map, Value};
+
+use crate::{DurationType, LangKey, OneOrMany, PositiveInteger};
+
+pub trait Visitor<'a> {
+    fn visit(&mut self, node: &'a dyn Any);
+}
+
+impl<'a, F> Visitor<'a> for F
+where
+    F: FnMut(&'a dyn Any),
+{
+    fn visit(&mut self, node: &'a dyn Any) {
+        self(node)
+    }
+}
+
+pub trait VisitorMut {
+    fn visit_mut(&mut self, node: &mut dyn Any);
+}
+
+impl<F> VisitorMut for F
+where
+    F: FnMut(&mut dyn Any),
+{
+    fn visit_mut(&mut self, node: &mut dyn Any) {
+        self(node)
+    }
+}
+
+pub trait Visit: Any {
+    /// Calls `visitor` with this value and then with everything in it, parents before their children.
+    fn visit<'a>(&'a self, visitor: &mut dyn Visitor<'a>);
+
+    /// Calls `visitor` with this value and then with everything in it. Children are visited after the visitor has
+    /// changed their parent.
+    fn visit_mut(&mut self, visitor: &mut dyn VisitorMut);
+
+    /// Calls `callback` with every `T` in this value, including the value itself.
+    fn for_each<'a, T: Any>(&'a self, mut callback: impl FnMut(&'a T))
+    where
+        Self: Sized,
+    {
+        self.visit(&mut |node: &'a dyn Any| {
+            if let Some(node) = node.downcast_ref() {
+                callback(node);
+            }
+        });
+    }
+
+    /// Calls `callback` with every `T` in this value, including the value itself.
+    fn for_each_mut<T: Any>(&mut self, mut callback: impl FnMut(&mut T))
+    where
+        Self: Sized,
+    {
+        self.visit_mut(&mut |node: &mut dyn Any| {
+            if let Some(node) = node.downcast_mut() {
+                callback(node);
+            }
+        });
+    }
+}
+
+/// A value a typed visitor `V` can be called with. Types of the visitor call their `visit_<type>` method, containers
+/// pass every value in them on and everything else is skipped.
+pub trait Accept<'a, V: ?Sized> {
+    fn accept(&'a self, _visitor: &mut V) {}
+}
+
+/// A value a typed visitor `V` can be called with to change it, see [`Accept`].
+pub trait AcceptMut<V: ?Sized> {
+    fn accept_mut(&mut self, _visitor: &mut V) {}
+}
+
+/// Passes the fields of a struct or the value of an enum variant to a typed visitor, `#[derive(Visit)]` implements it.
+pub trait Walk<'a, V: ?Sized> {
+    fn walk(&'a self, visitor: &mut V);
+}
+
+/// Passes the fields of a struct or the value of an enum variant to a typed visitor to change them.
+pub trait WalkMut<V: ?Sized> {
+    fn walk_mut(&mut self, visitor: &mut V);
+}
+
+macro_rules! impl_visit {
+    (for $($t:ty),+) => {
+        $(impl Visit for $t {
+            fn visit<'a>(&'a self, visitor: &mut dyn Visitor<'a>) {
+                visitor.visit(self);
+            }
+
+            fn visit_mut(&mut self, visitor: &mut dyn VisitorMut) {
+                visitor.visit_mut(self);
+            }
+        }
+
+        impl<'a, V: ?Sized> Accept<'a, V> for $t {}
+
+        impl<V: ?Sized> AcceptMut<V> for $t {})*
+    }
+}
+
+impl_visit!(for String, bool, PathBuf);
+impl_visit!(for usize, u8, u16, u32, u64, u128);
+impl_visit!(for isize, i8, i16, i32, i64, i128);
+impl_visit!(for f32, f64);
+impl_visit!(for DateTime<Utc>, NaiveDate, EmailAddress, DurationType, PositiveInteger, LangKey);
+impl_visit!(for Value, Map<String, Value>);
+
+impl<T: Visit> Visit for Box<T> {
+    fn visit<'a>(&'a self, visitor: &mut dyn Visitor<'a>) {
+        visitor.visit(self);
+        self.as_ref().visit(visitor);
+    }
+
+    fn visit_mut(&mut self, visitor: &mut dyn VisitorMut) {
+        visitor.visit_mut(self);
+        self.as_mut().visit_mut(visitor);
+    }
+}
+
+impl<T: Visit> Visit for Option<T> {
+    fn visit<'a>(&'a self, visitor: &mut dyn Visitor<'a>) {
+        visitor.visit(self);
+
+        if let Some(value) = self {
+            value.visit(visitor);
+        }
+    }
+
+    fn visit_mut(&mut self, visitor: &mut dyn VisitorMut) {
+        visitor.visit_mut(self);
+
+        if let Some(value) = self {
+            value.visit_mut(visitor);
+        }
+    }
+}
+
+impl<T: Visit> Visit for Vec<T> {
+    fn visit<'a>(&'a self, visitor: &mut dyn Visitor<'a>) {
+        visitor.visit(self);
+
+        for value in self {
+            value.visit(visitor);
+        }
+    }
+
+    fn visit_mut(&mut self, visitor: &mut dyn VisitorMut) {
+        visitor.visit_mut(self);
+
+        for value in self {
+            value.visit_mut(visitor);
+        }
+    }
+}
+
+impl<T: Visit> Visit for OneOrMany<T> {
+    fn visit<'a>(&'a self, visitor: &mut dyn Visitor<'a>) {
+        visitor.visit(self);
+
+        for value in self {
+            value.visit(visitor);
+        }
+    }
+
+    fn visit_mut(&mut self, visitor: &mut dyn VisitorMut) {
+        visitor.visit_mut(self);
+
+        match self {
+            OneOrMany::One(value) => value.visit_mut(visitor),
+            OneOrMany::Many(values) => values.iter_mut().for_each(|value| value.visit_mut(visitor)),
+        }
+    }
+}
+
+/// The keys of a map aren't visited, they can't be changed in place.
+impl<K: 'static, V: Visit> Visit for HashMap<K, V> {
+    fn visit<'a>(&'a self, visitor: &mut dyn Visitor<'a>) {
+        visitor.visit(self);
+
+        for value in self.values() {
+            value.visit(visitor);
+        }
+    }
+
+    fn visit_mut(&mut self, visitor: &mut dyn VisitorMut) {
+        visitor.visit_mut(self);
+
+        for value in self.values_mut() {
+            value.visit_mut(visitor);
+        }
+    }
+}
+
+/// The keys of a map aren't visited, they can't be changed in place.
+impl<K: 'static, V: Visit> Visit for BTreeMap<K, V> {
+    fn visit<'a>(&'a self, visitor: &mut dyn Visitor<'a>) {
+        visitor.visit(self);
+
+        for value in self.values() {
+            value.visit(visitor);
+        }
+    }
+
+    fn visit_mut(&mut self, visitor: &mut dyn VisitorMut) {
+        visitor.visit_mut(self);
+
+        for value in self.values_mut() {
+            value.visit_mut(visitor);
+        }
+    }
+}
+
+impl<'a, V: ?Sized, T: Accept<'a, V>> Accept<'a, V> for Box<T> {
+    fn accept(&'a self, visitor: &mut V) {
+        self.as_ref().accept(visitor);
+    }
+}
+
+impl<V: ?Sized, T: AcceptMut<V>> AcceptMut<V> for Box<T> {
+    fn accept_mut(&mut self, visitor: &mut V) {
+        self.as_mut().accept_mut(visitor);
+    }
+}
+
+impl<'a, V: ?Sized, T: Accept<'a, V>> Accept<'a, V> for Option<T> {
+    fn accept(&'a self, visitor: &mut V) {
+        if let Some(value) = self {
+            value.accept(visitor);
+        }
+    }
+}
+
+impl<V: ?Sized, T: AcceptMut<V>> AcceptMut<V> for Option<T> {
+    fn accept_mut(&mut self, visitor: &mut V) {
+        if let Some(value) = self {
+            value.accept_mut(visitor);
+        }
+    }
+}
+
+impl<'a, V: ?Sized, T: Accept<'a, V>> Accept<'a, V> for Vec<T> {
+    fn accept(&'a self, visitor: &mut V) {
+        for value in self {
+            value.accept(visitor);
+        }
+    }
+}
+
+impl<V: ?Sized, T: AcceptMut<V>> AcceptMut<V> for Vec<T> {
+    fn accept_mut(&mut self, visitor: &mut V) {
+        for value in self {
+            value.accept_mut(visitor);
+        }
+    }
+}
+
+impl<'a, V: ?Sized, T: Accept<'a, V>> Accept<'a, V> for OneOrMany<T> {
+    fn accept(&'a self, visitor: &mut V) {
+        for value in self {
+            value.accept(visitor);
+        }
+    }
+}
+
+impl<V: ?Sized, T: AcceptMut<V>> AcceptMut<V> for OneOrMany<T> {
+    fn accept_mut(&mut self, visitor: &mut V) {
+        match self {
+            OneOrMany::One(value) => value.accept_mut(visitor),
+            OneOrMany::Many(values) => values.iter_mut().for_each(|value| value.accept_mut(visitor)),
+        }
+    }
+}
+
+impl<'a, K, V: ?Sized, T: Accept<'a, V>> Accept<'a, V> for HashMap<K, T> {
+    fn accept(&'a self, visitor: &mut V) {
+        for value in self.values() {
+            value.accept(visitor);
+        }
+    }
+}
+
+impl<K, V: ?Sized, T: AcceptMut<V>> AcceptMut<V> for HashMap<K, T> {
+    fn accept_mut(&mut self, visitor: &mut V) {
+        for value in self.values_mut() {
+            value.accept_mut(visitor);
+        }
+    }
+}
+
+impl<'a, K, V: ?Sized, T: Accept<'a, V>> Accept<'a, V> for BTreeMap<K, T> {
+    fn accept(&'a self, visitor: &mut V) {
+        for value in self.values() {
+            value.accept(visitor);
+        }
+    }
+}
+
+impl<K, V: ?Sized, T: AcceptMut<V>> AcceptMut<V> for BTreeMap<K, T> {
+    fn accept_mut(&mut self, visitor: &mut V) {
+        for value in self.values_mut() {
+            value.accept_mut(visitor);
+        }
+    }
+}
@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+# Downloads the SKOS dumps of the EU vocabularies bundled in `vocabularies/` from the Publications Office, as Turtle
+# by content negotiation on the scheme URIs. `cargo xtask vocabularies` then regenerates the JSON snapshots from them.
+
+mkdir -p "$(dirname $0)/vocabularies/skos"
+cd "$(dirname $0)/vocabularies/skos"
+
+fetch() {
+    wget --header "Accept: text/turtle" -O "$1.ttl" "$2"
+}
+
+fetch eqf http://data.europa.eu/snb/eqf/25831c2
+fetch isced-f http://data.europa.eu/snb/isced-f/25831c2
+fetch country http://publications.europa.eu/resource/authority/country
+fetch language http://publications.europa.eu/resource/authority/language
+fetch credential http://data.europa.eu/snb/credential/25831c2
+fetch learning-assessment http://data.europa.eu/snb/learning-assessment/25831c2
+fetch learning-setting http://data.europa.eu/snb/learning-setting/25831c2
+fetch verification http://data.europa.eu/snb/verification/25831c2
+fetch verification-status http://data.europa.eu/snb/verification-status/25831c2
@@ -0,0 +1,74 @@
+//! JSON Schema of the types with their own serde implementation, the others derive it.
+
+use serde_json::{json, Value};
+use types_common::{JsonSchema, SchemaGenerator};
+
+use crate::{LangKV, LangKVPairs, MailTo, Percentage, ProofType, StatusListIndex, UriType};
+
+/// A text or texts in one language.
+fn lang_value() -> Value {
+    json!({ "oneOf": [{ "type": "string" }, { "type": "array", "items": { "type": "string" } }] })
+}
+
+impl JsonSchema for LangKV {
+    fn schema_name() -> Option<&'static str> {
+        Some("LangKV")
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+        json!({
+            "type": "object",
+            "minProperties": 1,
+            "maxProperties": 1,
+            "additionalProperties": lang_value()
+        })
+    }
+}
+
+impl JsonSchema for LangKVPairs {
+    fn schema_name() -> Option<&'static str> {
+        Some("LangKVPairs")
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "object", "minProperties": 1, "additionalProperties": lang_value() })
+    }
+}
+
+impl JsonSchema for MailTo {
+    fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "string", "pattern": "^mailto:" })
+    }
+}
+
+impl JsonSchema for Percentage {
+    fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "integer", "minimum": 0, "maximum": 100 })
+    }
+}
+
+impl JsonSchema for ProofType {
+    fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+        let known = [
+            ProofType::Proof,
+            ProofType::JsonWebSignature2020,
+            ProofType::Ed25519Signature2020,
+            ProofType::EcdsaSecp256k1Signature2019,
+            ProofType::DataIntegrityProof,
+        ];
+
+        json!({ "type": "string", "examples": known.iter().map(ProofType::as_str).collect::<Vec<_>>() })
+    }
+}
+
+impl JsonSchema for StatusListIndex {
+    fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "string", "pattern": "^[0-9]+$" })
+    }
+}
+
+impl JsonSchema for UriType {
+    fn json_schema(_generator: &mut SchemaGenerator) -> Value {
+        json!({ "type": "string", "format": "uri" })
+    }
+}
@@ -0,0 +1,191 @@
+//! Typed access to multilingual texts.
+//!
+//! A language value is a string or an array of strings, e.g. `{"en": "Diploma"}` or `{"en": ["David", "Smith"]}`.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::{error::ConversionError, Concept, DisplayParameter, LangKV, LangKVPairs, LangKey};
+
+/// The codes of the EU language authority table, e.g. `.../authority/language/ENG`, and their ISO 639-1 codes.
+const AUTHORITY_LANGUAGES: &[(&str, &str)] = &[
+    ("ARA", "ar"),
+    ("BOS", "bs"),
+    ("BUL", "bg"),
+    ("CAT", "ca"),
+    ("CES", "cs"),
+    ("CYM", "cy"),
+    ("DAN", "da"),
+    ("DEU", "de"),
+    ("ELL", "el"),
+    ("ENG", "en"),
+    ("EST", "et"),
+    ("EUS", "eu"),
+    ("FIN", "fi"),
+    ("FRA", "fr"),
+    ("GLE", "ga"),
+    ("GLG", "gl"),
+    ("HRV", "hr"),
+    ("HUN", "hu"),
+    ("ISL", "is"),
+    ("ITA", "it"),
+    ("JPN", "ja"),
+    ("LAV", "lv"),
+    ("LIT", "lt"),
+    ("LTZ", "lb"),
+    ("MKD", "mk"),
+    ("MLT", "mt"),
+    ("NLD", "nl"),
+    ("NNO", "nn"),
+    ("NOB", "nb"),
+    ("NOR", "no"),
+    ("POL", "pl"),
+    ("POR", "pt"),
+    ("RON", "ro"),
+    ("RUS", "ru"),
+    ("SLK", "sk"),
+    ("SLV", "sl"),
+    ("SPA", "es"),
+    ("SQI", "sq"),
+    ("SRP", "sr"),
+    ("SWE", "sv"),
+    ("TUR", "tr"),
+    ("UKR", "uk"),
+    ("ZHO", "zh"),
+];
+
+impl LangKVPairs {
+    /// The first text in `lang`.
+    pub fn text(&self, lang: &str) -> Option<&str> {
+        self.texts(lang).next()
+    }
+
+    /// Every text in `lang`.
+    pub fn texts(&self, lang: &str) -> impl Iterator<Item = &str> {
+        self.0
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(lang))
+            .into_iter()
+            .flat_map(|(_, value)| value_texts(value))
+    }
+
+    /// Every text with its language, ordered by language.
+    pub fn iter_texts(&self) -> impl Iterator<Item = (&LangKey, &str)> {
+        let mut pairs: Vec<_> = self.0.iter().collect();
+        pairs.sort_by_key(|(key, _)| *key);
+
+        pairs
+            .into_iter()
+            .flat_map(|(key, value)| value_texts(value).map(move |text| (key, text)))
+    }
+
+    /// The text in the first of `langs` that has one, else the text in the first language in alphabetical order.
+    ///
+    /// A language falls back to its less specific tags, `en-GB` takes the text in `en` when there's none in `en-GB`.
+    pub fn text_in(&self, langs: &[&str]) -> Option<&str> {
+        langs
+            .iter()
+            .find_map(|lang| match lang.parse::<LangKey>() {
+                Ok(lang) => lang.fallbacks().find_map(|fallback| self.text(fallback)),
+                Err(_) => self.text(lang),
+            })
+            .or_else(|| self.iter_texts().next().map(|(_, text)| text))
+    }
+
+    /// The text in `lang`, else in the primary language of the credential, else in any language.
+    pub fn localized(&self, lang: &str, display: &DisplayParameter) -> Option<&str> {
+        match display.primary_lang() {
+            Some(primary) => self.text_in(&[lang, primary.as_str()]),
+            None => self.text_in(&[lang]),
+        }
+    }
+}
+
+/// Builds the pairs from `[(lang, text)]`, texts in the same language become an array.
+impl<const N: usize> TryFrom<[(&str, &str); N]> for LangKVPairs {
+    type Error = ConversionError;
+
+    fn try_from(texts: [(&str, &str); N]) -> Result<Self, ConversionError> {
+        let mut kv_pairs: HashMap<LangKey, Value> = HashMap::new();
+
+        for (lang, text) in texts {
+            let lang: LangKey = lang.parse()?;
+
+            match kv_pairs.get_mut(&lang) {
+                None => {
+                    kv_pairs.insert(lang, text.into());
+                }
+                Some(Value::Array(values)) => values.push(text.into()),
+                Some(value) => *value = Value::Array(vec![value.take(), text.into()]),
+            }
+        }
+
+        LangKVPairs::new(kv_pairs).ok_or_else(|| "Requires at least one lang pair".into())
+    }
+}
+
+impl LangKV {
+    pub fn lang(&self) -> &str {
+        self.0.keys().next().expect("Contains one pair")
+    }
+
+    /// The first text.
+    pub fn text(&self) -> Option<&str> {
+        self.texts().next()
+    }
+
+    pub fn texts(&self) -> impl Iterator<Item = &str> {
+        self.0.values().flat_map(value_texts)
+    }
+}
+
+impl TryFrom<(&str, &str)> for LangKV {
+    type Error = ConversionError;
+
+    fn try_from((lang, text): (&str, &str)) -> Result<Self, ConversionError> {
+        let lang: LangKey = lang.parse()?;
+
+        let mut kv_pair = serde_json::Map::new();
+        kv_pair.insert(lang.to_string(), text.into());
+
+        Ok(LangKV(kv_pair))
+    }
+}
+
+impl Concept {
+    /// The language of a language concept, from its notation or its EU language authority id.
+    pub fn lang_key(&self) -> Option<LangKey> {
+        let id = self.id.as_ref().map(|id| id.to_string());
+        let id_code = id.as_deref().and_then(|id| id.rsplit('/').next());
+
+        self.notation
+            .as_ref()
+            .map(|notation| notation.as_str())
+            .into_iter()
+            .chain(id_code)
+            .find_map(|code| {
+                AUTHORITY_LANGUAGES
+                    .iter()
+                    .find(|(authority, _)| authority.eq_ignore_ascii_case(code))
+                    .map_or(code, |(_, lang)| lang)
+                    .parse()
+                    .ok()
+            })
+    }
+}
+
+impl DisplayParameter {
+    pub fn primary_lang(&self) -> Option<LangKey> {
+        self.primary_language.lang_key()
+    }
+}
+
+fn value_texts(value: &Value) -> impl Iterator<Item = &str> {
+    let values = match value {
+        Value::Array(values) => values.as_slice(),
+        value => std::slice::from_ref(value),
+    };
+
+    values.iter().filter_map(Value::as_str)
+}
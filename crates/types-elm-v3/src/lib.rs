@@ -1,9 +1,19 @@
 use chrono::{DateTime, Utc};
-use std::fmt;
 use serde::{de, Deserialize, Serialize};
+use std::fmt;
 use std::{collections::HashMap, ops};
-use types_common::{DurationType, EmailAddress, EnumDeserialize, OneOrMany, PositiveInteger, TagType};
-use types_common::{GenPaths, SchemaList};
+use types_common::{Builder, DurationType, EmailAddress, EnumDeserialize, OneOrMany, PositiveInteger, TagType};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
+
+pub use types_common::LangKey;
+
+mod json_schema;
+pub mod lang;
+pub mod presentation;
+#[cfg(feature = "proof")]
+pub mod proof;
+pub mod verification;
+pub mod vocabulary;
 
 /// Error types.
 pub mod error {
@@ -30,9 +40,14 @@ pub mod error {
             Self(value.into())
         }
     }
+    impl From<types_common::LangKeyError> for ConversionError {
+        fn from(value: types_common::LangKeyError) -> Self {
+            Self(value.to_string().into())
+        }
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(rename_all = "camelCase")]
 pub struct EuropassEdcCredential {
     #[serde(rename = "@context")]
@@ -43,7 +58,7 @@ pub struct EuropassEdcCredential {
     pub credential_schema: OneOrMany<CredentialSchema>,
 
     #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub credential_status: Option<EuropassEdcCredentialCredentialStatus>,
+    pub credential_status: Option<CredentialStatusValue>,
 
     pub credential_subject: OneOrMany<AgentOrPersonOrOrganisation>,
 
@@ -57,6 +72,8 @@ pub struct EuropassEdcCredential {
     /// DID of the credential issuer
     pub issuer: Organisation,
     #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof: Option<OneOrMany<Proof>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub terms_of_use: Option<OneOrMany<TermsOfUseValue>>,
     /// Full type chain, used to identify the credential base types
     #[serde(rename = "type")]
@@ -68,7 +85,7 @@ pub struct EuropassEdcCredential {
     pub valid_until: Option<DateTime<Utc>>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct Accreditation {
     #[serde(rename = "accreditingAgent")]
@@ -120,7 +137,7 @@ pub struct Accreditation {
     pub type_: OneOrMany<AccreditationTag>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct Address {
     #[serde(rename = "countryCode")]
@@ -135,7 +152,7 @@ pub struct Address {
     pub type_: AddressTag,
 }
 
-#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths)]
+#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AgentOrPersonOrOrganisation {
     Agent(Box<Agent>),
@@ -143,6 +160,16 @@ pub enum AgentOrPersonOrOrganisation {
     Organisation(Box<Organisation>),
 }
 
+impl AgentOrPersonOrOrganisation {
+    pub fn id(&self) -> Option<&UriType> {
+        match self {
+            AgentOrPersonOrOrganisation::Agent(agent) => agent.id.as_ref(),
+            AgentOrPersonOrOrganisation::Person(person) => person.id.as_ref(),
+            AgentOrPersonOrOrganisation::Organisation(organisation) => organisation.id.as_ref(),
+        }
+    }
+}
+
 //impl AddSchemaTypes for AgentOrPersonOrOrganisation {
 //fn add_enum_types(src_schema: &str, json_path: &str, optional: bool) -> Vec<SchemaData> {
 //let mut schemas = Vec::new();
@@ -157,7 +184,7 @@ pub enum AgentOrPersonOrOrganisation {
 //}
 //}
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct Agent {
     #[serde(rename = "additionalNote", default, skip_serializing_if = "Option::is_none")]
@@ -182,7 +209,7 @@ pub struct Agent {
     pub type_: AgentTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct Amount {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -193,7 +220,7 @@ pub struct Amount {
     pub type_: AmountTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct AwardingOpportunity {
     #[serde(rename = "awardingBody")]
@@ -212,7 +239,7 @@ pub struct AwardingOpportunity {
     pub type_: AwardingOpportunityTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct AwardingProcess {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -238,7 +265,22 @@ pub struct AwardingProcess {
     pub type_: AwardingProcessTag,
 }
 
-#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths)]
+/// A `credentialStatus` in a Bitstring Status List.
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct BitstringStatusListEntry {
+    pub id: UriType,
+    pub status_purpose: StatusPurpose,
+    pub status_list_index: StatusListIndex,
+    pub status_list_credential: UriType,
+    #[serde(rename = "type")]
+    pub type_: BitstringStatusListEntryTag,
+    /// Other properties of the entry, e.g. `statusSize`, `statusMessage` or `ttl`.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum ClaimNode {
     LearningAchievement(Box<LearningAchievement>),
@@ -248,7 +290,7 @@ pub enum ClaimNode {
     ClaimNodeType(Box<ClaimTypeNode>),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ClaimTypeNode {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -267,7 +309,7 @@ pub struct ClaimTypeNode {
     pub type_: ClaimTypeNodeTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct ConceptScheme {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -276,7 +318,7 @@ pub struct ConceptScheme {
     pub type_: ConceptSchemeTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct Concept {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -293,7 +335,7 @@ pub struct Concept {
     pub type_: ConceptTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct ContactPoint {
     #[serde(rename = "additionalNote", default, skip_serializing_if = "Option::is_none")]
@@ -314,7 +356,9 @@ pub struct ContactPoint {
     pub type_: ContactPointTag,
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub enum CredentialSchemaType {
     JsonSchema,
     ShaclValidator2017,
@@ -329,7 +373,7 @@ impl fmt::Display for CredentialSchemaType {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct CredentialStatus {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -338,14 +382,45 @@ pub struct CredentialStatus {
     pub type_: CredentialStatusTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, GenPaths)]
+/// A `credentialStatus`, picked by its `type`. Statuses of other types are kept as they are.
+#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths, JsonSchema, Query, Visit)]
+#[serde(untagged)]
+pub enum CredentialStatusValue {
+    CredentialStatus(Box<CredentialStatus>),
+    StatusList2021Entry(Box<StatusList2021Entry>),
+    BitstringStatusListEntry(Box<BitstringStatusListEntry>),
+    #[enum_deserialize(other)]
+    Other(serde_json::Value),
+}
+
+impl CredentialStatusValue {
+    pub fn id(&self) -> Option<String> {
+        match self {
+            CredentialStatusValue::CredentialStatus(status) => status.id.as_ref().map(|id| id.to_string()),
+            CredentialStatusValue::StatusList2021Entry(entry) => Some(entry.id.to_string()),
+            CredentialStatusValue::BitstringStatusListEntry(entry) => Some(entry.id.to_string()),
+            CredentialStatusValue::Other(value) => value.get("id").and_then(|id| id.as_str()).map(String::from),
+        }
+    }
+
+    pub fn type_name(&self) -> Option<&str> {
+        match self {
+            CredentialStatusValue::CredentialStatus(status) => Some(status.type_.as_str()),
+            CredentialStatusValue::StatusList2021Entry(entry) => Some(entry.type_.as_str()),
+            CredentialStatusValue::BitstringStatusListEntry(entry) => Some(entry.type_.as_str()),
+            CredentialStatusValue::Other(value) => value.get("type").and_then(|type_| type_.as_str()),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, GenPaths, JsonSchema, Query, Visit, Builder)]
 pub struct CredentialSubject {
     ///Defines the DID of the subject that is described by the issued credential
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct CreditPoint {
     pub framework: Concept,
@@ -356,7 +431,7 @@ pub struct CreditPoint {
     pub type_: CreditPointTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct DisplayDetail {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -367,7 +442,7 @@ pub struct DisplayDetail {
     pub type_: DisplayDetailTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct DisplayParameter {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -384,7 +459,7 @@ pub struct DisplayParameter {
     pub type_: DisplayParameterTag,
 }
 
-#[derive(Clone, Debug, Serialize, GenPaths)]
+#[derive(Clone, Debug, Serialize, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum EuropassEdcCredentialContext {
     One(String),
@@ -433,15 +508,6 @@ impl<'de> de::Deserialize<'de> for EuropassEdcCredentialContext {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, GenPaths)]
-pub struct EuropassEdcCredentialCredentialStatus {
-    ///Exact identity for the credential status
-    pub id: UriType,
-    ///Defines the revocation type extension
-    #[serde(rename = "type")]
-    pub revocation_type: String,
-}
-
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(untagged)]
 pub enum EuropassEdcCredentialIssuer {
@@ -452,14 +518,14 @@ pub enum EuropassEdcCredentialIssuer {
     },
 }
 
-#[derive(Serialize, Deserialize, Clone, Debug, GenPaths)]
+#[derive(Serialize, Deserialize, Clone, Debug, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum DataOrUri {
     Data(AgentOrPersonOrOrganisation),
     GenericId(UriType),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct EuropeanDigitalCredential {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -469,7 +535,7 @@ pub struct EuropeanDigitalCredential {
     #[serde(rename = "credentialSchema")]
     pub credential_schema: OneOrMany<CredentialSchema>,
     #[serde(rename = "credentialStatus", default, skip_serializing_if = "Option::is_none")]
-    pub credential_status: Option<OneOrMany<CredentialStatus>>,
+    pub credential_status: Option<OneOrMany<CredentialStatusValue>>,
     #[serde(rename = "credentialSubject")]
     pub credential_subject: AgentOrPersonOrOrganisation,
     #[serde(rename = "displayParameter")]
@@ -500,7 +566,7 @@ pub struct EuropeanDigitalCredential {
     pub type_: EuropeanDigitalCredentialTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct EuropeanDigitalPresentation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -517,7 +583,7 @@ pub struct EuropeanDigitalPresentation {
     pub type_: EuropeanDigitalPresentationTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Evidence {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -537,7 +603,7 @@ pub struct Evidence {
     pub type_: EvidenceTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct Geometry {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -550,7 +616,7 @@ pub struct Geometry {
     pub type_: GeometryTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct GradingScheme {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -566,7 +632,7 @@ pub struct GradingScheme {
     pub type_: GradingSchemeTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct Grant {
     #[serde(rename = "contentURL", default, skip_serializing_if = "Option::is_none")]
@@ -584,7 +650,7 @@ pub struct Grant {
     pub type_: GrantTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Group {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -604,7 +670,9 @@ pub struct Group {
     pub type_: GroupTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub struct HtmlType(pub String);
 impl std::ops::Deref for HtmlType {
     type Target = String;
@@ -613,14 +681,14 @@ impl std::ops::Deref for HtmlType {
     }
 }
 
-#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths)]
+#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths, JsonSchema, Query, Visit)]
 #[serde(rename_all = "camelCase", untagged)]
 pub enum IdentifierOrLegalIdentifier {
     Identifier(Box<Identifier>),
     LegalIdentifier(Box<LegalIdentifier>),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Identifier {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -644,7 +712,7 @@ pub struct Identifier {
     pub type_: IdentifierTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct IndividualDisplay {
     pub display_detail: OneOrMany<DisplayDetail>,
@@ -655,7 +723,9 @@ pub struct IndividualDisplay {
     pub type_: IndividualDisplayTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub struct IriType(pub String);
 impl std::ops::Deref for IriType {
     type Target = String;
@@ -664,7 +734,7 @@ impl std::ops::Deref for IriType {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct IssuerNode {
     pub eidas_legal_identifier: LegalIdentifier,
@@ -674,7 +744,7 @@ pub struct IssuerNode {
     pub type_: IssuerNodeTag,
 }
 
-#[derive(Clone, Debug, Serialize, GenPaths)]
+#[derive(Clone, Debug, Serialize, GenPaths, Query, Visit)]
 pub struct LangKV(pub serde_json::Map<String, serde_json::Value>);
 
 impl LangKV {
@@ -709,14 +779,14 @@ impl<'de> Deserialize<'de> for LangKV {
     }
 }
 
-#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths)]
+#[derive(Clone, Debug, Serialize, EnumDeserialize, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum LearningAchievementSpecificationOrQualification {
     LearningAchievementSpecification(Box<LearningAchievementSpecification>),
     Qualification(Box<Qualification>),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningAchievementSpecification {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -788,7 +858,7 @@ pub struct LearningAchievementSpecification {
     pub type_: LearningAchievementSpecificationTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningAchievement {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -825,7 +895,7 @@ pub struct LearningAchievement {
     pub type_: LearningAchievementTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningActivitySpecification {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -873,7 +943,7 @@ pub struct LearningActivitySpecification {
     pub type_: LearningActivitySpecificationTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningActivity {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -914,7 +984,7 @@ pub struct LearningActivity {
     pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct LearningAssessmentSpecification {
     #[serde(rename = "additionalNote", default, skip_serializing_if = "Option::is_none")]
@@ -960,7 +1030,7 @@ pub struct LearningAssessmentSpecification {
     pub type_: LearningAssessmentSpecificationTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningAssessment {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1004,7 +1074,7 @@ pub struct LearningAssessment {
     pub type_: LearningAssessmentTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningEntitlementSpecification {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1052,7 +1122,7 @@ pub struct LearningEntitlementSpecification {
     pub type_: LearningEntitlementSpecificationTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningEntitlement {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1085,7 +1155,7 @@ pub struct LearningEntitlement {
     pub type_: LearningEntitlementTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningOpportunity {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1147,7 +1217,7 @@ pub struct LearningOpportunity {
     pub type_: LearningOpportunityTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LearningOutcome {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1169,7 +1239,7 @@ pub struct LearningOutcome {
     pub type_: LearningOutcomeTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct LegalIdentifier {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1194,7 +1264,7 @@ pub struct LegalIdentifier {
     pub type_: LegalIdentifierTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, GenPaths, JsonSchema, Query, Visit)]
 pub struct Literal(pub String);
 
 impl std::ops::Deref for Literal {
@@ -1204,7 +1274,7 @@ impl std::ops::Deref for Literal {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Location {
     pub address: Option<OneOrMany<Address>>,
@@ -1224,7 +1294,7 @@ pub struct Location {
     pub type_: LocationTag,
 }
 
-#[derive(Debug, Clone, GenPaths)]
+#[derive(Debug, Clone, GenPaths, Query, Visit)]
 pub struct MailTo(String);
 
 impl ops::Deref for MailTo {
@@ -1264,14 +1334,14 @@ impl<'de> Deserialize<'de> for MailTo {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum Email {
     EmailAddress(EmailAddress),
     MailTo(MailTo),
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct Mailbox {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1280,7 +1350,7 @@ pub struct Mailbox {
     pub type_: MailboxTag,
 }
 
-#[derive(Clone, Debug, Serialize, GenPaths)]
+#[derive(Clone, Debug, Serialize, GenPaths, Query, Visit)]
 pub struct LangKVPairs(HashMap<LangKey, serde_json::Value>);
 
 impl LangKVPairs {
@@ -1317,41 +1387,7 @@ impl<'de> Deserialize<'de> for LangKVPairs {
     }
 }
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
-pub struct LangKey(String);
-impl std::ops::Deref for LangKey {
-    type Target = String;
-    fn deref(&self) -> &String {
-        &self.0
-    }
-}
-
-impl std::str::FromStr for LangKey {
-    type Err = self::error::ConversionError;
-    fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
-        let regex_str =
-            "^(aa|ab|ae|af|ak|am|an|ar|as|av|ay|az|ba|be|bg|bh|bi|bm|bn|bo|br|bs|ca|ce|ch|co|cr|cs|cu|cv|cy|da|de|dv|dz|ee|el|en|eo|es|et|eu|fa|ff|fi|fj|fo|fr|fy|ga|gd|gl|gn|gu|gv|ha|he|hi|ho|hr|ht|hu|hy|hz|ia|id|ie|ig|ii|ik|in|io|is|it|iu|iw|ja|ji|jv|jw|ka|kg|ki|kj|kk|kl|km|kn|ko|kr|ks|ku|kv|kw|ky|la|lb|lg|li|ln|lo|lt|lu|lv|mg|mh|mi|mk|ml|mn|mo|mr|ms|mt|my|na|nb|nd|ne|ng|nl|nn|no|nr|nv|ny|oc|oj|om|or|os|pa|pi|pl|ps|pt|qu|rm|rn|ro|ru|rw|sa|sc|sd|se|sg|sh|si|sk|sl|sm|sn|so|sq|sr|ss|st|su|sv|sw|ta|te|tg|th|ti|tk|tl|tn|to|tr|ts|tt|tw|ty|ug|uk|ur|uz|ve|vi|vo|wa|wo|xh|yi|yo|za|zh|zu)$";
-
-        if regex::Regex::new(regex_str).unwrap().is_match(value) {
-            Ok(Self(value.to_string()))
-        } else {
-            Err(format!("Doesn't match the pattern: \"{value}\"").into())
-        }
-    }
-}
-
-impl<'de> serde::Deserialize<'de> for LangKey {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        String::deserialize(deserializer)?
-            .parse()
-            .map_err(|e: self::error::ConversionError| <D::Error as serde::de::Error>::custom(e.to_string()))
-    }
-}
-
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct MediaObject {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1373,7 +1409,7 @@ pub struct MediaObject {
     pub type_: MediaObjectTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Note {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1387,7 +1423,7 @@ pub struct Note {
     pub type_: NoteTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Organisation {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1432,7 +1468,7 @@ pub struct Organisation {
     pub type_: OrganisationTag,
 }
 
-#[derive(Clone, Debug, Serialize, GenPaths)]
+#[derive(Clone, Debug, Serialize, GenPaths, Query, Visit)]
 pub struct Percentage(u32);
 
 impl Percentage {
@@ -1471,7 +1507,7 @@ impl<'de> Deserialize<'de> for Percentage {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct PeriodOfTime {
     #[serde(rename = "endDate", default, skip_serializing_if = "Option::is_none")]
@@ -1486,7 +1522,7 @@ pub struct PeriodOfTime {
     pub type_: PeriodOfTimeTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Person {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1531,7 +1567,7 @@ pub struct Person {
     pub type_: PersonTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Phone {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1548,7 +1584,7 @@ pub struct Phone {
     pub type_: PhoneTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct PriceDetail {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1567,16 +1603,103 @@ pub struct PriceDetail {
     pub type_: PriceDetailTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
-#[serde(deny_unknown_fields)]
+/// A proof of a credential or presentation, e.g. a `JsonWebSignature2020` with a detached `jws`.
+#[derive(Clone, Debug, Deserialize, Serialize, GenPaths, JsonSchema, Query, Visit, Builder)]
+#[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Proof {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub challenge: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Utc>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cryptosuite: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub domain: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<DateTime<Utc>>,
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub id: Option<UriType>,
+    /// A detached JWS, `<header>..<signature>`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub jws: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_purpose: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proof_value: Option<String>,
     #[serde(rename = "type")]
-    pub type_: ProofTag,
+    pub type_: ProofType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub verification_method: Option<String>,
+}
+
+/// The proof suite of a `Proof`, suites that aren't listed are kept by name.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, GenPaths, Visit)]
+pub enum ProofType {
+    /// The generic ELM `Proof`.
+    Proof,
+    JsonWebSignature2020,
+    Ed25519Signature2020,
+    EcdsaSecp256k1Signature2019,
+    DataIntegrityProof,
+    Other(String),
+}
+
+impl ProofType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ProofType::Proof => "Proof",
+            ProofType::JsonWebSignature2020 => "JsonWebSignature2020",
+            ProofType::Ed25519Signature2020 => "Ed25519Signature2020",
+            ProofType::EcdsaSecp256k1Signature2019 => "EcdsaSecp256k1Signature2019",
+            ProofType::DataIntegrityProof => "DataIntegrityProof",
+            ProofType::Other(type_) => type_,
+        }
+    }
+}
+
+/// A string in the JSON, typed queries match the `ProofType` itself.
+impl Query for ProofType {}
+
+impl fmt::Display for ProofType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<&str> for ProofType {
+    fn from(value: &str) -> Self {
+        match value {
+            "Proof" => ProofType::Proof,
+            "JsonWebSignature2020" => ProofType::JsonWebSignature2020,
+            "Ed25519Signature2020" => ProofType::Ed25519Signature2020,
+            "EcdsaSecp256k1Signature2019" => ProofType::EcdsaSecp256k1Signature2019,
+            "DataIntegrityProof" => ProofType::DataIntegrityProof,
+            _ => ProofType::Other(value.to_string()),
+        }
+    }
+}
+
+impl Serialize for ProofType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+impl<'de> Deserialize<'de> for ProofType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Ok(String::deserialize(deserializer)?.as_str().into())
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct Qualification {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1658,7 +1781,7 @@ pub struct Qualification {
     pub type_: QualificationTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ResultCategory {
     pub count: PositiveInteger,
@@ -1675,7 +1798,7 @@ pub struct ResultCategory {
     pub type_: ResultCategoryTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ResultDistribution {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1688,7 +1811,7 @@ pub struct ResultDistribution {
     pub type_: ResultDistributionTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct CredentialSchema {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1697,7 +1820,7 @@ pub struct CredentialSchema {
     pub type_: CredentialSchemaType,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct ShortenedGrading {
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1709,7 +1832,76 @@ pub struct ShortenedGrading {
     pub type_: ShortenedGradingTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, GenPaths)]
+/// A `credentialStatus` in a StatusList2021 status list, as used by EBSI.
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
+#[serde(rename_all = "camelCase")]
+pub struct StatusList2021Entry {
+    pub id: UriType,
+    pub status_purpose: StatusPurpose,
+    pub status_list_index: StatusListIndex,
+    pub status_list_credential: UriType,
+    #[serde(rename = "type")]
+    pub type_: StatusList2021EntryTag,
+    /// Other properties of the entry, e.g. `statusMessage`.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// Position in a status list, a non-negative integer serialized as a string.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, GenPaths, Query, Visit)]
+pub struct StatusListIndex(pub u64);
+
+impl ops::Deref for StatusListIndex {
+    type Target = u64;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl Serialize for StatusListIndex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+impl std::str::FromStr for StatusListIndex {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
+        if !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit()) {
+            value
+                .parse()
+                .map(StatusListIndex)
+                .map_err(|e: std::num::ParseIntError| e.to_string().into())
+        } else {
+            Err(format!("Not a status list index: \"{value}\"").into())
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StatusListIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: self::error::ConversionError| <D::Error as de::Error>::custom(e.to_string()))
+    }
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize, GenPaths, JsonSchema, Query, Visit)]
+#[serde(rename_all = "lowercase")]
+pub enum StatusPurpose {
+    Revocation,
+    Suspension,
+    Refresh,
+    Message,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, GenPaths, JsonSchema, Query, Visit, Builder)]
 pub struct TermsOfUseValue {
     ///Contains a URL that points to where more information about this instance of terms of use can be found.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -1722,6 +1914,28 @@ pub struct TermsOfUseValue {
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, GenPaths)]
 pub struct UriType(pub fluent_uri::Uri<String>);
 
+/// A string in the JSON, typed queries match the `UriType` itself.
+impl Query for UriType {}
+
+impl Visit for UriType {
+    fn visit<'a>(&'a self, visitor: &mut dyn types_common::Visitor<'a>) {
+        visitor.visit(self);
+    }
+
+    fn visit_mut(&mut self, visitor: &mut dyn types_common::VisitorMut) {
+        visitor.visit_mut(self);
+    }
+}
+
+/// A URI has no children, `ElmVisitor::visit_uri_type` is all a typed visitor sees of it.
+impl<'a, V: ?Sized> types_common::Walk<'a, V> for UriType {
+    fn walk(&'a self, _visitor: &mut V) {}
+}
+
+impl<V: ?Sized> types_common::WalkMut<V> for UriType {
+    fn walk_mut(&mut self, _visitor: &mut V) {}
+}
+
 impl ops::Deref for UriType {
     type Target = fluent_uri::Uri<String>;
     fn deref(&self) -> &Self::Target {
@@ -1738,23 +1952,27 @@ impl Serialize for UriType {
     }
 }
 
+impl std::str::FromStr for UriType {
+    type Err = self::error::ConversionError;
+    fn from_str(value: &str) -> Result<Self, self::error::ConversionError> {
+        fluent_uri::Uri::parse(value.to_string())
+            .map(UriType)
+            .map_err(|err| err.to_string().into())
+    }
+}
+
 impl<'de> Deserialize<'de> for UriType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        let value = String::deserialize(deserializer)?;
-
-        let uri = fluent_uri::Uri::parse(value.clone());
-
-        match uri {
-            Ok(uri) => Ok(UriType(uri)),
-            Err(err) => Err(<D::Error as de::Error>::custom(err)),
-        }
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(|e: self::error::ConversionError| <D::Error as de::Error>::custom(e.to_string()))
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields, rename_all = "camelCase")]
 pub struct VerificationCheck {
     pub dc_type: Concept,
@@ -1770,7 +1988,7 @@ pub struct VerificationCheck {
     pub type_: VerificationCheckTag,
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, TagType, GenPaths, JsonSchema, Query, Visit, Builder)]
 #[serde(deny_unknown_fields)]
 pub struct WebResource {
     #[serde(rename = "contentURL")]
@@ -1784,3 +2002,21 @@ pub struct WebResource {
     #[serde(rename = "type")]
     pub type_: WebResourceTag,
 }
+
+// A typed visitor for every `Visit` type above.
+types_common::visitor! {
+    pub trait ElmVisitor, ElmVisitorMut for
+    EuropassEdcCredential, Accreditation, Address, AgentOrPersonOrOrganisation, Agent, Amount, AwardingOpportunity,
+    AwardingProcess, BitstringStatusListEntry, ClaimNode, ClaimTypeNode, ConceptScheme, Concept, ContactPoint,
+    CredentialSchemaType, CredentialStatus, CredentialStatusValue, CredentialSubject, CreditPoint, DisplayDetail,
+    DisplayParameter, EuropassEdcCredentialContext, DataOrUri, EuropeanDigitalCredential,
+    EuropeanDigitalPresentation, Evidence, Geometry, GradingScheme, Grant, Group, HtmlType,
+    IdentifierOrLegalIdentifier, Identifier, IndividualDisplay, IriType, IssuerNode, LangKV,
+    LearningAchievementSpecificationOrQualification, LearningAchievementSpecification, LearningAchievement,
+    LearningActivitySpecification, LearningActivity, LearningAssessmentSpecification, LearningAssessment,
+    LearningEntitlementSpecification, LearningEntitlement, LearningOpportunity, LearningOutcome, LegalIdentifier,
+    Literal, Location, MailTo, Email, Mailbox, LangKVPairs, MediaObject, Note, Organisation, Percentage,
+    PeriodOfTime, Person, Phone, PriceDetail, Proof, ProofType, Qualification, ResultCategory, ResultDistribution,
+    CredentialSchema, ShortenedGrading, StatusList2021Entry, StatusListIndex, StatusPurpose, TermsOfUseValue,
+    UriType, VerificationCheck, WebResource,
+}
@@ -0,0 +1,147 @@
+//! Assembling and checking presentations of several credentials, e.g. a diploma with its transcript of records.
+
+use std::fmt;
+
+use types_common::OneOrMany;
+
+use crate::{
+    verification::{credential_reference, CheckStatus, CheckType},
+    AgentOrPersonOrOrganisation, EuropeanDigitalCredential, EuropeanDigitalPresentation, VerificationCheck,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PresentationError {
+    NoCredentials,
+    NoHolder,
+    /// The subject of a credential isn't a holder of the presentation, holds the credential id if it has one.
+    HolderMismatch(Option<String>),
+    /// No holder binding check ran, the credential subjects or the holders lack an id.
+    Unbound,
+}
+
+impl fmt::Display for PresentationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PresentationError::NoCredentials => write!(f, "Presentation has no credentials"),
+            PresentationError::NoHolder => write!(f, "Presentation has no holder"),
+            PresentationError::HolderMismatch(Some(id)) => {
+                write!(f, "Subject of credential {id} isn't the holder of the presentation")
+            }
+            PresentationError::HolderMismatch(None) => {
+                write!(f, "Subject of a credential isn't the holder of the presentation")
+            }
+            PresentationError::Unbound => write!(f, "No credential subject can be bound to the holder by its id"),
+        }
+    }
+}
+
+impl std::error::Error for PresentationError {}
+
+impl EuropeanDigitalPresentation {
+    pub fn new(holder: AgentOrPersonOrOrganisation, credentials: Vec<EuropeanDigitalCredential>) -> Self {
+        Self {
+            holder: Some(OneOrMany::from(holder)),
+            id: None,
+            proof: None,
+            verifiable_credential: (!credentials.is_empty()).then(|| OneOrMany::from(credentials)),
+            verification_check: None,
+            type_: Default::default(),
+        }
+    }
+
+    pub fn credentials(&self) -> &[EuropeanDigitalCredential] {
+        self.verifiable_credential.as_ref().map_or(&[], OneOrMany::as_slice)
+    }
+
+    pub fn holders(&self) -> &[AgentOrPersonOrOrganisation] {
+        self.holder.as_ref().map_or(&[], OneOrMany::as_slice)
+    }
+
+    pub fn verification_checks(&self) -> &[VerificationCheck] {
+        self.verification_check.as_ref().map_or(&[], OneOrMany::as_slice)
+    }
+
+    pub fn add_credential(&mut self, credential: EuropeanDigitalCredential) {
+        match &mut self.verifiable_credential {
+            Some(credentials) => credentials.push(credential),
+            None => self.verifiable_credential = Some(OneOrMany::from(credential)),
+        }
+    }
+
+    pub fn add_verification_check(&mut self, check: VerificationCheck) {
+        match &mut self.verification_check {
+            Some(checks) => checks.push(check),
+            None => self.verification_check = Some(OneOrMany::from(check)),
+        }
+    }
+
+    /// One holder binding check per credential, in the order of the credentials.
+    ///
+    /// A credential is bound when the id of its subject is the id of one of the holders. The check is skipped when
+    /// the subject or every holder lacks an id.
+    pub fn holder_binding_checks(&self) -> Vec<VerificationCheck> {
+        let holder_ids: Vec<_> = self
+            .holders()
+            .iter()
+            .filter_map(AgentOrPersonOrOrganisation::id)
+            .collect();
+
+        self.credentials()
+            .iter()
+            .map(|credential| {
+                let subject = credential_reference(credential);
+
+                let (status, description) = match credential.credential_subject.id() {
+                    None => (CheckStatus::Skipped, "Credential subject has no id"),
+                    Some(_) if holder_ids.is_empty() => (CheckStatus::Skipped, "Presentation holder has no id"),
+                    Some(id) if holder_ids.contains(&id) => (CheckStatus::Valid, "Credential subject is the holder"),
+                    Some(_) => (CheckStatus::Invalid, "Credential subject isn't the holder"),
+                };
+
+                VerificationCheck::new(CheckType::HolderBinding, status, subject).with_description(description)
+            })
+            .collect()
+    }
+
+    /// Replaces the holder binding checks in `verification_check` with fresh ones.
+    pub fn check_holder_binding(&mut self) {
+        let mut checks: Vec<_> = self
+            .verification_checks()
+            .iter()
+            .filter(|check| check.check_type() != Some(CheckType::HolderBinding))
+            .cloned()
+            .collect();
+        checks.extend(self.holder_binding_checks());
+
+        self.verification_check = (!checks.is_empty()).then(|| OneOrMany::from(checks));
+    }
+
+    /// Fails when the presentation is empty, has no holder, a credential is bound to someone else or no credential
+    /// could be bound at all.
+    ///
+    /// A skipped holder binding check doesn't fail as long as another one passes, the holder may be identified by other
+    /// means than an id.
+    pub fn validate(&self) -> Result<(), PresentationError> {
+        if self.credentials().is_empty() {
+            return Err(PresentationError::NoCredentials);
+        }
+
+        if self.holders().is_empty() {
+            return Err(PresentationError::NoHolder);
+        }
+
+        let checks = self.holder_binding_checks();
+
+        if let Some(check) = checks.iter().find(|check| check.status() == Some(CheckStatus::Invalid)) {
+            return Err(PresentationError::HolderMismatch(
+                check.subject.as_str().map(String::from),
+            ));
+        }
+
+        if checks.iter().all(|check| check.status() == Some(CheckStatus::Skipped)) {
+            return Err(PresentationError::Unbound);
+        }
+
+        Ok(())
+    }
+}
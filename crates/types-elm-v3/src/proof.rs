@@ -0,0 +1,412 @@
+//! Signing and verification of EDC proofs against locally supplied keys.
+//!
+//! Two forms are supported:
+//!
+//! - an embedded `JsonWebSignature2020` proof with a detached `jws`, see [`sign`] and [`verify`]
+//! - an enveloping JAdES compact JWS, where the credential is the payload, see [`sign_jws`] and [`verify_jws`]
+//!
+//! The payload of a detached `jws` is the hash of the proof configuration followed by the hash of the document, both
+//! canonicalized with URDNA2015. [`sign`] and [`verify`] take a [`Canonicalizer`], usually `Rdfc` of `linked-data`
+//! with its bundled VC, JWS-2020 and EDC contexts. [`sign`] adds the JWS-2020 context to the document, so the proof
+//! terms are those of the security vocabulary. A document the canonicalizer can't canonicalize fails with
+//! [`ProofError::UnsupportedCanonicalization`].
+
+use std::fmt;
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, Utc};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use linked_data::rdfc::Rdfc;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+
+use crate::{EuropassEdcCredential, EuropeanDigitalCredential, EuropeanDigitalPresentation, Proof, ProofType};
+
+/// The context of the `JsonWebSignature2020` proof terms.
+pub const JWS_2020_CONTEXT: &str = "https://w3id.org/security/suites/jws-2020/v1";
+
+#[derive(Debug)]
+pub enum ProofError {
+    /// The proof lacks a field the proof suite needs, e.g. `jws`.
+    MissingField(&'static str),
+    /// The document has no proof to verify.
+    NoProof,
+    /// The document has a proof of another type than `JsonWebSignature2020`, which can't be verified.
+    UnsupportedProofType(String),
+    /// The resolver doesn't know the key the JWS header refers to, e.g. by `kid` or `x5c`.
+    UnknownKey(String),
+    /// The canonicalizer can't canonicalize the document, e.g. because it doesn't know one of its contexts.
+    UnsupportedCanonicalization(String),
+    /// The `jws` isn't a detached JWS with an unencoded payload, `<header>..<signature>` with `b64` false.
+    InvalidJws(String),
+    /// The JWS header names another algorithm than the one of the resolved key.
+    AlgorithmMismatch(Algorithm),
+    InvalidSignature,
+    Jwt(jsonwebtoken::errors::Error),
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofError::MissingField(field) => write!(f, "Proof is missing `{field}`"),
+            ProofError::NoProof => write!(f, "Document has no proof"),
+            ProofError::UnsupportedProofType(type_) => write!(f, "Unsupported proof type: {type_}"),
+            ProofError::UnknownKey(key) => write!(f, "Unknown key: {key}"),
+            ProofError::UnsupportedCanonicalization(reason) => write!(f, "Can't canonicalize the document: {reason}"),
+            ProofError::InvalidJws(reason) => write!(f, "Invalid detached JWS: {reason}"),
+            ProofError::AlgorithmMismatch(algorithm) => write!(f, "JWS isn't signed with {algorithm:?}"),
+            ProofError::InvalidSignature => write!(f, "Signature doesn't match the document"),
+            ProofError::Jwt(err) => write!(f, "{err}"),
+            ProofError::Json(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ProofError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ProofError::Jwt(err) => Some(err),
+            ProofError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<jsonwebtoken::errors::Error> for ProofError {
+    fn from(err: jsonwebtoken::errors::Error) -> Self {
+        ProofError::Jwt(err)
+    }
+}
+
+impl From<serde_json::Error> for ProofError {
+    fn from(err: serde_json::Error) -> Self {
+        ProofError::Json(err)
+    }
+}
+
+/// Looks up the key a JWS header refers to, by its `kid`, `x5c` certificate chain or `x5t#S256` thumbprint, e.g. from
+/// a local key store, a resolved DID document or a trust list. The header is the one of the issuer, [`sign`] sets its
+/// `kid` to the `verificationMethod` of the proof.
+///
+/// The algorithm comes with the key and not from the JWS header, so a proof can't pick a weaker algorithm for a key.
+pub trait KeyResolver {
+    fn resolve(&self, header: &Header) -> Option<(Algorithm, DecodingKey)>;
+}
+
+impl<F> KeyResolver for F
+where
+    F: Fn(&Header) -> Option<(Algorithm, DecodingKey)>,
+{
+    fn resolve(&self, header: &Header) -> Option<(Algorithm, DecodingKey)> {
+        self(header)
+    }
+}
+
+/// Canonicalizes a JSON-LD document into N-Quads with URDNA2015, as `JsonWebSignature2020` requires.
+pub trait Canonicalizer {
+    fn canonicalize(&self, document: &Value) -> Result<String, String>;
+}
+
+impl<F, E> Canonicalizer for F
+where
+    F: Fn(&Value) -> Result<String, E>,
+    E: fmt::Display,
+{
+    fn canonicalize(&self, document: &Value) -> Result<String, String> {
+        self(document).map_err(|err| err.to_string())
+    }
+}
+
+impl Canonicalizer for Rdfc {
+    fn canonicalize(&self, document: &Value) -> Result<String, String> {
+        Rdfc::canonicalize(self, document).map_err(|err| err.to_string())
+    }
+}
+
+/// Proof options, the `jws` is added by [`sign`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofOptions {
+    pub algorithm: Algorithm,
+    pub verification_method: String,
+    pub created: DateTime<Utc>,
+    /// Defaults to `assertionMethod`.
+    pub proof_purpose: String,
+}
+
+impl ProofOptions {
+    pub fn new(verification_method: impl Into<String>, algorithm: Algorithm) -> Self {
+        Self {
+            algorithm,
+            verification_method: verification_method.into(),
+            created: Utc::now(),
+            proof_purpose: "assertionMethod".to_string(),
+        }
+    }
+}
+
+/// A credential or presentation that can carry proofs.
+pub trait Securable: Serialize + DeserializeOwned {}
+
+impl Securable for EuropassEdcCredential {}
+impl Securable for EuropeanDigitalCredential {}
+impl Securable for EuropeanDigitalPresentation {}
+
+/// Signs `document` and returns it with the new proof added to its existing ones, and the JWS-2020 context added to
+/// its `@context`, if it has one, unless it has it already.
+pub fn sign<T: Securable>(
+    document: &T,
+    key: &EncodingKey,
+    options: &ProofOptions,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<T, ProofError> {
+    Ok(serde_json::from_value(sign_value(
+        &serde_json::to_value(document)?,
+        key,
+        options,
+        canonicalizer,
+    )?)?)
+}
+
+/// Verifies every proof of `document`, a proof of another type than `JsonWebSignature2020` fails with
+/// [`ProofError::UnsupportedProofType`].
+pub fn verify<T: Securable>(
+    document: &T,
+    resolver: &impl KeyResolver,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<(), ProofError> {
+    verify_value(&serde_json::to_value(document)?, resolver, canonicalizer)
+}
+
+/// [`sign`] on the raw JSON of a credential or presentation.
+pub fn sign_value(
+    document: &Value,
+    key: &EncodingKey,
+    options: &ProofOptions,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<Value, ProofError> {
+    let (mut secured, mut proofs) = split_proofs(document);
+    add_suite_context(&mut secured);
+
+    let proof = Proof {
+        challenge: None,
+        created: Some(options.created),
+        cryptosuite: None,
+        domain: None,
+        expires: None,
+        id: None,
+        jws: None,
+        nonce: None,
+        proof_purpose: Some(options.proof_purpose.clone()),
+        proof_value: None,
+        type_: ProofType::JsonWebSignature2020,
+        verification_method: Some(options.verification_method.clone()),
+    };
+    let mut proof = serde_json::to_value(proof)?;
+
+    let header = URL_SAFE_NO_PAD.encode(serde_json::to_vec(&json!({
+        "alg": options.algorithm,
+        "kid": options.verification_method,
+        "b64": false,
+        "crit": ["b64"],
+    }))?);
+    let signature = jsonwebtoken::crypto::sign(
+        &signing_input(&header, &secured, &proof, canonicalizer)?,
+        key,
+        options.algorithm,
+    )?;
+
+    proof["jws"] = format!("{header}..{signature}").into();
+    proofs.push(proof);
+
+    secured["proof"] = match proofs.len() {
+        1 => proofs.remove(0),
+        _ => proofs.into(),
+    };
+
+    Ok(secured)
+}
+
+/// [`verify`] on the raw JSON of a credential or presentation, this avoids any difference the typed round trip
+/// could make.
+pub fn verify_value(
+    document: &Value,
+    resolver: &impl KeyResolver,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<(), ProofError> {
+    let (unsecured, proofs) = split_proofs(document);
+
+    if proofs.is_empty() {
+        return Err(ProofError::NoProof);
+    }
+
+    for mut proof in proofs {
+        if proof["type"] != ProofType::JsonWebSignature2020.as_str() {
+            return Err(ProofError::UnsupportedProofType(match &proof["type"] {
+                Value::String(type_) => type_.clone(),
+                type_ => type_.to_string(),
+            }));
+        }
+
+        let field = |name: &'static str| proof[name].as_str().ok_or(ProofError::MissingField(name));
+
+        field("verificationMethod")?;
+        let (header, signature) = field("jws")?
+            .split_once("..")
+            .ok_or_else(|| ProofError::InvalidJws("expected `<header>..<signature>`".to_string()))?;
+        let (header, signature) = (header.to_string(), signature.to_string());
+
+        let jose = decode_header(&header)?;
+
+        let (algorithm, key) = resolver
+            .resolve(&jose)
+            .ok_or_else(|| ProofError::UnknownKey(key_reference(&jose)))?;
+
+        check_header(&header, algorithm)?;
+
+        if let Some(proof) = proof.as_object_mut() {
+            proof.remove("jws");
+        }
+
+        let message = signing_input(&header, &unsecured, &proof, canonicalizer)?;
+        if !jsonwebtoken::crypto::verify(&signature, &message, &key, algorithm)? {
+            return Err(ProofError::InvalidSignature);
+        }
+    }
+
+    Ok(())
+}
+
+/// Signs `document` as the payload of a compact JWS, as the Europass portal does with JAdES.
+///
+/// Set `header.kid`, `header.x5c` or `header.x5t_s256` so [`verify_jws`] can resolve the key.
+pub fn sign_jws<T: Serialize>(document: &T, header: &Header, key: &EncodingKey) -> Result<String, ProofError> {
+    Ok(jsonwebtoken::encode(header, document, key)?)
+}
+
+/// Verifies a compact JWS with the key its header refers to and returns its payload.
+///
+/// The payload is a credential or presentation rather than a set of JWT claims, so no claims are required or checked.
+pub fn verify_jws<T: DeserializeOwned>(token: &str, resolver: &impl KeyResolver) -> Result<T, ProofError> {
+    let header = jsonwebtoken::decode_header(token)?;
+    let (algorithm, key) = resolver
+        .resolve(&header)
+        .ok_or_else(|| ProofError::UnknownKey(key_reference(&header)))?;
+
+    let mut validation = Validation::new(algorithm);
+    validation.validate_exp = false;
+    validation.validate_aud = false;
+    validation.required_spec_claims.clear();
+
+    Ok(jsonwebtoken::decode(token, &key, &validation)?.claims)
+}
+
+/// The header of a detached JWS, its `b64` and `crit` are checked by [`check_header`].
+fn decode_header(header: &str) -> Result<Header, ProofError> {
+    URL_SAFE_NO_PAD
+        .decode(header)
+        .ok()
+        .and_then(|header| serde_json::from_slice(&header).ok())
+        .ok_or_else(|| ProofError::InvalidJws("header isn't a base64url encoded JOSE header".to_string()))
+}
+
+/// How a header refers to its key, for [`ProofError::UnknownKey`].
+fn key_reference(header: &Header) -> String {
+    match (&header.kid, &header.x5t_s256, &header.x5t, &header.x5c) {
+        (Some(kid), ..) => kid.clone(),
+        (None, Some(thumbprint), ..) => format!("x5t#S256 {thumbprint}"),
+        (None, None, Some(thumbprint), _) => format!("x5t {thumbprint}"),
+        (None, None, None, Some(chain)) if !chain.is_empty() => "x5c certificate chain".to_string(),
+        _ => "no kid, x5c or x5t in the header".to_string(),
+    }
+}
+
+/// Checks that a detached JWS header has an unencoded payload and the algorithm of the key.
+fn check_header(header: &str, algorithm: Algorithm) -> Result<(), ProofError> {
+    let invalid = |reason: &str| ProofError::InvalidJws(reason.to_string());
+
+    let header: Value = URL_SAFE_NO_PAD
+        .decode(header)
+        .ok()
+        .and_then(|header| serde_json::from_slice(&header).ok())
+        .ok_or_else(|| invalid("header isn't base64url encoded JSON"))?;
+
+    if header["b64"] != false
+        || !header["crit"]
+            .as_array()
+            .is_some_and(|crit| crit.contains(&"b64".into()))
+    {
+        return Err(invalid("payload must be unencoded, `b64` false and critical"));
+    }
+
+    match serde_json::from_value::<Algorithm>(header["alg"].clone()) {
+        Ok(alg) if alg == algorithm => Ok(()),
+        _ => Err(ProofError::AlgorithmMismatch(algorithm)),
+    }
+}
+
+/// Adds [`JWS_2020_CONTEXT`] to the `@context` of `document` unless it has it, a document without `@context` is left
+/// as it is.
+fn add_suite_context(document: &mut Value) {
+    let Some(document) = document.as_object_mut() else {
+        return;
+    };
+
+    let mut contexts = match document.remove("@context") {
+        None => return,
+        Some(Value::Array(contexts)) => contexts,
+        Some(context) => vec![context],
+    };
+    if !contexts.contains(&JWS_2020_CONTEXT.into()) {
+        contexts.push(JWS_2020_CONTEXT.into());
+    }
+
+    document.insert("@context".to_string(), contexts.into());
+}
+
+/// Removes the `proof` of the document, proofs in a proof set are created and verified independently.
+fn split_proofs(document: &Value) -> (Value, Vec<Value>) {
+    let mut unsecured = document.clone();
+
+    let proofs = match unsecured.as_object_mut().and_then(|object| object.remove("proof")) {
+        None => vec![],
+        Some(Value::Array(proofs)) => proofs,
+        Some(proof) => vec![proof],
+    };
+
+    (unsecured, proofs)
+}
+
+/// The JWS signing input of an unencoded payload, the encoded header, a `.` and the payload bytes.
+///
+/// The payload is the hash of the proof configuration followed by the hash of the canonical document.
+fn signing_input(
+    header: &str,
+    unsecured: &Value,
+    proof_config: &Value,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<Vec<u8>, ProofError> {
+    let mut proof_config = proof_config.clone();
+
+    if let Some(context) = unsecured.get("@context") {
+        proof_config["@context"] = context.clone();
+    }
+
+    let canonicalize = |document: &Value| {
+        canonicalizer
+            .canonicalize(document)
+            .map_err(ProofError::UnsupportedCanonicalization)
+    };
+
+    let proof_config_hash = Sha256::digest(canonicalize(&proof_config)?);
+    let document_hash = Sha256::digest(canonicalize(unsecured)?);
+
+    Ok([
+        header.as_bytes(),
+        b".",
+        proof_config_hash.as_slice(),
+        document_hash.as_slice(),
+    ]
+    .concat())
+}
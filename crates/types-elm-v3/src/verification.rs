@@ -0,0 +1,455 @@
+//! Typed construction of [`VerificationCheck`]s and the [`Verifier`] that runs the standard EDC checks.
+//!
+//! The check type and status are concepts of the Europass verification vocabularies, which are bundled in
+//! `vocabularies/verification.json` and `vocabularies/verification-status.json`. A new check gets the published id
+//! and labels of its concepts from them, and a check is recognised by the id of its concepts or, for concepts without
+//! an id, by their English label. [`Verifier::with_vocabularies`] and [`VerificationCheck::with_vocabularies`] take
+//! the concepts from other copies of the schemes, e.g. loaded from newer SKOS dumps.
+
+use std::{fmt, sync::OnceLock};
+
+use chrono::{DateTime, Utc};
+use types_common::OneOrMany;
+
+use crate::{
+    vocabulary::VocabularyRegistry, AgentOrPersonOrOrganisation, Concept, ConceptScheme, CredentialSchema,
+    CredentialStatusValue, DataOrUri, EuropeanDigitalCredential, EuropeanDigitalPresentation, LangKVPairs,
+    VerificationCheck,
+};
+
+/// The vocabulary `VerificationCheck.dcType` must come from.
+pub const VERIFICATION_TYPE_SCHEME: &str = "http://data.europa.eu/snb/verification/25831c2";
+/// The vocabulary `VerificationCheck.verificationStatus` must come from.
+pub const VERIFICATION_STATUS_SCHEME: &str = "http://data.europa.eu/snb/verification-status/25831c2";
+
+/// The bundled verification and verification status schemes.
+pub fn verification_schemes() -> &'static VocabularyRegistry {
+    static REGISTRY: OnceLock<VocabularyRegistry> = OnceLock::new();
+
+    REGISTRY.get_or_init(|| {
+        let mut registry = VocabularyRegistry::new();
+
+        for snapshot in [
+            include_str!("../vocabularies/verification.json"),
+            include_str!("../vocabularies/verification-status.json"),
+        ] {
+            registry.insert(serde_json::from_str(snapshot).expect("bundled vocabulary is valid"));
+        }
+
+        registry
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckType {
+    /// The credential subject is the holder of the presentation.
+    HolderBinding,
+    /// `validFrom`, `validUntil` and `expirationDate` include the time of verification.
+    ValidityWindow,
+    /// The credential conforms to its `credentialSchema`.
+    CredentialSchema,
+    /// The issuer has an accreditation that hasn't expired.
+    Accreditation,
+    /// The `credentialStatus` isn't revoked or suspended.
+    CredentialStatus,
+}
+
+impl CheckType {
+    const ALL: [CheckType; 5] = [
+        CheckType::HolderBinding,
+        CheckType::ValidityWindow,
+        CheckType::CredentialSchema,
+        CheckType::Accreditation,
+        CheckType::CredentialStatus,
+    ];
+
+    /// The English label of the concept, which finds it in the verification scheme.
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckType::HolderBinding => "Holder binding",
+            CheckType::ValidityWindow => "Validity",
+            CheckType::CredentialSchema => "Credential schema",
+            CheckType::Accreditation => "Accreditation",
+            CheckType::CredentialStatus => "Credential status",
+        }
+    }
+
+    /// The concept of the bundled verification scheme, see [`CheckType::concept_in`].
+    pub fn concept(&self) -> Concept {
+        self.concept_in(verification_schemes())
+    }
+
+    /// The concept of the verification scheme in `vocabularies`, with its id and labels, or a concept with only the
+    /// English label when the scheme isn't loaded or has no concept with this label.
+    pub fn concept_in(&self, vocabularies: &VocabularyRegistry) -> Concept {
+        vocabularies
+            .concept_by_label(VERIFICATION_TYPE_SCHEME, self.label())
+            .unwrap_or_else(|| unidentified(VERIFICATION_TYPE_SCHEME, self.label()))
+    }
+
+    fn from_concept(concept: &Concept) -> Option<Self> {
+        CheckType::ALL
+            .into_iter()
+            .find(|check_type| is_concept(concept, VERIFICATION_TYPE_SCHEME, check_type.label()))
+    }
+}
+
+impl fmt::Display for CheckType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CheckStatus {
+    Valid,
+    Invalid,
+    /// The check couldn't be performed, e.g. because an identifier is missing.
+    Skipped,
+}
+
+impl CheckStatus {
+    const ALL: [CheckStatus; 3] = [CheckStatus::Valid, CheckStatus::Invalid, CheckStatus::Skipped];
+
+    /// The English label of the concept, which finds it in the verification status scheme.
+    pub fn label(&self) -> &'static str {
+        match self {
+            CheckStatus::Valid => "Valid",
+            CheckStatus::Invalid => "Invalid",
+            CheckStatus::Skipped => "Skipped",
+        }
+    }
+
+    /// The concept of the bundled verification status scheme, see [`CheckStatus::concept_in`].
+    pub fn concept(&self) -> Concept {
+        self.concept_in(verification_schemes())
+    }
+
+    /// The concept of the verification status scheme in `vocabularies`, with its id and labels, or a concept with only
+    /// the English label when the scheme isn't loaded or has no concept with this label.
+    pub fn concept_in(&self, vocabularies: &VocabularyRegistry) -> Concept {
+        vocabularies
+            .concept_by_label(VERIFICATION_STATUS_SCHEME, self.label())
+            .unwrap_or_else(|| unidentified(VERIFICATION_STATUS_SCHEME, self.label()))
+    }
+
+    fn from_concept(concept: &Concept) -> Option<Self> {
+        CheckStatus::ALL
+            .into_iter()
+            .find(|status| is_concept(concept, VERIFICATION_STATUS_SCHEME, status.label()))
+    }
+}
+
+impl fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.label())
+    }
+}
+
+impl VerificationCheck {
+    /// A check of `check_type` on `subject`, usually the id of the checked credential.
+    pub fn new(check_type: CheckType, status: CheckStatus, subject: serde_json::Value) -> Self {
+        Self {
+            dc_type: check_type.concept(),
+            description: None,
+            elm_subject: None,
+            id: None,
+            subject,
+            verification_status: status.concept(),
+            type_: Default::default(),
+        }
+    }
+
+    /// Replaces the check type and status concepts with the ones in `vocabularies`, see [`CheckType::concept_in`].
+    pub fn with_vocabularies(mut self, vocabularies: &VocabularyRegistry) -> Self {
+        if let Some(check_type) = self.check_type() {
+            self.dc_type = check_type.concept_in(vocabularies);
+        }
+        if let Some(status) = self.status() {
+            self.verification_status = status.concept_in(vocabularies);
+        }
+        self
+    }
+
+    /// Sets an English description, e.g. why the check failed.
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description = english(description.into());
+        self
+    }
+
+    /// `None` when `dcType` isn't one of the [`CheckType`]s.
+    pub fn check_type(&self) -> Option<CheckType> {
+        CheckType::from_concept(&self.dc_type)
+    }
+
+    /// `None` when `verificationStatus` isn't one of the [`CheckStatus`]es.
+    pub fn status(&self) -> Option<CheckStatus> {
+        CheckStatus::from_concept(&self.verification_status)
+    }
+}
+
+/// Checks a credential against one of its `credentialSchema`s, e.g. with a JSON Schema or SHACL validator.
+pub trait SchemaValidator {
+    /// Fails with a description of the violations.
+    fn validate(&self, credential: &EuropeanDigitalCredential, schema: &CredentialSchema) -> Result<(), String>;
+}
+
+impl<F> SchemaValidator for F
+where
+    F: Fn(&EuropeanDigitalCredential, &CredentialSchema) -> Result<(), String>,
+{
+    fn validate(&self, credential: &EuropeanDigitalCredential, schema: &CredentialSchema) -> Result<(), String> {
+        self(credential, schema)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CredentialState {
+    Active,
+    Revoked,
+    Suspended,
+}
+
+/// Looks up the state of a `credentialStatus` entry, e.g. in a status list.
+pub trait StatusResolver {
+    /// Fails when the status can't be looked up, e.g. because the status list is unreachable.
+    fn resolve(&self, status: &CredentialStatusValue) -> Result<CredentialState, String>;
+}
+
+impl<F> StatusResolver for F
+where
+    F: Fn(&CredentialStatusValue) -> Result<CredentialState, String>,
+{
+    fn resolve(&self, status: &CredentialStatusValue) -> Result<CredentialState, String> {
+        self(status)
+    }
+}
+
+/// Runs the standard EDC checks on credentials.
+///
+/// Checks that need a [`SchemaValidator`] or [`StatusResolver`] are skipped when none is configured.
+#[derive(Clone, Copy)]
+pub struct Verifier<'a> {
+    pub now: DateTime<Utc>,
+    pub schema_validator: Option<&'a dyn SchemaValidator>,
+    pub status_resolver: Option<&'a dyn StatusResolver>,
+    /// The verification schemes, the concepts of the checks have no id without them.
+    pub vocabularies: Option<&'a VocabularyRegistry>,
+}
+
+impl Default for Verifier<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Verifier<'a> {
+    pub fn new() -> Self {
+        Self {
+            now: Utc::now(),
+            schema_validator: None,
+            status_resolver: None,
+            vocabularies: None,
+        }
+    }
+
+    /// Verifies as if it were `now`, e.g. to check whether a credential was valid when it was presented.
+    pub fn at(mut self, now: DateTime<Utc>) -> Self {
+        self.now = now;
+        self
+    }
+
+    pub fn with_schema_validator(mut self, schema_validator: &'a dyn SchemaValidator) -> Self {
+        self.schema_validator = Some(schema_validator);
+        self
+    }
+
+    pub fn with_status_resolver(mut self, status_resolver: &'a dyn StatusResolver) -> Self {
+        self.status_resolver = Some(status_resolver);
+        self
+    }
+
+    pub fn with_vocabularies(mut self, vocabularies: &'a VocabularyRegistry) -> Self {
+        self.vocabularies = Some(vocabularies);
+        self
+    }
+
+    /// The validity window, credential schema, accreditation and credential status checks, in that order.
+    pub fn verify(&self, credential: &EuropeanDigitalCredential) -> Vec<VerificationCheck> {
+        let subject = credential_reference(credential);
+
+        [
+            (CheckType::ValidityWindow, self.check_validity_window(credential)),
+            (CheckType::CredentialSchema, self.check_credential_schema(credential)),
+            (CheckType::Accreditation, self.check_accreditation(credential)),
+            (CheckType::CredentialStatus, self.check_credential_status(credential)),
+        ]
+        .into_iter()
+        .map(|(check_type, (status, description))| {
+            self.with_concepts(
+                VerificationCheck::new(check_type, status, subject.clone()).with_description(description),
+            )
+        })
+        .collect()
+    }
+
+    /// The holder binding checks of the presentation followed by the checks of each credential.
+    pub fn verify_presentation(&self, presentation: &EuropeanDigitalPresentation) -> Vec<VerificationCheck> {
+        let mut checks: Vec<_> = presentation
+            .holder_binding_checks()
+            .into_iter()
+            .map(|check| self.with_concepts(check))
+            .collect();
+        checks.extend(
+            presentation
+                .credentials()
+                .iter()
+                .flat_map(|credential| self.verify(credential)),
+        );
+        checks
+    }
+
+    fn with_concepts(&self, check: VerificationCheck) -> VerificationCheck {
+        match self.vocabularies {
+            Some(vocabularies) => check.with_vocabularies(vocabularies),
+            None => check,
+        }
+    }
+
+    fn check_validity_window(&self, credential: &EuropeanDigitalCredential) -> (CheckStatus, String) {
+        if self.now < credential.valid_from {
+            return (
+                CheckStatus::Invalid,
+                format!("Credential isn't valid until {}", credential.valid_from),
+            );
+        }
+
+        let expirations = credential
+            .valid_until
+            .iter()
+            .chain(credential.expiration_date.iter().flatten());
+
+        match expirations.filter(|expiration| **expiration <= self.now).min() {
+            Some(expiration) => (CheckStatus::Invalid, format!("Credential expired at {expiration}")),
+            None => (
+                CheckStatus::Valid,
+                "Credential is within its validity period".to_string(),
+            ),
+        }
+    }
+
+    fn check_credential_schema(&self, credential: &EuropeanDigitalCredential) -> (CheckStatus, String) {
+        let Some(schema_validator) = self.schema_validator else {
+            return (CheckStatus::Skipped, "No schema validator configured".to_string());
+        };
+
+        let errors: Vec<_> = credential
+            .credential_schema
+            .iter()
+            .filter_map(|schema| schema_validator.validate(credential, schema).err())
+            .collect();
+
+        if errors.is_empty() {
+            (CheckStatus::Valid, "Credential conforms to its schemas".to_string())
+        } else {
+            (CheckStatus::Invalid, errors.join("\n"))
+        }
+    }
+
+    fn check_accreditation(&self, credential: &EuropeanDigitalCredential) -> (CheckStatus, String) {
+        let DataOrUri::Data(AgentOrPersonOrOrganisation::Organisation(issuer)) = &credential.issuer else {
+            return (
+                CheckStatus::Skipped,
+                "Issuer isn't an embedded organisation".to_string(),
+            );
+        };
+
+        let accreditations: &[_] = issuer.accreditation.as_ref().map_or(&[], OneOrMany::as_slice);
+
+        if accreditations.is_empty() {
+            return (CheckStatus::Invalid, "Issuer has no accreditation".to_string());
+        }
+
+        let current = accreditations
+            .iter()
+            .any(|accreditation| accreditation.expiry_date.is_none_or(|expiry| self.now < expiry));
+
+        if current {
+            (CheckStatus::Valid, "Issuer is accredited".to_string())
+        } else {
+            (
+                CheckStatus::Invalid,
+                "Every accreditation of the issuer has expired".to_string(),
+            )
+        }
+    }
+
+    fn check_credential_status(&self, credential: &EuropeanDigitalCredential) -> (CheckStatus, String) {
+        let statuses: &[_] = credential.credential_status.as_ref().map_or(&[], OneOrMany::as_slice);
+
+        if statuses.is_empty() {
+            return (CheckStatus::Skipped, "Credential has no status".to_string());
+        }
+
+        let Some(status_resolver) = self.status_resolver else {
+            return (CheckStatus::Skipped, "No status resolver configured".to_string());
+        };
+
+        for status in statuses {
+            match status_resolver.resolve(status) {
+                Ok(CredentialState::Active) => {}
+                Ok(CredentialState::Revoked) => return (CheckStatus::Invalid, "Credential is revoked".to_string()),
+                Ok(CredentialState::Suspended) => return (CheckStatus::Invalid, "Credential is suspended".to_string()),
+                Err(err) => return (CheckStatus::Skipped, format!("Status couldn't be resolved: {err}")),
+            }
+        }
+
+        (CheckStatus::Valid, "Credential is active".to_string())
+    }
+}
+
+/// The `subject` of a check on `credential`: its id, or `null` when it has none.
+pub(crate) fn credential_reference(credential: &EuropeanDigitalCredential) -> serde_json::Value {
+    credential
+        .id
+        .as_ref()
+        .map_or(serde_json::Value::Null, |id| id.to_string().into())
+}
+
+/// A concept of `scheme` that only has its English label.
+fn unidentified(scheme: &str, label: &str) -> Concept {
+    Concept {
+        definition: None,
+        id: None,
+        in_scheme: Some(ConceptScheme {
+            id: scheme.parse().ok(),
+            type_: Default::default(),
+        }),
+        notation: None,
+        pref_label: english(label.to_string()),
+        type_: Default::default(),
+    }
+}
+
+/// Whether `concept` is the concept of `scheme` with the English label `label`, taking the label of the bundled concept
+/// with its id over its own.
+fn is_concept(concept: &Concept, scheme: &str, label: &str) -> bool {
+    let in_scheme = concept.in_scheme.as_ref().and_then(|scheme| scheme.id.as_ref());
+    if in_scheme.is_none_or(|in_scheme| in_scheme.to_string() != scheme) {
+        return false;
+    }
+
+    let bundled = concept
+        .id
+        .as_ref()
+        .and_then(|id| verification_schemes().vocabulary(scheme)?.concept(&id.to_string()));
+
+    bundled
+        .and_then(|bundled| bundled.pref_label("en"))
+        .or_else(|| concept.pref_label.as_ref()?.text("en"))
+        .is_some_and(|other| other.to_lowercase() == label.to_lowercase())
+}
+
+fn english(value: String) -> Option<LangKVPairs> {
+    LangKVPairs::new([("en".parse().expect("valid language"), value.into())].into())
+}
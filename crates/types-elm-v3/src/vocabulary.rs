@@ -0,0 +1,243 @@
+//! Controlled vocabularies for `Concept` values, e.g. EQF levels, ISCED-F fields, countries, languages, credential
+//! types and learning modes.
+//!
+//! A [`VocabularyRegistry`] holds concept schemes by their id. With the `vocabularies` feature,
+//! [`VocabularyRegistry::bundled`] has the EU schemes in `vocabularies/`, each file names its `source`.
+//! `fetch-vocabularies.sh` downloads the SKOS dumps of the Publications Office and `cargo xtask vocabularies`
+//! regenerates the files from them, with the complete schemes and their labels in all languages. Until then:
+//!
+//! - The country and language tables hold the ISO 3166-1 and ISO 639-2 names of Debian's iso-codes, their labels may
+//!   differ from the labels of the Publications Office, and of the codes the EU adds only `XKX` is included.
+//! - The EQF and ISCED-F schemes only have English labels.
+//! - The credential type, learning assessment and learning setting schemes only have the concepts of the EDC examples.
+
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Concept, ConceptScheme, LangKVPairs, LangKey, Literal};
+
+/// European Qualifications Framework levels, e.g. `http://data.europa.eu/snb/eqf/6`.
+pub const EQF: &str = "http://data.europa.eu/snb/eqf/25831c2";
+/// ISCED-F 2013 fields of education and training, e.g. `http://data.europa.eu/snb/isced-f/0613`.
+pub const ISCED_F: &str = "http://data.europa.eu/snb/isced-f/25831c2";
+/// The EU country authority table, e.g. `http://publications.europa.eu/resource/authority/country/NLD`.
+pub const COUNTRY: &str = "http://publications.europa.eu/resource/authority/country";
+/// The EU language authority table, e.g. `http://publications.europa.eu/resource/authority/language/ENG`.
+pub const LANGUAGE: &str = "http://publications.europa.eu/resource/authority/language";
+/// Europass credential types, the `credentialProfiles` of a credential.
+pub const CREDENTIAL_TYPE: &str = "http://data.europa.eu/snb/credential/25831c2";
+/// Modes of assessment, e.g. online, the `mode` of a learning assessment.
+pub const LEARNING_ASSESSMENT: &str = "http://data.europa.eu/snb/learning-assessment/25831c2";
+/// Learning settings, e.g. formal learning, the `learningSetting` of a learning opportunity.
+pub const LEARNING_SETTING: &str = "http://data.europa.eu/snb/learning-setting/25831c2";
+
+/// A concept scheme and its concepts.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Vocabulary {
+    /// The id of the concept scheme.
+    pub id: String,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pref_label: BTreeMap<LangKey, String>,
+    pub concepts: Vec<VocabularyConcept>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VocabularyConcept {
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notation: Option<String>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pref_label: BTreeMap<LangKey, String>,
+}
+
+impl Vocabulary {
+    pub fn concept(&self, id: &str) -> Option<&VocabularyConcept> {
+        self.concepts.iter().find(|concept| concept.id == id)
+    }
+
+    /// The concept with `notation`, ignoring case, e.g. `NLD` or `nld` in the country table.
+    pub fn concept_by_notation(&self, notation: &str) -> Option<&VocabularyConcept> {
+        self.concepts.iter().find(|concept| {
+            concept
+                .notation
+                .as_ref()
+                .is_some_and(|other| other.eq_ignore_ascii_case(notation))
+        })
+    }
+
+    /// The concept with the label `label` in `lang`, ignoring case, for schemes whose concepts have no notation.
+    pub fn concept_by_label(&self, lang: &str, label: &str) -> Option<&VocabularyConcept> {
+        self.concepts.iter().find(|concept| {
+            concept
+                .pref_label(lang)
+                .is_some_and(|other| other.to_lowercase() == label.to_lowercase())
+        })
+    }
+
+    pub fn contains(&self, id: &str) -> bool {
+        self.concept(id).is_some()
+    }
+
+    /// An ELM `Concept` of `concept`, with its scheme, notation and labels.
+    pub fn to_concept(&self, concept: &VocabularyConcept) -> Concept {
+        let pref_label = concept
+            .pref_label
+            .iter()
+            .map(|(lang, label)| (lang.clone(), label.as_str().into()))
+            .collect::<HashMap<_, _>>();
+
+        Concept {
+            definition: None,
+            id: concept.id.parse().ok(),
+            in_scheme: Some(ConceptScheme {
+                id: self.id.parse().ok(),
+                type_: Default::default(),
+            }),
+            notation: concept.notation.clone().map(Literal),
+            pref_label: LangKVPairs::new(pref_label),
+            type_: Default::default(),
+        }
+    }
+}
+
+impl VocabularyConcept {
+    /// The label in `lang`, `en-GB` takes the label in `en` when there's none in `en-GB`.
+    pub fn pref_label(&self, lang: &str) -> Option<&str> {
+        let lang: LangKey = lang.parse().ok()?;
+
+        lang.lookup(self.pref_label.keys())
+            .and_then(|lang| self.pref_label.get(lang))
+            .map(String::as_str)
+    }
+}
+
+/// Concept schemes by their id.
+#[derive(Clone, Debug, Default)]
+pub struct VocabularyRegistry {
+    vocabularies: BTreeMap<String, Vocabulary>,
+}
+
+impl VocabularyRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The EQF, ISCED-F, country, language, credential type, learning assessment, learning setting, verification and
+    /// verification status schemes.
+    #[cfg(feature = "vocabularies")]
+    pub fn bundled() -> &'static VocabularyRegistry {
+        static REGISTRY: std::sync::OnceLock<VocabularyRegistry> = std::sync::OnceLock::new();
+
+        REGISTRY.get_or_init(|| {
+            let mut registry = VocabularyRegistry::new();
+
+            for snapshot in [
+                include_str!("../vocabularies/eqf.json"),
+                include_str!("../vocabularies/isced-f.json"),
+                include_str!("../vocabularies/country.json"),
+                include_str!("../vocabularies/language.json"),
+                include_str!("../vocabularies/credential.json"),
+                include_str!("../vocabularies/learning-assessment.json"),
+                include_str!("../vocabularies/learning-setting.json"),
+                include_str!("../vocabularies/verification.json"),
+                include_str!("../vocabularies/verification-status.json"),
+            ] {
+                registry.insert(serde_json::from_str(snapshot).expect("bundled vocabulary is valid"));
+            }
+
+            registry
+        })
+    }
+
+    /// Adds `vocabulary`, returns the vocabulary it replaces.
+    pub fn insert(&mut self, vocabulary: Vocabulary) -> Option<Vocabulary> {
+        self.vocabularies.insert(vocabulary.id.clone(), vocabulary)
+    }
+
+    pub fn vocabulary(&self, scheme: &str) -> Option<&Vocabulary> {
+        self.vocabularies.get(scheme)
+    }
+
+    pub fn vocabularies(&self) -> impl Iterator<Item = &Vocabulary> {
+        self.vocabularies.values()
+    }
+
+    /// The concept with `id`, in any of the vocabularies.
+    pub fn concept(&self, id: &str) -> Option<&VocabularyConcept> {
+        self.vocabularies().find_map(|vocabulary| vocabulary.concept(id))
+    }
+
+    /// Whether `concept` is one of the concepts of `scheme`, e.g. `contains(EQF, &concept)` for a valid EQF level.
+    ///
+    /// A concept without an id or of an unknown scheme isn't contained.
+    pub fn contains(&self, scheme: &str, concept: &Concept) -> bool {
+        match (self.vocabulary(scheme), &concept.id) {
+            (Some(vocabulary), Some(id)) => vocabulary.contains(&id.to_string()),
+            _ => false,
+        }
+    }
+
+    /// The label in `lang` of the concept with `id`.
+    pub fn pref_label(&self, id: &str, lang: &str) -> Option<&str> {
+        self.concept(id)?.pref_label(lang)
+    }
+
+    /// An ELM `Concept` of the concept with `notation` in `scheme`.
+    pub fn concept_by_notation(&self, scheme: &str, notation: &str) -> Option<Concept> {
+        let vocabulary = self.vocabulary(scheme)?;
+
+        vocabulary
+            .concept_by_notation(notation)
+            .map(|concept| vocabulary.to_concept(concept))
+    }
+
+    /// An ELM `Concept` of the concept with the English label `label` in `scheme`.
+    pub fn concept_by_label(&self, scheme: &str, label: &str) -> Option<Concept> {
+        let vocabulary = self.vocabulary(scheme)?;
+
+        vocabulary
+            .concept_by_label("en", label)
+            .map(|concept| vocabulary.to_concept(concept))
+    }
+}
+
+#[cfg(feature = "vocabularies")]
+impl Concept {
+    /// An EQF level, none outside 1 to 8.
+    pub fn eqf_level(level: u8) -> Option<Concept> {
+        VocabularyRegistry::bundled().concept_by_notation(EQF, &level.to_string())
+    }
+
+    /// An ISCED-F 2013 field, e.g. `0613` for software and applications development and analysis.
+    pub fn isced_f(code: &str) -> Option<Concept> {
+        VocabularyRegistry::bundled().concept_by_notation(ISCED_F, code)
+    }
+
+    /// A country by its ISO 3166-1 alpha-3 code, e.g. `NLD`.
+    pub fn country(code: &str) -> Option<Concept> {
+        VocabularyRegistry::bundled().concept_by_notation(COUNTRY, code)
+    }
+
+    /// A language by its ISO 639-2 code, e.g. `ENG`.
+    pub fn language(code: &str) -> Option<Concept> {
+        VocabularyRegistry::bundled().concept_by_notation(LANGUAGE, code)
+    }
+
+    /// A credential type by its English label, e.g. `Generic`.
+    pub fn credential_type(label: &str) -> Option<Concept> {
+        VocabularyRegistry::bundled().concept_by_label(CREDENTIAL_TYPE, label)
+    }
+
+    /// A mode of assessment by its English label, e.g. `Online`.
+    pub fn learning_assessment(label: &str) -> Option<Concept> {
+        VocabularyRegistry::bundled().concept_by_label(LEARNING_ASSESSMENT, label)
+    }
+
+    /// A learning setting by its English label, e.g. `formal learning`.
+    pub fn learning_setting(label: &str) -> Option<Concept> {
+        VocabularyRegistry::bundled().concept_by_label(LEARNING_SETTING, label)
+    }
+}
@@ -0,0 +1,7 @@
+#!/usr/bin/env bash
+# Downloads the official 1EdTech Open Badges 3.0 context over the local stand-in in `contexts/`, `proof::rdfc`
+# canonicalizes `eddsa-rdfc-2022` proofs with it.
+
+cd "$(dirname $0)/contexts"
+
+wget -O ob-v3p0-context-3.0.2.jsonld https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.2.json
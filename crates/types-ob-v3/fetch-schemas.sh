@@ -0,0 +1,8 @@
+#!/usr/bin/env bash
+# Downloads the official 1EdTech Open Badges 3.0 JSON Schemas over the local stand-ins in `schemas/`, the validator
+# layers `schemas/local.json` on top of them.
+
+cd "$(dirname $0)/schemas"
+
+wget -O ob_v3p0_achievementcredential_schema.json https://purl.imsglobal.org/spec/ob/v3p0/schema/json/ob_v3p0_achievementcredential_schema.json
+wget -O ob_v3p0_endorsementcredential_schema.json https://purl.imsglobal.org/spec/ob/v3p0/schema/json/ob_v3p0_endorsementcredential_schema.json
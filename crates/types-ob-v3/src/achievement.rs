@@ -2,10 +2,10 @@ use std::fmt;
 
 use super::{alignment, endorsement, general, identity, profile, related, result::ResultDescription};
 use serde::{Deserialize, Serialize};
-use types_common::{GenPaths, SchemaList};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
 
 #[doc = "A collection of information about the accomplishment recognized by the Assertion. Many assertions may be created corresponding to one Achievement."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct Achievement {
     #[doc = "Unique URI for the Achievement."]
     pub id: String,
@@ -63,7 +63,7 @@ impl From<&Achievement> for Achievement {
 }
 
 #[doc = "The type of achievement. This is an extensible vocabulary."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AchievementType {
     Enum(AchievementTypeEnum),
@@ -94,7 +94,9 @@ impl std::str::FromStr for AchievementType {
     }
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub enum AchievementTypeEnum {
     Achievement,
     ApprenticeshipCertificate,
@@ -234,7 +236,7 @@ impl std::convert::TryFrom<String> for AchievementTypeEnum {
     }
 }
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, Query, Visit)]
 pub struct AchievementTypeString(String);
 impl std::ops::Deref for AchievementTypeString {
     type Target = String;
@@ -298,8 +300,14 @@ impl<'de> serde::Deserialize<'de> for AchievementTypeString {
     }
 }
 
+impl JsonSchema for AchievementTypeString {
+    fn json_schema(_generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+        serde_json::json!({ "type": "string", "pattern": "(ext:)[a-z|A-Z|0-9|.|-|_]+" })
+    }
+}
+
 #[doc = "Descriptive metadata about the achievements necessary to be recognized with an assertion of a particular achievement. This data is added to the Achievement class so that it may be rendered when the achievement assertion is displayed, instead of simply a link to human-readable criteria external to the achievement. Embedding criteria allows either enhancement of an external criteria page or increased portability and ease of use by allowing issuers to skip hosting the formerly-required external criteria page altogether. Criteria is used to allow would-be recipients to learn what is required of them to be recognized with an assertion of a particular achievement. It is also used after the assertion is awarded to a recipient to let those inspecting earned achievements know the general requirements that the recipients met in order to earn it."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct Criteria {
     #[doc = "The URI of a webpage that describes in a human-readable format the criteria for the achievement."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -315,7 +323,7 @@ impl From<&Criteria> for Criteria {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum Type {
     String(String),
@@ -350,7 +358,7 @@ impl From<Vec<&str>> for Type {
 }
 
 #[doc = "Allows endorsers to make specific claims about the Achievement. These endorsements are signed with the VC-JWT proof format."]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, Query, Visit)]
 pub struct AchievementEndorsementJwtItem(String);
 impl std::ops::Deref for AchievementEndorsementJwtItem {
     type Target = String;
@@ -417,73 +425,14 @@ impl<'de> serde::Deserialize<'de> for AchievementEndorsementJwtItem {
     }
 }
 
-#[doc = "The language of the achievement."]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
-pub struct AchievementLanguage(String);
-impl std::ops::Deref for AchievementLanguage {
-    type Target = String;
-    fn deref(&self) -> &String {
-        &self.0
-    }
-}
-
-impl From<AchievementLanguage> for String {
-    fn from(value: AchievementLanguage) -> Self {
-        value.0
-    }
-}
-
-impl From<&AchievementLanguage> for AchievementLanguage {
-    fn from(value: &AchievementLanguage) -> Self {
-        value.clone()
-    }
-}
-
-impl std::str::FromStr for AchievementLanguage {
-    type Err = &'static str;
-    fn from_str(value: &str) -> Result<Self, &'static str> {
-        if regex::Regex::new("^[a-z]{2,4}(-[A-Z][a-z]{3})?(-([A-Z]{2}|[0-9]{3}))?$")
-            .unwrap()
-            .is_match(value)
-        {
-            Ok(Self(value.to_string()))
-        } else {
-            Err("doesn't match pattern \"^[a-z]{2,4}(-[A-Z][a-z]{3})?(-([A-Z]{2}|[0-9]{3}))?$\"")
-        }
-    }
-}
-
-impl std::convert::TryFrom<&str> for AchievementLanguage {
-    type Error = &'static str;
-    fn try_from(value: &str) -> Result<Self, &'static str> {
-        value.parse()
-    }
-}
-
-impl std::convert::TryFrom<&String> for AchievementLanguage {
-    type Error = &'static str;
-    fn try_from(value: &String) -> Result<Self, &'static str> {
-        value.parse()
+impl JsonSchema for AchievementEndorsementJwtItem {
+    fn json_schema(_generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+        serde_json::json!({ "type": "string", "pattern": "^[a-zA-Z0-9_-]+\\.[a-zA-Z0-9_-]*\\.[a-zA-Z0-9_-]+$" })
     }
 }
 
-impl std::convert::TryFrom<String> for AchievementLanguage {
-    type Error = &'static str;
-    fn try_from(value: String) -> Result<Self, &'static str> {
-        value.parse()
-    }
-}
-
-impl<'de> serde::Deserialize<'de> for AchievementLanguage {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        String::deserialize(deserializer)?
-            .parse()
-            .map_err(|e: &'static str| <D::Error as serde::de::Error>::custom(e.to_string()))
-    }
-}
+#[doc = "The language of the achievement."]
+pub type AchievementLanguage = types_common::LangKey;
 
 #[derive(Clone, Debug, PartialEq)]
 pub struct AchievementBuilder {
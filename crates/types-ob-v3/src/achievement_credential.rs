@@ -1,9 +1,9 @@
 use super::{achievement_subject, endorsement, general, profile, proof_evidence};
 use serde::{Deserialize, Serialize};
-use types_common::{GenPaths, SchemaList};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
 
 #[doc = "AchievementCredentials are representations of an awarded achievement, used to share information about a achievement belonging to one earner. Maps to a Verifiable Credential as defined in the [[VC-DATA-MODEL]]. As described in [[[#data-integrity]]], at least one proof mechanism, and the details necessary to evaluate that proof, MUST be expressed for a credential to be a verifiable credential. In the case of an embedded proof, the credential MUST append the proof in the `proof` property."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct AchievementCredential {
     #[serde(rename = "@context")]
     pub context: Vec<general::Context>,
@@ -54,7 +54,7 @@ impl From<&AchievementCredential> for AchievementCredential {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AchievementCredentialSchema {
     Schema(CredentialSchema),
@@ -88,7 +88,7 @@ impl From<Vec<CredentialSchema>> for AchievementCredentialSchema {
 }
 
 #[doc = "Allows endorsers to make specific claims about the credential, and the achievement and profiles in the credential. These endorsements are signed with the VC-JWT proof format."]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, Query, Visit)]
 pub struct AchievementCredentialEndorsementJwtItem(String);
 impl std::ops::Deref for AchievementCredentialEndorsementJwtItem {
     type Target = String;
@@ -155,7 +155,13 @@ impl<'de> serde::Deserialize<'de> for AchievementCredentialEndorsementJwtItem {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+impl JsonSchema for AchievementCredentialEndorsementJwtItem {
+    fn json_schema(_generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+        serde_json::json!({ "type": "string", "pattern": "^[a-zA-Z0-9_-]+\\.[a-zA-Z0-9_-]*\\.[a-zA-Z0-9_-]+$" })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AchievementCredentialProof {
     Proof(proof_evidence::Proof),
@@ -180,7 +186,7 @@ impl From<Vec<proof_evidence::Proof>> for AchievementCredentialProof {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AchievementCredentialTermsOfUse {
     TermsOfUse(general::TermsOfUse),
@@ -205,7 +211,7 @@ impl From<Vec<general::TermsOfUse>> for AchievementCredentialTermsOfUse {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AchievementCredentialType {
     String(String),
@@ -239,7 +245,7 @@ impl From<Vec<&str>> for AchievementCredentialType {
 }
 
 #[doc = "Identify the type and location of a data schema."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct CredentialSchema {
     #[doc = "The value MUST be a URI identifying the schema file. One instance of `CredentialSchema` MUST have an `id` that is the URL of the JSON Schema for this credential defined by this specification."]
     pub id: String,
@@ -254,7 +260,7 @@ impl From<&CredentialSchema> for CredentialSchema {
 }
 
 #[doc = "The information in CredentialStatus is used to discover information about the current status of a verifiable credential, such as whether it is suspended or revoked."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct CredentialStatus {
     #[doc = "The value MUST be the URL of the issuer's credential status method."]
     pub id: String,
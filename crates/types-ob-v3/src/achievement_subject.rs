@@ -1,9 +1,9 @@
 use super::{achievement, general, identity, profile, result};
 use serde::{Deserialize, Serialize};
-use types_common::{GenPaths, SchemaList};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
 
 #[doc = "A collection of information about the recipient of an achievement. Maps to Credential Subject in [[VC-DATA-MODEL]]."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct AchievementSubject {
     #[doc = "An identifier for the Credential Subject. Either `id` or at least one `identifier` MUST be supplied."]
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -46,7 +46,7 @@ impl From<&AchievementSubject> for AchievementSubject {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AchievementSubjectType {
     String(String),
@@ -1,10 +1,10 @@
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
-use types_common::{GenPaths, SchemaList};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
 
 #[doc = "Describes an alignment between an achievement and a node in an educational framework."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct Alignment {
     #[serde(rename = "type")]
     pub type_: AlignmentType,
@@ -35,7 +35,7 @@ impl From<&Alignment> for Alignment {
 }
 
 #[doc = "The type of the alignment target node."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AlignmentTargetType {
     Enum(AlignmentTargetTypeEnum),
@@ -67,7 +67,9 @@ impl std::str::FromStr for AlignmentTargetType {
     }
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub enum AlignmentTargetTypeEnum {
     #[serde(rename = "ceasn:Competency")]
     CeasnCompetency,
@@ -135,7 +137,7 @@ impl std::convert::TryFrom<String> for AlignmentTargetTypeEnum {
         value.parse()
     }
 }
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, Query, Visit)]
 pub struct AlignmentTargetTypeString(String);
 impl std::ops::Deref for AlignmentTargetTypeString {
     type Target = String;
@@ -191,7 +193,13 @@ impl<'de> serde::Deserialize<'de> for AlignmentTargetTypeString {
             .map_err(|e: &'static str| <D::Error as serde::de::Error>::custom(e.to_string()))
     }
 }
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+
+impl JsonSchema for AlignmentTargetTypeString {
+    fn json_schema(_generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+        serde_json::json!({ "type": "string", "pattern": "(ext:)[a-z|A-Z|0-9|.|-|_]+" })
+    }
+}
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum AlignmentType {
     String(String),
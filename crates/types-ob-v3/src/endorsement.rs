@@ -1,9 +1,9 @@
 use super::{achievement_credential, general, profile, proof_evidence};
 use serde::{Deserialize, Serialize};
-use types_common::{GenPaths, SchemaList};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
 
 #[doc = "A verifiable credential that asserts a claim about an entity. As described in [[[#data-integrity]]], at least one proof mechanism, and the details necessary to evaluate that proof, MUST be expressed for a credential to be a verifiable credential. In the case of an embedded proof, the credential MUST append the proof in the `proof` property."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct EndorsementCredential {
     #[serde(rename = "@context")]
     pub context: Vec<general::Context>,
@@ -46,7 +46,7 @@ impl From<&EndorsementCredential> for EndorsementCredential {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum EndorsementCredentialSchema {
     Schema(achievement_credential::CredentialSchema),
@@ -67,7 +67,7 @@ impl From<Vec<achievement_credential::CredentialSchema>> for EndorsementCredenti
         Self::VecSchema(value)
     }
 }
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum EndorsementCredentialProof {
     Proof(proof_evidence::Proof),
@@ -88,7 +88,7 @@ impl From<Vec<proof_evidence::Proof>> for EndorsementCredentialProof {
         Self::VecProof(value)
     }
 }
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum EndorsementCredentialTermsOfUse {
     TermsOfUse(general::TermsOfUse),
@@ -109,7 +109,7 @@ impl From<Vec<general::TermsOfUse>> for EndorsementCredentialTermsOfUse {
         Self::VecTermsOfUse(value)
     }
 }
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum EndorsementCredentialType {
     String(String),
@@ -142,7 +142,7 @@ impl From<Vec<&str>> for EndorsementCredentialType {
     }
 }
 #[doc = "A collection of information about the subject of the endorsement."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct EndorsementSubject {
     #[doc = "The identifier of the individual, entity, organization, assertion, or achievement that is endorsed."]
     pub id: String,
@@ -158,7 +158,7 @@ impl From<&EndorsementSubject> for EndorsementSubject {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum EndorsementSubjectType {
     String(String),
@@ -1,10 +1,10 @@
 use std::fmt;
 
 use serde::{Deserialize, Serialize};
-use types_common::{GenPaths, SchemaList};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
 
 #[doc = "No description supplied."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(deny_unknown_fields)]
 pub struct IdentifierEntry {
     #[doc = "The value of the type property MUST be an unordered set. One of the items MUST be the IRI 'IdentifierEntry'."]
@@ -23,7 +23,7 @@ impl From<&IdentifierEntry> for IdentifierEntry {
 }
 
 #[doc = "The identifier type."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum IdentifierType {
     Enum(IdentifierTypeEnum),
@@ -56,7 +56,9 @@ impl std::str::FromStr for IdentifierType {
     }
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub enum IdentifierTypeEnum {
     #[serde(rename = "name")]
     Name,
@@ -172,7 +174,7 @@ impl std::convert::TryFrom<String> for IdentifierTypeEnum {
         value.parse()
     }
 }
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, Query, Visit)]
 pub struct IdentifierTypeString(String);
 impl std::ops::Deref for IdentifierTypeString {
     type Target = String;
@@ -228,8 +230,14 @@ impl<'de> serde::Deserialize<'de> for IdentifierTypeString {
             .map_err(|e: &'static str| <D::Error as serde::de::Error>::custom(e.to_string()))
     }
 }
+
+impl JsonSchema for IdentifierTypeString {
+    fn json_schema(_generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+        serde_json::json!({ "type": "string", "pattern": "(ext:)[a-z|A-Z|0-9|.|-|_]+" })
+    }
+}
 #[doc = "A collection of information about the recipient of an achievement."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(deny_unknown_fields)]
 pub struct IdentityObject {
     #[doc = "MUST be the IRI 'IdentityObject'."]
@@ -254,7 +262,7 @@ impl From<&IdentityObject> for IdentityObject {
 }
 
 #[doc = "The identity type."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum IdentityObjectType {
     Enum(IdentityObjectTypeEnum),
@@ -286,7 +294,9 @@ impl std::str::FromStr for IdentityObjectType {
     }
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub enum IdentityObjectTypeEnum {
     #[serde(rename = "name")]
     Name,
@@ -402,7 +412,7 @@ impl std::convert::TryFrom<String> for IdentityObjectTypeEnum {
         value.parse()
     }
 }
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, Query, Visit)]
 pub struct IdentityObjectTypeString(String);
 impl std::ops::Deref for IdentityObjectTypeString {
     type Target = String;
@@ -459,6 +469,12 @@ impl<'de> serde::Deserialize<'de> for IdentityObjectTypeString {
     }
 }
 
+impl JsonSchema for IdentityObjectTypeString {
+    fn json_schema(_generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+        serde_json::json!({ "type": "string", "pattern": "(ext:)[a-z|A-Z|0-9|.|-|_]+" })
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub struct IdentifierEntryBuilder {
     identifier: Result<String, String>,
@@ -0,0 +1,158 @@
+//! VC-JWT encoding of OBv3 credentials, as described in the "JSON Web Token Proof Format" section of the spec.
+//!
+//! The credential is the `vc` claim, `iss`, `sub`, `nbf`, `exp` and `jti` are derived from it.
+
+use std::{fmt, ops::Deref};
+
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::prelude::{AchievementCredential, EndorsementCredential};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JwtClaims<T> {
+    pub vc: T,
+    pub iss: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub: Option<String>,
+    pub nbf: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exp: Option<i64>,
+    pub jti: String,
+}
+
+/// A credential that can be the `vc` claim of a VC-JWT.
+pub trait JwtCredential: Serialize + DeserializeOwned + Clone {
+    fn claims(&self) -> JwtClaims<Self>;
+}
+
+impl JwtCredential for AchievementCredential {
+    fn claims(&self) -> JwtClaims<Self> {
+        JwtClaims {
+            vc: self.clone(),
+            iss: self.issuer.id.clone(),
+            sub: self.credential_subject.id.clone(),
+            nbf: self.issuance_date.timestamp(),
+            exp: self.expiration_date.map(|date| date.timestamp()),
+            jti: self.id.clone(),
+        }
+    }
+}
+
+impl JwtCredential for EndorsementCredential {
+    fn claims(&self) -> JwtClaims<Self> {
+        JwtClaims {
+            vc: self.clone(),
+            iss: self.issuer.id.clone(),
+            sub: Some(self.credential_subject.id.clone()),
+            nbf: self.issuance_date.timestamp(),
+            exp: self.expiration_date.map(|date| date.timestamp()),
+            jti: self.id.clone(),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum JwtError {
+    Jwt(jsonwebtoken::errors::Error),
+    /// The resolver has no key for the JWT header, e.g. an unknown `kid`.
+    UnknownKey,
+    /// A registered claim contradicts the `vc` claim, e.g. `iss` isn't the issuer id.
+    ClaimMismatch(&'static str),
+}
+
+impl fmt::Display for JwtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JwtError::Jwt(err) => write!(f, "{err}"),
+            JwtError::UnknownKey => write!(f, "No key found for the JWT"),
+            JwtError::ClaimMismatch(claim) => write!(f, "The `{claim}` claim doesn't match the credential"),
+        }
+    }
+}
+
+impl std::error::Error for JwtError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            JwtError::Jwt(err) => Some(err),
+            JwtError::UnknownKey | JwtError::ClaimMismatch(_) => None,
+        }
+    }
+}
+
+impl From<jsonwebtoken::errors::Error> for JwtError {
+    fn from(err: jsonwebtoken::errors::Error) -> Self {
+        JwtError::Jwt(err)
+    }
+}
+
+/// Looks up the key of a JWT by its header, e.g. the `kid`.
+///
+/// The algorithm comes with the key and not from the header, so a token can't pick a weaker algorithm for a key.
+pub trait JwtKeyResolver {
+    fn resolve(&self, header: &Header) -> Option<(Algorithm, DecodingKey)>;
+}
+
+impl<F> JwtKeyResolver for F
+where
+    F: Fn(&Header) -> Option<(Algorithm, DecodingKey)>,
+{
+    fn resolve(&self, header: &Header) -> Option<(Algorithm, DecodingKey)> {
+        self(header)
+    }
+}
+
+/// Encodes `credential` as a compact JWS, `header.typ` should stay `JWT`.
+pub fn encode<T: JwtCredential>(credential: &T, header: &Header, key: &EncodingKey) -> Result<String, JwtError> {
+    Ok(jsonwebtoken::encode(header, &credential.claims(), key)?)
+}
+
+/// Verifies the signature, `nbf` and `exp` of a VC-JWT and returns its `vc` claim.
+///
+/// `nbf` and `exp` have to be the `issuanceDate` and `expirationDate` of the credential, a token without `exp` can't
+/// extend the validity of an expired credential.
+pub fn decode<T: JwtCredential>(token: &str, resolver: &impl JwtKeyResolver) -> Result<T, JwtError> {
+    let header = jsonwebtoken::decode_header(token)?;
+    let (algorithm, key) = resolver.resolve(&header).ok_or(JwtError::UnknownKey)?;
+
+    let mut validation = Validation::new(algorithm);
+    validation.validate_nbf = true;
+    validation.validate_aud = false;
+    validation.set_required_spec_claims(&["iss", "nbf"]);
+
+    let claims = jsonwebtoken::decode::<JwtClaims<T>>(token, &key, &validation)?.claims;
+    let expected = claims.vc.claims();
+
+    if claims.iss != expected.iss {
+        return Err(JwtError::ClaimMismatch("iss"));
+    }
+
+    if claims.jti != expected.jti {
+        return Err(JwtError::ClaimMismatch("jti"));
+    }
+
+    if claims.sub.is_some() && claims.sub != expected.sub {
+        return Err(JwtError::ClaimMismatch("sub"));
+    }
+
+    if claims.nbf != expected.nbf {
+        return Err(JwtError::ClaimMismatch("nbf"));
+    }
+
+    if claims.exp != expected.exp {
+        return Err(JwtError::ClaimMismatch("exp"));
+    }
+
+    Ok(claims.vc)
+}
+
+/// Decodes the `endorsementJwt` items of a credential, achievement or profile.
+pub fn resolve_endorsements<I>(
+    items: &[I],
+    resolver: &impl JwtKeyResolver,
+) -> Result<Vec<EndorsementCredential>, JwtError>
+where
+    I: Deref<Target = String>,
+{
+    items.iter().map(|item| decode(item, resolver)).collect()
+}
@@ -5,14 +5,19 @@ mod alignment;
 mod endorsement;
 mod general;
 mod identity;
+#[cfg(feature = "jwt")]
+pub mod jwt;
 mod profile;
+#[cfg(feature = "proof")]
+pub mod proof;
 mod proof_evidence;
 mod related;
 mod result;
+mod visitor;
 
 pub mod prelude {
     pub use crate::{
         achievement::*, achievement_credential::*, achievement_subject::*, alignment::*, endorsement::*, general::*,
-        identity::*, profile::*, proof_evidence::*, related::*, result::*,
+        identity::*, profile::*, proof_evidence::*, related::*, result::*, visitor::*,
     };
 }
@@ -0,0 +1,381 @@
+//! Data Integrity proofs ([VC-DI-EDDSA](https://www.w3.org/TR/vc-di-eddsa/)) for OBv3 credentials.
+//!
+//! `eddsa-jcs-2022` canonicalizes with JCS (RFC 8785). `eddsa-rdfc-2022` canonicalizes with RDFC-1.0, by default
+//! with [`rdfc`], which knows the W3C contexts bundled by `linked-data` and the OBv3 context. Other contexts are passed
+//! to [`sign_with`] and [`verify_with`] with a [`Canonicalizer`] of their own, e.g. `rdfc().clone().with_context(…)`.
+//! A document with a context the canonicalizer doesn't know fails with [`ProofError::UnsupportedCanonicalization`].
+
+use std::{fmt, sync::OnceLock};
+
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use linked_data::rdfc::Rdfc;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+use crate::prelude::{AchievementCredential, EndorsementCredential, Proof};
+
+pub const DATA_INTEGRITY_PROOF: &str = "DataIntegrityProof";
+
+/// The OBv3 context the examples and schemas reference.
+pub const OB_CONTEXT_URL: &str = "https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.2.json";
+
+/// A local stand-in for the OBv3 context, `fetch-contexts.sh` replaces it with the official one.
+const OB_CONTEXT: &str = include_str!("../contexts/ob-v3p0-context-3.0.2.jsonld");
+
+/// The multicodec prefix of an Ed25519 public key in a `Multikey`.
+const ED25519_PUB_PREFIX: [u8; 2] = [0xed, 0x01];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Cryptosuite {
+    EddsaJcs2022,
+    EddsaRdfc2022,
+}
+
+impl Cryptosuite {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Cryptosuite::EddsaJcs2022 => "eddsa-jcs-2022",
+            Cryptosuite::EddsaRdfc2022 => "eddsa-rdfc-2022",
+        }
+    }
+}
+
+impl std::str::FromStr for Cryptosuite {
+    type Err = ProofError;
+
+    fn from_str(value: &str) -> Result<Self, ProofError> {
+        match value {
+            "eddsa-jcs-2022" => Ok(Cryptosuite::EddsaJcs2022),
+            "eddsa-rdfc-2022" => Ok(Cryptosuite::EddsaRdfc2022),
+            _ => Err(ProofError::UnsupportedCryptosuite(value.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Cryptosuite {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+#[derive(Debug)]
+pub enum ProofError {
+    UnsupportedCryptosuite(String),
+    /// The document can't be canonicalized for the cryptosuite, e.g. `eddsa-rdfc-2022` with a context the
+    /// canonicalizer doesn't know.
+    UnsupportedCanonicalization(String),
+    /// The proof lacks a field the cryptosuite needs, e.g. `proofValue`.
+    MissingField(&'static str),
+    /// The document has no `DataIntegrityProof` to verify.
+    NoProof,
+    /// The resolver doesn't know the `verificationMethod`.
+    UnknownVerificationMethod(String),
+    /// `proofValue` or a `publicKeyMultibase` isn't a base58btc multibase value of the right length.
+    InvalidMultibase(String),
+    InvalidSignature,
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProofError::UnsupportedCryptosuite(suite) => write!(f, "Unsupported cryptosuite: {suite}"),
+            ProofError::UnsupportedCanonicalization(reason) => write!(f, "Can't canonicalize the document: {reason}"),
+            ProofError::MissingField(field) => write!(f, "Proof is missing `{field}`"),
+            ProofError::NoProof => write!(f, "Credential has no DataIntegrityProof"),
+            ProofError::UnknownVerificationMethod(method) => write!(f, "Unknown verification method: {method}"),
+            ProofError::InvalidMultibase(value) => write!(f, "Invalid multibase value: {value}"),
+            ProofError::InvalidSignature => write!(f, "Signature doesn't match the credential"),
+            ProofError::Json(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ProofError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ProofError::Json(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for ProofError {
+    fn from(err: serde_json::Error) -> Self {
+        ProofError::Json(err)
+    }
+}
+
+/// Looks up the public key of a `verificationMethod`, e.g. from a DID document or a local key store.
+pub trait KeyResolver {
+    fn resolve(&self, verification_method: &str) -> Option<VerifyingKey>;
+}
+
+impl<F> KeyResolver for F
+where
+    F: Fn(&str) -> Option<VerifyingKey>,
+{
+    fn resolve(&self, verification_method: &str) -> Option<VerifyingKey> {
+        self(verification_method)
+    }
+}
+
+/// Canonicalizes a JSON-LD document into N-Quads with RDFC-1.0, as `eddsa-rdfc-2022` requires.
+pub trait Canonicalizer {
+    fn canonicalize(&self, document: &Value) -> Result<String, String>;
+}
+
+impl<F, E> Canonicalizer for F
+where
+    F: Fn(&Value) -> Result<String, E>,
+    E: fmt::Display,
+{
+    fn canonicalize(&self, document: &Value) -> Result<String, String> {
+        self(document).map_err(|err| err.to_string())
+    }
+}
+
+impl Canonicalizer for Rdfc {
+    fn canonicalize(&self, document: &Value) -> Result<String, String> {
+        Rdfc::canonicalize(self, document).map_err(|err| err.to_string())
+    }
+}
+
+/// The RDFC-1.0 canonicalizer of [`sign`] and [`verify`], with the bundled W3C contexts and the OBv3 context.
+pub fn rdfc() -> &'static Rdfc {
+    static RDFC: OnceLock<Rdfc> = OnceLock::new();
+
+    RDFC.get_or_init(|| {
+        Rdfc::new().with_context(
+            OB_CONTEXT_URL,
+            serde_json::from_str(OB_CONTEXT).expect("bundled context is valid JSON"),
+        )
+    })
+}
+
+/// Proof options, the `proofValue` is added by [`sign`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProofOptions {
+    pub cryptosuite: Cryptosuite,
+    pub verification_method: String,
+    pub created: chrono::DateTime<chrono::offset::Utc>,
+    /// Defaults to `assertionMethod`, the only purpose OBv3 allows for credentials.
+    pub proof_purpose: String,
+}
+
+impl ProofOptions {
+    pub fn new(verification_method: impl Into<String>) -> Self {
+        Self {
+            cryptosuite: Cryptosuite::EddsaJcs2022,
+            verification_method: verification_method.into(),
+            created: chrono::Utc::now(),
+            proof_purpose: "assertionMethod".to_string(),
+        }
+    }
+}
+
+/// A credential that can carry Data Integrity proofs.
+pub trait Securable: Serialize + DeserializeOwned {}
+
+impl Securable for AchievementCredential {}
+impl Securable for EndorsementCredential {}
+
+/// Signs `credential` and returns it with the new proof added to its existing ones.
+pub fn sign<T: Securable>(credential: &T, key: &SigningKey, options: &ProofOptions) -> Result<T, ProofError> {
+    Ok(serde_json::from_value(sign_value(
+        &serde_json::to_value(credential)?,
+        key,
+        options,
+    )?)?)
+}
+
+/// Verifies every `DataIntegrityProof` of `credential`.
+pub fn verify<T: Securable>(credential: &T, resolver: &impl KeyResolver) -> Result<(), ProofError> {
+    verify_value(&serde_json::to_value(credential)?, resolver)
+}
+
+/// [`sign`] with another canonicalizer for `eddsa-rdfc-2022`.
+pub fn sign_with<T: Securable>(
+    credential: &T,
+    key: &SigningKey,
+    options: &ProofOptions,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<T, ProofError> {
+    Ok(serde_json::from_value(sign_value_with(
+        &serde_json::to_value(credential)?,
+        key,
+        options,
+        canonicalizer,
+    )?)?)
+}
+
+/// [`verify`] with another canonicalizer for `eddsa-rdfc-2022`.
+pub fn verify_with<T: Securable>(
+    credential: &T,
+    resolver: &impl KeyResolver,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<(), ProofError> {
+    verify_value_with(&serde_json::to_value(credential)?, resolver, canonicalizer)
+}
+
+/// [`sign`] on the raw JSON of a credential.
+pub fn sign_value(document: &Value, key: &SigningKey, options: &ProofOptions) -> Result<Value, ProofError> {
+    secure(document, key, options, rdfc())
+}
+
+/// [`verify`] on the raw JSON of a credential, this avoids any difference the typed round trip could make.
+pub fn verify_value(document: &Value, resolver: &impl KeyResolver) -> Result<(), ProofError> {
+    check(document, resolver, rdfc())
+}
+
+/// [`sign_with`] on the raw JSON of a credential.
+pub fn sign_value_with(
+    document: &Value,
+    key: &SigningKey,
+    options: &ProofOptions,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<Value, ProofError> {
+    secure(document, key, options, canonicalizer)
+}
+
+/// [`verify_with`] on the raw JSON of a credential.
+pub fn verify_value_with(
+    document: &Value,
+    resolver: &impl KeyResolver,
+    canonicalizer: &impl Canonicalizer,
+) -> Result<(), ProofError> {
+    check(document, resolver, canonicalizer)
+}
+
+fn secure(
+    document: &Value,
+    key: &SigningKey,
+    options: &ProofOptions,
+    canonicalizer: &dyn Canonicalizer,
+) -> Result<Value, ProofError> {
+    let (mut secured, mut proofs) = split_proofs(document);
+
+    let proof = Proof {
+        type_: DATA_INTEGRITY_PROOF.to_string(),
+        challenge: None,
+        created: Some(options.created),
+        cryptosuite: Some(options.cryptosuite.to_string()),
+        domain: None,
+        nonce: None,
+        proof_purpose: Some(options.proof_purpose.clone()),
+        proof_value: None,
+        verification_method: Some(options.verification_method.clone()),
+    };
+    let mut proof = serde_json::to_value(proof)?;
+
+    let signature = key.sign(&hash_data(&secured, &proof, options.cryptosuite, canonicalizer)?);
+
+    proof["proofValue"] = format!("z{}", bs58::encode(signature.to_bytes()).into_string()).into();
+    proofs.push(proof);
+
+    secured["proof"] = match proofs.len() {
+        1 => proofs.remove(0),
+        _ => proofs.into(),
+    };
+
+    Ok(secured)
+}
+
+fn check(document: &Value, resolver: &impl KeyResolver, canonicalizer: &dyn Canonicalizer) -> Result<(), ProofError> {
+    let (unsecured, proofs) = split_proofs(document);
+
+    let proofs: Vec<_> = proofs
+        .into_iter()
+        .filter(|proof| proof["type"] == DATA_INTEGRITY_PROOF)
+        .collect();
+
+    if proofs.is_empty() {
+        return Err(ProofError::NoProof);
+    }
+
+    for mut proof in proofs {
+        let field = |name: &'static str| proof[name].as_str().ok_or(ProofError::MissingField(name));
+
+        let cryptosuite = field("cryptosuite")?.parse()?;
+        let verification_method = field("verificationMethod")?;
+        let signature = decode_multibase(field("proofValue")?)?;
+        let signature = Signature::from_slice(&signature).map_err(|_| ProofError::InvalidSignature)?;
+
+        let key = resolver
+            .resolve(verification_method)
+            .ok_or_else(|| ProofError::UnknownVerificationMethod(verification_method.to_string()))?;
+
+        if let Some(proof) = proof.as_object_mut() {
+            proof.remove("proofValue");
+        }
+
+        key.verify(&hash_data(&unsecured, &proof, cryptosuite, canonicalizer)?, &signature)
+            .map_err(|_| ProofError::InvalidSignature)?;
+    }
+
+    Ok(())
+}
+
+/// Decodes a `publicKeyMultibase` of an Ed25519 `Multikey`, e.g. `z6Mk…`.
+pub fn decode_multikey(public_key_multibase: &str) -> Result<VerifyingKey, ProofError> {
+    let invalid = || ProofError::InvalidMultibase(public_key_multibase.to_string());
+
+    let bytes = decode_multibase(public_key_multibase)?;
+    let key = bytes.strip_prefix(&ED25519_PUB_PREFIX).ok_or_else(invalid)?;
+
+    VerifyingKey::try_from(key).map_err(|_| invalid())
+}
+
+/// Encodes an Ed25519 public key as the `publicKeyMultibase` of a `Multikey`.
+pub fn encode_multikey(key: &VerifyingKey) -> String {
+    let bytes = [ED25519_PUB_PREFIX.as_slice(), key.as_bytes()].concat();
+
+    format!("z{}", bs58::encode(bytes).into_string())
+}
+
+fn decode_multibase(value: &str) -> Result<Vec<u8>, ProofError> {
+    value
+        .strip_prefix('z')
+        .and_then(|base58| bs58::decode(base58).into_vec().ok())
+        .ok_or_else(|| ProofError::InvalidMultibase(value.to_string()))
+}
+
+/// Removes the `proof` of the document, proofs in a proof set are created and verified independently.
+fn split_proofs(document: &Value) -> (Value, Vec<Value>) {
+    let mut unsecured = document.clone();
+
+    let proofs = match unsecured.as_object_mut().and_then(|object| object.remove("proof")) {
+        None => vec![],
+        Some(Value::Array(proofs)) => proofs,
+        Some(proof) => vec![proof],
+    };
+
+    (unsecured, proofs)
+}
+
+/// The hash of the proof configuration followed by the hash of the canonical document, which is what gets signed.
+fn hash_data(
+    unsecured: &Value,
+    proof_config: &Value,
+    cryptosuite: Cryptosuite,
+    canonicalizer: &dyn Canonicalizer,
+) -> Result<Vec<u8>, ProofError> {
+    let mut proof_config = proof_config.clone();
+
+    if let Some(context) = unsecured.get("@context") {
+        proof_config["@context"] = context.clone();
+    }
+
+    let canonicalize = |document: &Value| match cryptosuite {
+        Cryptosuite::EddsaJcs2022 => Ok(serde_jcs::to_string(document)?),
+        Cryptosuite::EddsaRdfc2022 => canonicalizer
+            .canonicalize(document)
+            .map_err(ProofError::UnsupportedCanonicalization),
+    };
+
+    let proof_config_hash = Sha256::digest(canonicalize(&proof_config)?);
+    let document_hash = Sha256::digest(canonicalize(unsecured)?);
+
+    Ok([proof_config_hash.as_slice(), document_hash.as_slice()].concat())
+}
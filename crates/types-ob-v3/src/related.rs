@@ -1,8 +1,8 @@
 use serde::{Deserialize, Serialize};
-use types_common::{GenPaths, SchemaList};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
 
 #[doc = "Identifies a related achievement."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct Related {
     #[doc = "The related achievement."]
     pub id: String,
@@ -23,74 +23,9 @@ impl From<&Related> for Related {
 }
 
 #[doc = "The language of the related achievement."]
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
-pub struct RelatedLanguage(String);
-impl std::ops::Deref for RelatedLanguage {
-    type Target = String;
-    fn deref(&self) -> &String {
-        &self.0
-    }
-}
-
-impl From<RelatedLanguage> for String {
-    fn from(value: RelatedLanguage) -> Self {
-        value.0
-    }
-}
-
-impl From<&RelatedLanguage> for RelatedLanguage {
-    fn from(value: &RelatedLanguage) -> Self {
-        value.clone()
-    }
-}
-
-impl std::str::FromStr for RelatedLanguage {
-    type Err = &'static str;
-    fn from_str(value: &str) -> Result<Self, &'static str> {
-        if regex::Regex::new("^[a-z]{2,4}(-[A-Z][a-z]{3})?(-([A-Z]{2}|[0-9]{3}))?$")
-            .unwrap()
-            .is_match(value)
-        {
-            Ok(Self(value.to_string()))
-        } else {
-            Err("doesn't match pattern \"^[a-z]{2,4}(-[A-Z][a-z]{3})?(-([A-Z]{2}|[0-9]{3}))?$\"")
-        }
-    }
-}
-
-impl std::convert::TryFrom<&str> for RelatedLanguage {
-    type Error = &'static str;
-    fn try_from(value: &str) -> Result<Self, &'static str> {
-        value.parse()
-    }
-}
-
-impl std::convert::TryFrom<&String> for RelatedLanguage {
-    type Error = &'static str;
-    fn try_from(value: &String) -> Result<Self, &'static str> {
-        value.parse()
-    }
-}
-
-impl std::convert::TryFrom<String> for RelatedLanguage {
-    type Error = &'static str;
-    fn try_from(value: String) -> Result<Self, &'static str> {
-        value.parse()
-    }
-}
-
-impl<'de> serde::Deserialize<'de> for RelatedLanguage {
-    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        String::deserialize(deserializer)?
-            .parse()
-            .map_err(|e: &'static str| <D::Error as serde::de::Error>::custom(e.to_string()))
-    }
-}
+pub type RelatedLanguage = types_common::LangKey;
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum RelatedType {
     String(String),
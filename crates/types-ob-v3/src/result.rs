@@ -2,11 +2,11 @@ use std::fmt;
 
 use super::alignment;
 use serde::{Deserialize, Serialize};
-use types_common::{GenPaths, SchemaList};
+use types_common::{GenPaths, JsonSchema, Query, SchemaList, Visit};
 
 /// Originally named: Result
 /// Describes a result that was achieved.
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct ResultAchievement {
     #[serde(rename = "type")]
     pub type_: ResultType,
@@ -33,7 +33,9 @@ impl From<&ResultAchievement> for ResultAchievement {
 }
 
 #[doc = "The status of the achievement. Required if `resultType` of the linked ResultDescription is Status."]
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub enum ResultStatus {
     Completed,
     Enrolled,
@@ -93,7 +95,7 @@ impl std::convert::TryFrom<String> for ResultStatus {
         value.parse()
     }
 }
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum ResultType {
     String(String),
@@ -127,7 +129,7 @@ impl From<Vec<&str>> for ResultType {
 }
 
 #[doc = "Describes a possible achievement result."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct ResultDescription {
     #[doc = "The unique URI for this result description. Required so a result can link to this result description."]
     pub id: String,
@@ -164,7 +166,7 @@ impl From<&ResultDescription> for ResultDescription {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum DescriptionType {
     String(String),
@@ -199,7 +201,7 @@ impl From<Vec<&str>> for DescriptionType {
 }
 
 #[doc = "The type of result this description represents. This is an extensible enumerated vocabulary."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum ResultDescriptionType {
     Enum(ResultDescriptionTypeEnum),
@@ -226,7 +228,9 @@ impl std::str::FromStr for ResultDescriptionType {
     }
 }
 
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(
+    Clone, Copy, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, JsonSchema, Query, Visit,
+)]
 pub enum ResultDescriptionTypeEnum {
     GradePointAverage,
     LetterGrade,
@@ -304,7 +308,7 @@ impl std::convert::TryFrom<String> for ResultDescriptionTypeEnum {
         value.parse()
     }
 }
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize, GenPaths, Query, Visit)]
 pub struct ResultDescriptionTypeString(String);
 impl std::ops::Deref for ResultDescriptionTypeString {
     type Target = String;
@@ -360,8 +364,14 @@ impl<'de> serde::Deserialize<'de> for ResultDescriptionTypeString {
             .map_err(|e: &'static str| <D::Error as serde::de::Error>::custom(e.to_string()))
     }
 }
+
+impl JsonSchema for ResultDescriptionTypeString {
+    fn json_schema(_generator: &mut types_common::SchemaGenerator) -> serde_json::Value {
+        serde_json::json!({ "type": "string", "pattern": "(ext:)[a-z|A-Z|0-9|.|-|_]+" })
+    }
+}
 #[doc = "Describes a rubric criterion level."]
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 pub struct RubricCriterionLevel {
     #[doc = "The unique URI for this rubric criterion level. Required so a result can link to this rubric criterion level."]
     pub id: String,
@@ -387,7 +397,7 @@ impl From<&RubricCriterionLevel> for RubricCriterionLevel {
     }
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths)]
+#[derive(Clone, Debug, Deserialize, Serialize, PartialEq, GenPaths, JsonSchema, Query, Visit)]
 #[serde(untagged)]
 pub enum RubricCriterionLevelType {
     String(String),
@@ -0,0 +1,18 @@
+use crate::prelude::*;
+
+// A typed visitor for every `Visit` type of the crate.
+types_common::visitor! {
+    pub trait ObVisitor, ObVisitorMut for
+    Achievement, AchievementType, AchievementTypeEnum, AchievementTypeString, Criteria, Type,
+    AchievementEndorsementJwtItem, AchievementCredential, AchievementCredentialSchema,
+    AchievementCredentialEndorsementJwtItem, AchievementCredentialProof, AchievementCredentialTermsOfUse,
+    AchievementCredentialType, CredentialSchema, CredentialStatus, AchievementSubject, AchievementSubjectType,
+    Alignment, AlignmentTargetType, AlignmentTargetTypeEnum, AlignmentTargetTypeString, AlignmentType,
+    EndorsementCredential, EndorsementCredentialSchema, EndorsementCredentialProof, EndorsementCredentialTermsOfUse,
+    EndorsementCredentialType, EndorsementSubject, EndorsementSubjectType, Context, Image, RefreshService,
+    TermsOfUse, IdentifierEntry, IdentifierType, IdentifierTypeEnum, IdentifierTypeString, IdentityObject,
+    IdentityObjectType, IdentityObjectTypeEnum, IdentityObjectTypeString, Profile, ProfileEndorsementJwtItem,
+    ProfileType, Address, AddressType, GeoCoordinates, Evidence, EvidenceType, Proof, Related, RelatedType,
+    ResultAchievement, ResultStatus, ResultType, ResultDescription, DescriptionType, ResultDescriptionType,
+    ResultDescriptionTypeEnum, ResultDescriptionTypeString, RubricCriterionLevel, RubricCriterionLevelType,
+}
@@ -4,8 +4,12 @@ mod alignment_credential_engine;
 mod basic_achievement_credential;
 mod endorsement_credential;
 mod full_achievement_credential;
+mod jwt;
+mod proof;
+mod query;
 mod skill_assertion_case;
 mod skill_assertion_credential_engine;
+mod visit;
 
 // Below are functions defined for use across all OBv3 example tests.
 
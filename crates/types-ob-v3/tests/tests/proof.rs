@@ -0,0 +1,197 @@
+use crate::tests::json_example;
+use ed25519_dalek::{SigningKey, VerifyingKey};
+use types_ob_v3::{prelude::*, proof::*};
+
+const ISSUER_KEY: &str = "https://example.edu/issuers/565049#key-1";
+
+fn issuer_key() -> SigningKey {
+    SigningKey::from_bytes(&[7; 32])
+}
+
+fn resolver(verification_method: &str) -> Option<VerifyingKey> {
+    (verification_method == ISSUER_KEY).then(|| issuer_key().verifying_key())
+}
+
+fn options() -> ProofOptions {
+    ProofOptions {
+        created: "2024-01-01T00:00:00Z".parse().unwrap(),
+        ..ProofOptions::new(ISSUER_KEY)
+    }
+}
+
+#[test]
+fn sign_and_verify() {
+    let credential: AchievementCredential = json_example("tests/obv3_json_examples/basic_achievement_credential.json");
+
+    let signed = sign(&credential, &issuer_key(), &options()).unwrap();
+    let Some(AchievementCredentialProof::Proof(proof)) = &signed.proof else {
+        panic!("expected a single proof");
+    };
+    assert_eq!(proof.type_, "DataIntegrityProof");
+    assert_eq!(proof.cryptosuite.as_deref(), Some("eddsa-jcs-2022"));
+    assert!(proof.proof_value.as_ref().is_some_and(|value| value.starts_with('z')));
+
+    verify(&signed, &resolver).unwrap();
+
+    let mut tampered = signed.clone();
+    tampered.name = "Another Degree".to_string();
+    assert!(matches!(
+        verify(&tampered, &resolver),
+        Err(ProofError::InvalidSignature)
+    ));
+
+    assert!(matches!(verify(&credential, &resolver), Err(ProofError::NoProof)));
+    assert!(matches!(
+        verify(&signed, &|_: &str| None),
+        Err(ProofError::UnknownVerificationMethod(_))
+    ));
+}
+
+#[test]
+fn proof_set() {
+    let credential: EndorsementCredential = json_example("tests/obv3_json_examples/endorsement_credential.json");
+    let other_key = SigningKey::from_bytes(&[9; 32]);
+
+    let signed = sign(&credential, &issuer_key(), &options()).unwrap();
+    let signed = sign(
+        &signed,
+        &other_key,
+        &ProofOptions::new("https://state.gov/issuers/565049#key-1"),
+    )
+    .unwrap();
+
+    let resolver = |verification_method: &str| match verification_method {
+        ISSUER_KEY => Some(issuer_key().verifying_key()),
+        "https://state.gov/issuers/565049#key-1" => Some(other_key.verifying_key()),
+        _ => None,
+    };
+
+    verify(&signed, &resolver).unwrap();
+}
+
+#[test]
+fn rdfc_proof() {
+    let mut credential: serde_json::Value = json_example("tests/obv3_json_examples/basic_achievement_credential.json");
+    // The VC 1.1 context doesn't define `DataIntegrityProof`.
+    credential["@context"]
+        .as_array_mut()
+        .unwrap()
+        .push("https://w3id.org/security/data-integrity/v1".into());
+
+    let options = ProofOptions {
+        cryptosuite: Cryptosuite::EddsaRdfc2022,
+        ..options()
+    };
+
+    let signed = sign_value(&credential, &issuer_key(), &options).unwrap();
+    assert_eq!(signed["proof"]["cryptosuite"], "eddsa-rdfc-2022");
+    verify_value(&signed, &resolver).unwrap();
+
+    // The signature covers the graph, not the JSON: reordering the types keeps it valid, changing a value doesn't.
+    let mut reordered = signed.clone();
+    reordered["type"] = serde_json::json!(["OpenBadgeCredential", "VerifiableCredential"]);
+    verify_value(&reordered, &resolver).unwrap();
+
+    let mut tampered = signed.clone();
+    tampered["name"] = "Another Badge".into();
+    assert!(matches!(
+        verify_value(&tampered, &resolver),
+        Err(ProofError::InvalidSignature)
+    ));
+
+    // A context that isn't bundled or supplied can't be canonicalized, nor a property no context defines.
+    let mut unknown = credential.clone();
+    unknown["@context"][1] = "https://example.org/unknown-context".into();
+    assert!(matches!(
+        sign_value(&unknown, &issuer_key(), &options),
+        Err(ProofError::UnsupportedCanonicalization(_))
+    ));
+
+    let mut undefined = credential.clone();
+    undefined["@context"] = serde_json::json!([
+        "https://www.w3.org/2018/credentials/v1",
+        "https://w3id.org/security/data-integrity/v1"
+    ]);
+    assert!(matches!(
+        sign_value(&undefined, &issuer_key(), &options),
+        Err(ProofError::UnsupportedCanonicalization(_))
+    ));
+
+    let extended = rdfc().clone().with_context(
+        "https://example.org/unknown-context",
+        serde_json::json!({ "@context": { "@vocab": "https://example.org/vocab#" } }),
+    );
+    let signed = sign_value_with(&unknown, &issuer_key(), &options, &extended).unwrap();
+    verify_value_with(&signed, &resolver, &extended).unwrap();
+
+    let failing = |_: &serde_json::Value| Err::<String, _>("unknown context");
+    assert!(matches!(
+        sign_value_with(&credential, &issuer_key(), &options, &failing),
+        Err(ProofError::UnsupportedCanonicalization(reason)) if reason == "unknown context"
+    ));
+}
+
+/// The `eddsa-rdfc-2022` example of the W3C Data Integrity EdDSA Cryptosuites specification, appendix B.1.
+#[test]
+fn eddsa_rdfc_2022_test_vector() {
+    const PUBLIC_KEY: &str = "z6MkrJVnaZkeFzdQyMZu1cgjg7k1pZZ6pvBQ7XJPt4swbTQ2";
+    const SECRET_KEY: &str = "z3u2en7t5LR2WtQH5PfFqMqwVHBeXouLzo6haApm8XHqvjxq";
+    const PROOF_VALUE: &str =
+        "z2YwC8z3ap7yx1nZYCg4L3j3ApHsF8kgPdSb5xoS1VR7vPG3F561B52hYnQF9iseabecm3ijx4K1FBTQsCZahKZme";
+
+    let unsecured = serde_json::json!({
+        "@context": [
+            "https://www.w3.org/ns/credentials/v2",
+            "https://www.w3.org/ns/credentials/examples/v2"
+        ],
+        "id": "urn:uuid:58172aac-d8ba-11ed-83dd-0b3aef56cc33",
+        "type": ["VerifiableCredential", "AlumniCredential"],
+        "name": "Alumni Credential",
+        "description": "A minimum viable example of an Alumni Credential.",
+        "issuer": "https://vc.example/issuers/5678",
+        "validFrom": "2023-01-01T00:00:00Z",
+        "credentialSubject": {
+            "id": "did:example:abcdefgh",
+            "alumniOf": "The School of Examples"
+        }
+    });
+    let verification_method = format!("did:key:{PUBLIC_KEY}#{PUBLIC_KEY}");
+
+    let mut secured = unsecured.clone();
+    secured["proof"] = serde_json::json!({
+        "type": "DataIntegrityProof",
+        "cryptosuite": "eddsa-rdfc-2022",
+        "created": "2023-02-24T23:36:38Z",
+        "verificationMethod": verification_method,
+        "proofPurpose": "assertionMethod",
+        "proofValue": PROOF_VALUE
+    });
+
+    let public_key = decode_multikey(PUBLIC_KEY).unwrap();
+    let resolver = |method: &str| (method == verification_method).then_some(public_key);
+    verify_value(&secured, &resolver).unwrap();
+
+    // Ed25519 is deterministic, signing with the secret key of the example gives its proof value.
+    let secret_key = bs58::decode(&SECRET_KEY[1..]).into_vec().unwrap();
+    let secret_key = secret_key.strip_prefix(&[0x80, 0x26]).unwrap();
+    let key = SigningKey::try_from(secret_key).unwrap();
+    assert_eq!(key.verifying_key(), public_key);
+
+    let options = ProofOptions {
+        cryptosuite: Cryptosuite::EddsaRdfc2022,
+        created: "2023-02-24T23:36:38Z".parse().unwrap(),
+        ..ProofOptions::new(verification_method.clone())
+    };
+    let signed = sign_value(&unsecured, &key, &options).unwrap();
+    assert_eq!(signed["proof"]["proofValue"], PROOF_VALUE);
+}
+
+#[test]
+fn multikey() {
+    let key = issuer_key().verifying_key();
+    let multibase = encode_multikey(&key);
+
+    assert!(multibase.starts_with("z6Mk"));
+    assert_eq!(decode_multikey(&multibase).unwrap(), key);
+    assert!(matches!(decode_multikey("zabc"), Err(ProofError::InvalidMultibase(_))));
+}
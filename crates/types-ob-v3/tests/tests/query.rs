@@ -0,0 +1,29 @@
+use types_common::Query;
+use types_ob_v3::prelude::*;
+
+use crate::tests::json_example;
+
+#[test]
+fn query() {
+    let credential: AchievementCredential = json_example("tests/obv3_json_examples/full_achievement_credential.json");
+
+    assert_eq!(
+        credential
+            .query_as::<String>("$.credentialSubject.achievement.name")
+            .unwrap(),
+        vec!["1EdTech University Degree"]
+    );
+    assert_eq!(
+        credential
+            .query_as::<String>("$.credentialSubject.result[*].alignment[*].targetName")
+            .unwrap(),
+        vec!["Final Project", "Final Project"]
+    );
+    assert_eq!(
+        credential
+            .query_as::<ResultStatus>("$.credentialSubject.result[*].status")
+            .unwrap(),
+        vec![&ResultStatus::Completed]
+    );
+    assert_eq!(credential.query("$..targetUrl").unwrap().len(), 8);
+}
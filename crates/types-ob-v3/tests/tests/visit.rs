@@ -0,0 +1,51 @@
+use types_common::{Visit, Walk};
+use types_ob_v3::prelude::*;
+
+use crate::tests::json_example;
+
+#[test]
+fn visit() {
+    let mut credential: AchievementCredential =
+        json_example("tests/obv3_json_examples/full_achievement_credential.json");
+
+    let mut alignments = vec![];
+    credential.for_each(|alignment: &Alignment| alignments.push(alignment.target_name.as_str()));
+    assert_eq!(alignments.len(), 8);
+
+    credential.for_each_mut(|result: &mut ResultAchievement| result.value = None);
+
+    let mut values = vec![];
+    credential.for_each(|result: &ResultAchievement| values.push(result.value.clone()));
+    assert!(!values.is_empty());
+    assert!(values.iter().all(Option::is_none));
+}
+
+#[test]
+fn typed_visitor() {
+    struct Alignments<'a>(Vec<&'a str>);
+
+    impl<'a> ObVisitor<'a> for Alignments<'a> {
+        fn visit_alignment(&mut self, node: &'a Alignment) {
+            self.0.push(node.target_name.as_str());
+            node.walk(self);
+        }
+    }
+
+    struct ClearResults;
+
+    impl ObVisitorMut for ClearResults {
+        fn visit_result_achievement_mut(&mut self, node: &mut ResultAchievement) {
+            node.value = None;
+        }
+    }
+
+    let mut credential: AchievementCredential =
+        json_example("tests/obv3_json_examples/full_achievement_credential.json");
+
+    let mut alignments = Alignments(vec![]);
+    alignments.visit_achievement_credential(&credential);
+    assert_eq!(alignments.0.len(), 8);
+
+    ClearResults.visit_achievement_credential_mut(&mut credential);
+    credential.for_each(|result: &ResultAchievement| assert!(result.value.is_none()));
+}
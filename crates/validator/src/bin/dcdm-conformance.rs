@@ -0,0 +1,78 @@
+use std::{fs, path::PathBuf, process::ExitCode};
+
+use clap::{Parser, ValueEnum};
+use serde_json::Value;
+use validator::{conformance, schema::EDC_SCHEMA, shacl::EDC_SHAPES, turtle, ValidatorError};
+
+/// Compares the Rust ELM model with the EDC JSON Schema and SHACL shapes: missing fields, extra fields and
+/// cardinality mismatches.
+///
+/// Exits with 0 when the model conforms, 1 when there are differences and 2 when a specification can't be read.
+#[derive(Debug, Parser)]
+#[command(name = "dcdm-conformance", version)]
+struct Args {
+    /// The EDC JSON Schema, defaults to the bundled `types-elm-v3/schema.json`.
+    #[arg(long)]
+    schema: Option<PathBuf>,
+
+    /// The EDC SHACL shapes in Turtle, defaults to the bundled `shacl-validator/shape.ttl`.
+    #[arg(long)]
+    shapes: Option<PathBuf>,
+
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Human,
+    Json,
+}
+
+fn main() -> ExitCode {
+    let args = Args::parse();
+
+    let specification = read(args.schema.as_ref(), EDC_SCHEMA).and_then(|schema| {
+        let schema: Value = serde_json::from_str(&schema)?;
+        let shapes = read(args.shapes.as_ref(), EDC_SHAPES)?;
+        let shapes = turtle::parse(&shapes).map_err(|err| ValidatorError::Shacl(err.into()))?;
+
+        Ok((schema, shapes))
+    });
+
+    let (schema, shapes) = match specification {
+        Ok(specification) => specification,
+        Err(err) => {
+            eprintln!("{err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let differences = conformance::diff_edc(&schema, &shapes);
+
+    match args.format {
+        Format::Human => {
+            for difference in &differences {
+                println!("{difference}");
+            }
+            println!("{} differences", differences.len());
+        }
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&differences).expect("differences serialize")
+        ),
+    }
+
+    if differences.is_empty() {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn read(path: Option<&PathBuf>, bundled: &str) -> Result<String, ValidatorError> {
+    match path {
+        Some(path) => Ok(fs::read_to_string(path)?),
+        None => Ok(bundled.to_string()),
+    }
+}
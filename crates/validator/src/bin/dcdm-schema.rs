@@ -0,0 +1,61 @@
+use clap::{Parser, ValueEnum};
+use serde_json::Value;
+use types_common::{schema_for, SchemaGraph};
+use types_elm_v3::EuropassEdcCredential;
+use types_ob_v3::prelude::AchievementCredential;
+
+/// Prints the data model of a credential as a class diagram, a table of its fields or a JSON Schema.
+#[derive(Debug, Parser)]
+#[command(name = "dcdm-schema", version)]
+struct Args {
+    #[arg(value_enum)]
+    model: Model,
+
+    #[arg(long, value_enum, default_value_t = Format::Mermaid)]
+    format: Format,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Model {
+    /// `EuropassEdcCredential`
+    Elm,
+    /// OBv3 `AchievementCredential`
+    Ob,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Dot,
+    Mermaid,
+    Csv,
+    Json,
+    /// JSON Schema draft 2020-12
+    JsonSchema,
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let (graph, json_schema) = match args.model {
+        Model::Elm => (
+            SchemaGraph::of::<EuropassEdcCredential>(),
+            schema_for::<EuropassEdcCredential>(),
+        ),
+        Model::Ob => (
+            SchemaGraph::of::<AchievementCredential>(),
+            schema_for::<AchievementCredential>(),
+        ),
+    };
+
+    match args.format {
+        Format::Dot => print!("{}", graph.to_dot()),
+        Format::Mermaid => print!("{}", graph.to_mermaid()),
+        Format::Csv => print!("{}", graph.to_csv()),
+        Format::Json => print_json(&graph.to_json()),
+        Format::JsonSchema => print_json(&json_schema),
+    }
+}
+
+fn print_json(value: &Value) {
+    println!("{}", serde_json::to_string_pretty(value).expect("JSON serializes"));
+}
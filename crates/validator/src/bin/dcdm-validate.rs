@@ -0,0 +1,279 @@
+use std::{
+    fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::{Parser, ValueEnum};
+use env_logger::Env;
+use serde::Serialize;
+use validator::{
+    report::{ValidationReport, Violation},
+    CredentialKind, ValidatorError,
+};
+
+/// Validates ELM and OBv3 credentials: Rust deserialization, SHACL shapes and JSON Schema.
+///
+/// Exits with 0 when every credential is valid, 1 when at least one is invalid, including input that isn't JSON, and 2
+/// when an input can't be read.
+#[derive(Debug, Parser)]
+#[command(name = "dcdm-validate", version)]
+struct Args {
+    /// Credential files or directories, directories are searched for `.json` and `.jsonld` files. Reads stdin
+    /// when empty or `-`.
+    inputs: Vec<PathBuf>,
+
+    #[arg(long, value_enum, default_value_t = Format::Human)]
+    format: Format,
+
+    /// Only these checks decide the verdict, defaults to all of them.
+    #[arg(long = "check", value_enum)]
+    checks: Vec<Check>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Human,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Check {
+    Shacl,
+    Schema,
+    Deserialization,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Outcome {
+    source: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kind: Option<CredentialKind>,
+    valid: bool,
+    /// `None` when the check doesn't apply to the credential kind.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    valid_shacl: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    valid_schema: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    valid_deserialization: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    report: Option<ValidationReport>,
+    /// Set when the input couldn't be validated at all.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl Outcome {
+    fn error(source: String, kind: Option<CredentialKind>, err: impl ToString) -> Self {
+        Self {
+            source,
+            kind,
+            valid: false,
+            valid_shacl: None,
+            valid_schema: None,
+            valid_deserialization: None,
+            report: None,
+            error: Some(err.to_string()),
+        }
+    }
+
+    /// The input isn't JSON, an invalid credential rather than an input that couldn't be validated.
+    fn unparseable(source: String, err: &serde_json::Error) -> Self {
+        Self {
+            source,
+            kind: None,
+            valid: false,
+            valid_shacl: None,
+            valid_schema: None,
+            valid_deserialization: Some(false),
+            report: Some(ValidationReport {
+                violations: vec![Violation::from_parse(err)],
+            }),
+            error: None,
+        }
+    }
+
+    fn verdict(&mut self, checks: &[Check]) {
+        let selected = |check: Check| checks.is_empty() || checks.contains(&check);
+
+        self.valid = [
+            (Check::Shacl, self.valid_shacl),
+            (Check::Schema, self.valid_schema),
+            (Check::Deserialization, self.valid_deserialization),
+        ]
+        .into_iter()
+        .filter(|(check, _)| selected(*check))
+        .all(|(_, valid)| valid.unwrap_or(true))
+            && self.error.is_none();
+    }
+}
+
+fn main() -> ExitCode {
+    env_logger::init_from_env(Env::default().filter_or("DCDM_LOG_LEVEL", "off"));
+
+    let args = Args::parse();
+
+    let inputs = if args.inputs.is_empty() {
+        vec![PathBuf::from("-")]
+    } else {
+        args.inputs
+    };
+
+    let mut files = Vec::new();
+    let mut outcomes = Vec::new();
+
+    for input in inputs {
+        if input.is_dir() {
+            if let Err(err) = collect_files(&input, &mut files) {
+                outcomes.push(Outcome::error(input.display().to_string(), None, err));
+            }
+        } else {
+            files.push(input);
+        }
+    }
+
+    outcomes.extend(files.iter().map(|file| {
+        let mut outcome = validate_input(file);
+        outcome.verdict(&args.checks);
+        outcome
+    }));
+
+    match args.format {
+        Format::Human => print_human(&outcomes),
+        Format::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&outcomes).expect("outcomes serialize")
+        ),
+    }
+
+    if outcomes.iter().any(|outcome| outcome.error.is_some()) {
+        ExitCode::from(2)
+    } else if outcomes.iter().all(|outcome| outcome.valid) {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+/// Recursively adds the `.json` and `.jsonld` files of `dir`, sorted by path.
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    let mut entries = fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .collect::<io::Result<Vec<_>>>()?;
+    entries.sort();
+
+    for path in entries {
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else if path.extension().is_some_and(|e| e == "json" || e == "jsonld") {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_input(input: &Path) -> Outcome {
+    let stdin = input == Path::new("-");
+    let source = if stdin {
+        "<stdin>".to_string()
+    } else {
+        input.display().to_string()
+    };
+
+    let json = if stdin {
+        let mut json = String::new();
+        io::stdin().read_to_string(&mut json).map(|_| json)
+    } else {
+        fs::read_to_string(input)
+    };
+
+    let json = match json {
+        Ok(json) => json,
+        Err(err) => return Outcome::error(source, None, ValidatorError::from(err)),
+    };
+
+    let document = match validator::parse_str(&json) {
+        Ok(document) => document,
+        Err(ValidatorError::Deserialization { source: err, .. }) => return Outcome::unparseable(source, &err),
+        Err(err) => return Outcome::error(source, None, err),
+    };
+
+    let Some(kind) = validator::detect(&document) else {
+        return Outcome::error(source, None, "Unknown credential, expected an ELM or OBv3 credential");
+    };
+
+    match kind {
+        CredentialKind::Elm => match validator::validate_value(&document) {
+            Ok(request) => Outcome {
+                source,
+                kind: Some(kind),
+                valid: false,
+                valid_shacl: Some(request.valid_shacl),
+                valid_schema: Some(request.valid_schema),
+                valid_deserialization: Some(request.rust_object.is_ok()),
+                report: Some(request.report),
+                error: None,
+            },
+            Err(err) => Outcome::error(source, Some(kind), err),
+        },
+        CredentialKind::ObV3 => match validator::validate_ob_value(&document) {
+            Ok(request) => Outcome {
+                source,
+                kind: Some(kind),
+                valid: false,
+                valid_shacl: None,
+                valid_schema: Some(request.valid_schema),
+                valid_deserialization: Some(request.rust_object.is_ok()),
+                report: Some(request.report),
+                error: None,
+            },
+            Err(err) => Outcome::error(source, Some(kind), err),
+        },
+    }
+}
+
+fn print_human(outcomes: &[Outcome]) {
+    let verdict = |valid: Option<bool>| match valid {
+        Some(true) => "ok",
+        Some(false) => "failed",
+        None => "n/a",
+    };
+
+    for outcome in outcomes {
+        let status = if outcome.error.is_some() {
+            "ERROR"
+        } else if outcome.valid {
+            "VALID"
+        } else {
+            "INVALID"
+        };
+
+        match outcome.kind {
+            Some(kind) => println!("{status} {} ({kind})", outcome.source),
+            None => println!("{status} {}", outcome.source),
+        }
+
+        if let Some(err) = &outcome.error {
+            println!("  {err}");
+            continue;
+        }
+
+        println!(
+            "  SHACL: {}, JSON Schema: {}, deserialization: {}",
+            verdict(outcome.valid_shacl),
+            verdict(outcome.valid_schema),
+            verdict(outcome.valid_deserialization)
+        );
+
+        for violation in outcome.report.iter().flat_map(ValidationReport::iter) {
+            println!("  {violation}");
+        }
+    }
+
+    let valid = outcomes.iter().filter(|outcome| outcome.valid).count();
+    println!("\n{valid}/{} credentials valid", outcomes.len());
+}
@@ -0,0 +1,364 @@
+//! Compares the Rust ELM model with the specification it implements.
+//!
+//! The fields of the model are the rows of `#[derive(GenPaths)]`, the [`SchemaGraph`] of `EuropassEdcCredential`.
+//! They are compared with the definitions of the EDC JSON Schema (`types-elm-v3/schema.json`) and the property
+//! shapes of the SHACL shapes (`shacl-validator/shape.ttl`), which catches fields the model lacks, fields it has but
+//! the specification doesn't define and fields whose multiplicity or required flag differ.
+//!
+//! A Rust type `X` is matched with the definition `X` or `XType` of the JSON Schema and with the shape whose
+//! `sh:targetClass` compacts to `X` under the bundled JSON-LD context. The SHACL shapes only constrain the
+//! controlled vocabularies, so a shape's missing `sh:minCount` or `sh:maxCount` isn't compared and the model's
+//! other fields aren't extra. The required `type` tags of the model aren't compared with the optional `type` of the
+//! JSON Schema.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use types_common::{Multiplicity, SchemaData, SchemaGraph};
+use types_elm_v3::EuropassEdcCredential;
+
+use crate::{
+    jsonld::Context,
+    rdf::{Graph, Term},
+    shacl::SH,
+};
+
+/// Which part of the specification a difference was found against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SpecSource {
+    Schema,
+    Shacl,
+}
+
+impl fmt::Display for SpecSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecSource::Schema => write!(f, "JSON Schema"),
+            SpecSource::Shacl => write!(f, "SHACL"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DifferenceKind {
+    /// A class of the specification without a Rust type.
+    MissingSchema,
+    /// A property of the specification that the Rust type lacks.
+    MissingField,
+    /// A Rust field that the specification doesn't define.
+    ExtraField,
+    /// The multiplicity or the required flag of a field differ.
+    Cardinality,
+}
+
+impl fmt::Display for DifferenceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DifferenceKind::MissingSchema => write!(f, "missing schema"),
+            DifferenceKind::MissingField => write!(f, "missing field"),
+            DifferenceKind::ExtraField => write!(f, "extra field"),
+            DifferenceKind::Cardinality => write!(f, "cardinality mismatch"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Difference {
+    pub source: SpecSource,
+    /// The Rust type, or the name of the specification's class for [`DifferenceKind::MissingSchema`].
+    pub schema: String,
+    /// The JSON name of the field, empty for [`DifferenceKind::MissingSchema`].
+    pub field: String,
+    pub kind: DifferenceKind,
+    /// The cardinality of the field in the model, e.g. `1|*, optional` against the JSON Schema or `0..*` against
+    /// SHACL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model: Option<String>,
+    /// The cardinality of the field in the specification, in the same notation as `model`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spec: Option<String>,
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {} ", self.source, self.kind)?;
+
+        if self.field.is_empty() {
+            write!(f, "{}", self.schema)?;
+        } else {
+            write!(f, "{}.{}", self.schema, self.field)?;
+        }
+
+        match (&self.model, &self.spec) {
+            (Some(model), Some(spec)) => write!(f, ", model {model}, spec {spec}"),
+            (Some(model), None) => write!(f, ", model {model}"),
+            (None, Some(spec)) => write!(f, ", spec {spec}"),
+            (None, None) => Ok(()),
+        }
+    }
+}
+
+/// Compares `EuropassEdcCredential` with a release of the EDC JSON Schema and SHACL shapes, e.g. the bundled
+/// [`crate::schema::EDC_SCHEMA`] and [`crate::shacl::EDC_SHAPES`].
+pub fn diff_edc(schema: &Value, shapes: &Graph) -> Vec<Difference> {
+    let graph = SchemaGraph::of::<EuropassEdcCredential>();
+
+    let mut differences = diff_schema(&graph, "EuropassEdcCredential", schema);
+    differences.extend(diff_shapes(&graph, shapes, Context::edc()));
+
+    differences
+}
+
+/// Compares the structs of `graph` with the definitions of a JSON Schema, `root` is the type of the document itself.
+pub fn diff_schema(graph: &SchemaGraph, root: &str, schema: &Value) -> Vec<Difference> {
+    let empty = Map::new();
+    let definitions = schema["$defs"].as_object().unwrap_or(&empty);
+    let mut matched = BTreeSet::new();
+    let mut differences = vec![];
+
+    for (name, fields) in structs(graph) {
+        let definition = if name == root {
+            Some(root_definition(schema))
+        } else {
+            [name.to_string(), format!("{name}Type")]
+                .into_iter()
+                .find(|key| definitions.get(key).is_some_and(|d| d.get("properties").is_some()))
+                .map(|key| {
+                    let definition = definitions[&key].clone();
+                    matched.insert(key);
+                    definition
+                })
+        };
+
+        let Some(definition) = definition else {
+            continue;
+        };
+
+        let required: BTreeSet<&str> = definition["required"]
+            .as_array()
+            .map(|required| required.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+        let properties = definition["properties"].as_object().unwrap_or(&empty);
+
+        for (field, row) in &fields {
+            let Some(property) = properties.get(*field) else {
+                differences.push(Difference {
+                    source: SpecSource::Schema,
+                    schema: name.to_string(),
+                    field: field.to_string(),
+                    kind: DifferenceKind::ExtraField,
+                    model: Some(json_cardinality(&row.multiplicity, row.required)),
+                    spec: None,
+                });
+                continue;
+            };
+
+            let spec_multiplicity = property_multiplicity(property);
+            let spec_required = required.contains(*field);
+            // The model needs the `type` tag to tell the classes apart, the schema never requires it.
+            let required_differs = spec_required != row.required && *field != "type";
+
+            if spec_multiplicity != row.multiplicity || required_differs {
+                differences.push(Difference {
+                    source: SpecSource::Schema,
+                    schema: name.to_string(),
+                    field: field.to_string(),
+                    kind: DifferenceKind::Cardinality,
+                    model: Some(json_cardinality(&row.multiplicity, row.required)),
+                    spec: Some(json_cardinality(&spec_multiplicity, spec_required)),
+                });
+            }
+        }
+
+        for (field, property) in properties {
+            if !fields.contains_key(field.as_str()) {
+                differences.push(Difference {
+                    source: SpecSource::Schema,
+                    schema: name.to_string(),
+                    field: field.clone(),
+                    kind: DifferenceKind::MissingField,
+                    model: None,
+                    spec: Some(json_cardinality(
+                        &property_multiplicity(property),
+                        required.contains(field.as_str()),
+                    )),
+                });
+            }
+        }
+    }
+
+    for (key, definition) in definitions {
+        if definition.get("properties").is_some() && !matched.contains(key) {
+            differences.push(Difference {
+                source: SpecSource::Schema,
+                schema: key.clone(),
+                field: String::new(),
+                kind: DifferenceKind::MissingSchema,
+                model: None,
+                spec: None,
+            });
+        }
+    }
+
+    differences.sort();
+    differences
+}
+
+/// Compares the structs of `graph` with the property shapes of the shapes with a `sh:targetClass`.
+pub fn diff_shapes(graph: &SchemaGraph, shapes: &Graph, context: &Context) -> Vec<Difference> {
+    let schemas = structs(graph);
+    let mut differences = vec![];
+
+    for shape in shapes.subjects_with(&format!("{SH}targetClass")) {
+        for class in shapes.objects(shape, &format!("{SH}targetClass")) {
+            let Some(class) = class.as_iri().and_then(|class| context.compact_iri(class)) else {
+                continue;
+            };
+
+            let Some(fields) = schemas.get(class.as_str()) else {
+                differences.push(Difference {
+                    source: SpecSource::Shacl,
+                    schema: class,
+                    field: String::new(),
+                    kind: DifferenceKind::MissingSchema,
+                    model: None,
+                    spec: None,
+                });
+                continue;
+            };
+
+            for property in shapes.objects(shape, &format!("{SH}property")) {
+                let Some(field) = shapes
+                    .object(property, &format!("{SH}path"))
+                    .and_then(Term::as_iri)
+                    .and_then(|path| context.compact_iri(path))
+                else {
+                    continue;
+                };
+
+                let min_count = count(shapes, property, "minCount");
+                let max_count = count(shapes, property, "maxCount");
+                let spec = Some(shacl_cardinality(min_count.unwrap_or(0), max_count));
+
+                let Some(row) = fields.get(field.as_str()) else {
+                    differences.push(Difference {
+                        source: SpecSource::Shacl,
+                        schema: class.clone(),
+                        field,
+                        kind: DifferenceKind::MissingField,
+                        model: None,
+                        spec,
+                    });
+                    continue;
+                };
+
+                let model_max_one = row.multiplicity == Multiplicity::One;
+                let min_differs = min_count.is_some_and(|min| (min > 0) != row.required);
+                let max_differs = max_count.is_some_and(|max| (max == 1) != model_max_one);
+
+                if min_differs || max_differs {
+                    let model_max = if model_max_one { Some(1) } else { None };
+
+                    differences.push(Difference {
+                        source: SpecSource::Shacl,
+                        schema: class.clone(),
+                        field,
+                        kind: DifferenceKind::Cardinality,
+                        model: Some(shacl_cardinality(u64::from(row.required), model_max)),
+                        spec,
+                    });
+                }
+            }
+        }
+    }
+
+    differences.sort();
+    differences.dedup();
+    differences
+}
+
+/// The fields of the structs of `graph` by their JSON name. Enums, whose rows have no field, are left out.
+fn structs(graph: &SchemaGraph) -> BTreeMap<&str, BTreeMap<&str, &SchemaData>> {
+    let mut structs: BTreeMap<&str, BTreeMap<&str, &SchemaData>> = BTreeMap::new();
+
+    for row in graph.rows().iter().filter(|row| !row.src_field.is_empty()) {
+        // Untagged enum fields have a row per variant, they share the multiplicity.
+        structs
+            .entry(&row.src_schema)
+            .or_default()
+            .entry(&row.src_field)
+            .or_insert(row);
+    }
+
+    structs
+}
+
+/// The properties and required properties of the document itself, spread over its `allOf`.
+fn root_definition(schema: &Value) -> Value {
+    let mut properties = Map::new();
+    let mut required = vec![];
+
+    let parts = std::iter::once(schema).chain(schema["allOf"].as_array().into_iter().flatten());
+
+    for part in parts {
+        if let Some(part_properties) = part["properties"].as_object() {
+            properties.extend(part_properties.clone());
+        }
+
+        if let Some(part_required) = part["required"].as_array() {
+            required.extend(part_required.iter().cloned());
+        }
+    }
+
+    serde_json::json!({ "properties": properties, "required": required })
+}
+
+/// `Many!X` definitions and `oneOf` a value or an array are one or many, arrays are many.
+fn property_multiplicity(property: &Value) -> Multiplicity {
+    let is_array = |schema: &Value| schema["type"] == "array";
+
+    if is_array(property) {
+        Multiplicity::Many
+    } else if property["oneOf"]
+        .as_array()
+        .is_some_and(|schemas| schemas.iter().any(is_array))
+        || property["$ref"]
+            .as_str()
+            .is_some_and(|reference| reference.starts_with("#/$defs/Many!"))
+    {
+        Multiplicity::OneOrMany
+    } else {
+        Multiplicity::One
+    }
+}
+
+fn json_cardinality(multiplicity: &Multiplicity, required: bool) -> String {
+    if required {
+        format!("{multiplicity}, required")
+    } else {
+        format!("{multiplicity}, optional")
+    }
+}
+
+/// The UML notation of a count range, e.g. `0..1`.
+fn shacl_cardinality(min: u64, max: Option<u64>) -> String {
+    match max {
+        Some(max) if max == min => max.to_string(),
+        Some(max) => format!("{min}..{max}"),
+        None => format!("{min}..*"),
+    }
+}
+
+fn count(shapes: &Graph, property: &Term, constraint: &str) -> Option<u64> {
+    shapes
+        .object(property, &format!("{SH}{constraint}"))
+        .and_then(Term::as_literal)
+        .and_then(|literal| literal.value.parse().ok())
+}
@@ -0,0 +1,67 @@
+use std::fmt;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// The data model a credential belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CredentialKind {
+    /// European Learning Model, deserialized into `EuropassEdcCredential`.
+    Elm,
+    /// Open Badges 3.0.
+    ObV3,
+}
+
+impl fmt::Display for CredentialKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CredentialKind::Elm => write!(f, "ELM"),
+            CredentialKind::ObV3 => write!(f, "OBv3"),
+        }
+    }
+}
+
+/// Detects the data model from the credential `type` entries, falling back to the JSON-LD `@context`.
+pub fn detect(document: &Value) -> Option<CredentialKind> {
+    let strings = |value: Option<&Value>| -> Vec<String> {
+        match value {
+            Some(Value::String(value)) => vec![value.clone()],
+            Some(Value::Array(values)) => values.iter().filter_map(|v| v.as_str().map(str::to_string)).collect(),
+            _ => vec![],
+        }
+    };
+
+    let types = strings(document.get("type"));
+
+    if types.iter().any(|type_| type_ == "EuropeanDigitalCredential") {
+        return Some(CredentialKind::Elm);
+    }
+
+    if types.iter().any(|type_| {
+        matches!(
+            type_.as_str(),
+            "OpenBadgeCredential" | "AchievementCredential" | "EndorsementCredential"
+        )
+    }) {
+        return Some(CredentialKind::ObV3);
+    }
+
+    let contexts = strings(document.get("@context"));
+
+    if contexts
+        .iter()
+        .any(|context| context.starts_with("http://data.europa.eu/snb/"))
+    {
+        return Some(CredentialKind::Elm);
+    }
+
+    if contexts
+        .iter()
+        .any(|context| context.starts_with("https://purl.imsglobal.org/spec/ob/"))
+    {
+        return Some(CredentialKind::ObV3);
+    }
+
+    None
+}
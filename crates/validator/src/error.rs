@@ -0,0 +1,63 @@
+use std::{fmt, io, path::PathBuf};
+
+use crate::shacl::ShaclError;
+
+#[derive(Debug)]
+pub enum ValidatorError {
+    /// The path doesn't point to a file.
+    MissingFile(PathBuf),
+    /// The file isn't a `.json` or `.jsonld` file.
+    InvalidExtension(PathBuf),
+    /// The SHACL engine couldn't process the shapes or the credential.
+    Shacl(ShaclError),
+    /// The input isn't valid JSON, or doesn't deserialize into the credential type.
+    Deserialization {
+        path: String,
+        source: serde_json::Error,
+    },
+    Io(io::Error),
+}
+
+impl fmt::Display for ValidatorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ValidatorError::MissingFile(path) => write!(f, "Not a valid file: {}", path.display()),
+            ValidatorError::InvalidExtension(path) => write!(f, "Not a json(ld) file: {}", path.display()),
+            ValidatorError::Shacl(err) => write!(f, "SHACL validation failed: {err}"),
+            ValidatorError::Deserialization { path, source } => write!(f, "Deserialization failed at {path}: {source}"),
+            ValidatorError::Io(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for ValidatorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ValidatorError::Shacl(err) => Some(err),
+            ValidatorError::Deserialization { source, .. } => Some(source),
+            ValidatorError::Io(err) => Some(err),
+            ValidatorError::MissingFile(_) | ValidatorError::InvalidExtension(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ValidatorError {
+    fn from(err: io::Error) -> Self {
+        ValidatorError::Io(err)
+    }
+}
+
+impl From<ShaclError> for ValidatorError {
+    fn from(err: ShaclError) -> Self {
+        ValidatorError::Shacl(err)
+    }
+}
+
+impl From<serde_json::Error> for ValidatorError {
+    fn from(err: serde_json::Error) -> Self {
+        ValidatorError::Deserialization {
+            path: "$".to_string(),
+            source: err,
+        }
+    }
+}
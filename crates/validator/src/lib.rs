@@ -1,21 +1,67 @@
-use std::{fs, io, path::PathBuf};
-use validator::ValidateRequest;
+pub use detect::{detect, CredentialKind};
+pub use error::ValidatorError;
+pub use linked_data::{jsonld, rdf, rdfc, turtle};
+pub use ob::{ObCredential, ObValidateRequest};
+use std::{fs, io::Read, path::PathBuf};
+pub use validator::{validate_shacl, ValidateRequest};
+pub mod conformance;
+pub mod detect;
+mod error;
+pub mod ob;
+pub mod report;
+pub mod schema;
+pub mod shacl;
+pub mod skos;
 mod validator;
 
-pub fn validate(file: PathBuf) -> io::Result<ValidateRequest> {
+pub fn validate(file: PathBuf) -> Result<ValidateRequest, ValidatorError> {
+    ValidateRequest::new(json_file(file)?)
+}
+
+/// Validates an OBv3 `AchievementCredential` or `EndorsementCredential` file.
+pub fn validate_ob(file: PathBuf) -> Result<ObValidateRequest, ValidatorError> {
+    ObValidateRequest::new(json_file(file)?)
+}
+
+pub fn validate_ob_value(value: &serde_json::Value) -> Result<ObValidateRequest, ValidatorError> {
+    ObValidateRequest::from_value(value)
+}
+
+fn json_file(file: PathBuf) -> Result<PathBuf, ValidatorError> {
     if !file.is_file() {
-        panic!("Not a valid file!");
+        return Err(ValidatorError::MissingFile(file));
     }
 
     let is_json = file.extension().map(|e| e == "json" || e == "jsonld").unwrap_or(false);
 
     if !is_json {
-        panic!("Not a json(ld) file");
+        return Err(ValidatorError::InvalidExtension(file));
     }
 
-    let absolute = fs::canonicalize(&file)?;
+    Ok(fs::canonicalize(&file)?)
+}
+
+/// Validates a credential from a JSON(-LD) string, without touching the filesystem.
+pub fn validate_str(json: &str) -> Result<ValidateRequest, ValidatorError> {
+    ValidateRequest::from_json(json)
+}
+
+/// Parses a JSON(-LD) document without the recursion limit of `serde_json`, like [`validate_str`] does, so deeply
+/// nested credentials parse.
+pub fn parse_str(json: &str) -> Result<serde_json::Value, ValidatorError> {
+    validator::parse_json(json)
+}
+
+pub fn validate_value(value: &serde_json::Value) -> Result<ValidateRequest, ValidatorError> {
+    ValidateRequest::from_value(value)
+}
+
+/// Reads the whole credential from `reader`, e.g. an HTTP request body, and validates it.
+pub fn validate_reader(mut reader: impl Read) -> Result<ValidateRequest, ValidatorError> {
+    let mut json = String::new();
+    reader.read_to_string(&mut json)?;
 
-    ValidateRequest::new(absolute)
+    ValidateRequest::from_json(&json)
 }
 
 pub fn manifest_dir() -> PathBuf {
@@ -24,12 +70,10 @@ pub fn manifest_dir() -> PathBuf {
 
 #[cfg(test)]
 mod tests {
-    use std::{fs::File, io::BufWriter};
+    use std::fs::File;
 
     use env_logger::Env;
 
-    use crate::validator::validate_shacl;
-
     use super::*;
 
     #[ctor::ctor]
@@ -42,18 +86,53 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn no_file() {
-        let _ = validate(PathBuf::new());
+        assert!(matches!(validate(PathBuf::new()), Err(ValidatorError::MissingFile(_))));
     }
 
     #[test]
-    #[should_panic]
     fn no_json_file() {
-        let _ = validate(PathBuf::from("lib.rs"));
+        let file = manifest_dir().join("src/lib.rs");
+        assert!(matches!(validate(file), Err(ValidatorError::InvalidExtension(_))));
+    }
+
+    #[test]
+    fn test_in_memory() {
+        let json = fs::read_to_string(manifest_dir().join("elm-requests/microcredential.json")).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        for result in [
+            validate_str(&json).unwrap(),
+            validate_value(&value).unwrap(),
+            validate_reader(json.as_bytes()).unwrap(),
+        ] {
+            assert!(result.valid_shacl);
+            assert!(result.rust_object.is_ok());
+        }
+
+        assert!(matches!(
+            validate_str("{ invalid"),
+            Err(ValidatorError::Deserialization { .. })
+        ));
+        assert!(matches!(
+            validate_value(&serde_json::json!("not a credential")),
+            Err(ValidatorError::Shacl(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_deep() {
+        let json = format!("{}{}", "[".repeat(1000), "]".repeat(1000));
+
+        assert!(serde_json::from_str::<serde_json::Value>(&json).is_err());
+        assert!(parse_str(&json).is_ok());
+        assert!(matches!(
+            parse_str("{ invalid"),
+            Err(ValidatorError::Deserialization { .. })
+        ));
     }
 
-    fn validate_file(filename: &str) -> io::Result<()> {
+    fn validate_file(filename: &str) -> Result<(), ValidatorError> {
         let manifest_dir = manifest_dir();
 
         let file_path = format!("elm-requests/{}", filename);
@@ -69,55 +148,561 @@ mod tests {
 
             let obj = result.rust_object.unwrap();
 
-            let tmp = std::env::temp_dir().join("digital-credential-data-models");
-
-            if !tmp.is_dir() {
-                fs::create_dir(tmp.clone())?;
-            }
-
-            let path = tmp.join(filename);
-            let file = File::create(&path)?;
-            let writer = BufWriter::new(file);
-            serde_json::to_writer(writer, &obj)?;
-            let result = validate_shacl(&path);
+            let result = validate_value(&serde_json::to_value(&obj)?);
 
             if let Some(err) = result.as_ref().err() {
                 panic!("Serialize err: {}", err);
             }
 
-            assert!(result.unwrap(), "Serialize is valid");
+            let result = result.unwrap();
+            assert!(result.valid_shacl, "Serialize is valid");
+            assert!(result.rust_object.is_ok(), "Serialize deserializes again");
         }
 
         Ok(())
     }
 
     #[test]
-    fn test_bengales_diploma() -> io::Result<()> {
+    fn test_bengales_diploma() -> Result<(), ValidatorError> {
         validate_file("bengales-highschool-diploma.json")
     }
 
     #[test]
-    fn test_digicomp_generic() -> io::Result<()> {
+    fn test_digicomp_generic() -> Result<(), ValidatorError> {
         validate_file("digicomp-generic.json")
     }
 
     #[test]
-    fn test_rntuo_credential() -> io::Result<()> {
+    fn test_rntuo_credential() -> Result<(), ValidatorError> {
         validate_file("diploma-rntuo-credential.json")
     }
 
     #[test]
-    fn test_francisco_cruz() -> io::Result<()> {
+    fn test_francisco_cruz() -> Result<(), ValidatorError> {
         validate_file("francisco-cruz.json")
     }
 
     #[test]
-    fn test_microcredential() -> io::Result<()> {
+    fn test_microcredential() -> Result<(), ValidatorError> {
         validate_file("microcredential.json")
     }
 
     #[test]
-    fn test_transcript_of_records() -> io::Result<()> {
+    fn test_transcript_of_records() -> Result<(), ValidatorError> {
         validate_file("transcript-of-records.json")
     }
+
+    #[test]
+    fn test_shacl_violation() -> Result<(), ValidatorError> {
+        let file = File::open(manifest_dir().join("elm-requests/microcredential.json"))?;
+        let mut credential: serde_json::Value = serde_json::from_reader(file)?;
+
+        credential["displayParameter"]["primaryLanguage"] = serde_json::json!({
+            "id": "http://publications.europa.eu/resource/authority/country/BEL",
+            "type": "Concept",
+            "inScheme": {
+                "id": "http://publications.europa.eu/resource/authority/country",
+                "type": "ConceptScheme"
+            }
+        });
+
+        let data = jsonld::to_rdf(&credential).unwrap();
+        let results = shacl::Shapes::edc().validate(&data);
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(
+            results[0].result_path,
+            Some(shacl::PropertyPath::Predicate(
+                "http://data.europa.eu/snb/model/elm/primaryLanguage".to_string()
+            ))
+        );
+        assert_eq!(
+            results[0].source_constraint_component,
+            "http://www.w3.org/ns/shacl#NodeConstraintComponent"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_validation_report() -> Result<(), ValidatorError> {
+        let file = File::open(manifest_dir().join("elm-requests/microcredential.json"))?;
+        let mut credential: serde_json::Value = serde_json::from_reader(file)?;
+
+        credential["displayParameter"]["primaryLanguage"]["id"] =
+            "http://publications.europa.eu/resource/authority/language/XXX".into();
+        credential["displayParameter"]["primaryLanguage"]["inScheme"]["id"] =
+            "http://publications.europa.eu/resource/authority/country".into();
+        credential["validFrom"] = 42.into();
+
+        let result = validate_value(&credential)?;
+        assert!(!result.valid_shacl);
+        assert!(result.rust_object.is_err());
+
+        let shacl: Vec<_> = result.report.by_source(report::ViolationSource::Shacl).collect();
+        assert!(!shacl.is_empty());
+        assert!(shacl
+            .iter()
+            .all(|violation| violation.json_path.starts_with("$.") && violation.constraint.is_some()));
+        assert!(shacl
+            .iter()
+            .any(|violation| violation.json_path == "$.displayParameter.primaryLanguage"));
+
+        let deserialization: Vec<_> = result
+            .report
+            .by_source(report::ViolationSource::Deserialization)
+            .collect();
+        assert_eq!(deserialization.len(), 1);
+        assert_eq!(deserialization[0].json_path, "$.validFrom");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_schema_validation() -> Result<(), ValidatorError> {
+        let file = File::open(manifest_dir().join("elm-requests/microcredential.json"))?;
+        let mut credential: serde_json::Value = serde_json::from_reader(file)?;
+
+        // The upstream EBSI examples don't pass the upstream schema, e.g. the issuer lacks `eidasLegalIdentifier`.
+        let report = schema::validate_schema(&credential);
+        assert!(!report.conforms());
+        assert!(report.at("$.issuer").any(|violation| violation
+            .constraint
+            .as_deref()
+            .is_some_and(|keyword| keyword.ends_with("/required"))));
+        assert!(report.at("$.validFrom").next().is_none());
+
+        credential["validFrom"] = "yesterday".into();
+
+        // All three verdicts differ: SHACL only sees a literal, the schema and serde both reject the date.
+        let result = validate_value(&credential)?;
+        assert!(result.valid_shacl);
+        assert!(!result.valid_schema);
+        assert!(result.rust_object.is_err());
+
+        let valid_from: Vec<_> = result.report.at("$.validFrom").collect();
+        assert!(valid_from
+            .iter()
+            .any(|violation| violation.source == report::ViolationSource::Schema
+                && violation
+                    .constraint
+                    .as_deref()
+                    .is_some_and(|keyword| keyword.ends_with("/format"))));
+        assert!(valid_from
+            .iter()
+            .any(|violation| violation.source == report::ViolationSource::Deserialization));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect() {
+        let elm: serde_json::Value = serde_json::from_str(
+            &fs::read_to_string(manifest_dir().join("elm-requests/microcredential.json")).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(detect(&elm), Some(CredentialKind::Elm));
+
+        let ob = serde_json::json!({
+            "@context": ["https://www.w3.org/ns/credentials/v2", "https://purl.imsglobal.org/spec/ob/v3p0/context-3.0.3.json"],
+            "type": ["VerifiableCredential", "OpenBadgeCredential"]
+        });
+        assert_eq!(detect(&ob), Some(CredentialKind::ObV3));
+
+        let ob_context = serde_json::json!({ "@context": ob["@context"] });
+        assert_eq!(detect(&ob_context), Some(CredentialKind::ObV3));
+
+        assert_eq!(detect(&serde_json::json!({ "type": "VerifiableCredential" })), None);
+    }
+
+    fn validate_ob_file(filename: &str) -> Result<ObValidateRequest, ValidatorError> {
+        validate_ob(
+            manifest_dir()
+                .join("../types-ob-v3/tests/obv3_json_examples")
+                .join(filename),
+        )
+    }
+
+    #[test]
+    fn test_ob_examples() -> Result<(), ValidatorError> {
+        for filename in [
+            "alignment_case.json",
+            "alignment_credential_engine.json",
+            "basic_achievement_credential.json",
+            "full_achievement_credential.json",
+            "skill_assertion_case.json",
+            "skill_assertion_credential_engine.json",
+        ] {
+            let result = validate_ob_file(filename)?;
+            assert!(result.report.conforms(), "{filename}: {}", result.report);
+            assert!(matches!(result.rust_object, Ok(ObCredential::Achievement(_))));
+        }
+
+        let result = validate_ob_file("endorsement_credential.json")?;
+        assert!(result.valid_schema, "{}", result.report);
+        assert!(matches!(result.rust_object, Ok(ObCredential::Endorsement(_))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_ob_schema() -> Result<(), ValidatorError> {
+        let file = File::open(
+            manifest_dir().join("../types-ob-v3/tests/obv3_json_examples/full_achievement_credential.json"),
+        )?;
+        let mut credential: serde_json::Value = serde_json::from_reader(file)?;
+
+        credential["@context"].as_array_mut().unwrap().swap(0, 1);
+        credential["type"] = serde_json::json!(["VerifiableCredential"]);
+        credential["credentialSubject"]["identifier"][0]["hashed"] = true.into();
+        credential["credentialSubject"]["identifier"][1]["hashed"] = true.into();
+        credential["credentialSubject"]["identifier"][1]["identityHash"] =
+            "sha256$28d1ebd1d1d4ea7ea1c5c4f5e6a0ba36ad2c8e5dc6ba8b1a0a17c6d3a1f0b2e5".into();
+
+        let result = validate_ob_value(&credential)?;
+        assert!(!result.valid_schema);
+        assert!(result.rust_object.is_ok());
+
+        assert_eq!(result.report.at("$.@context").count(), 2);
+        assert_eq!(result.report.at("$.type").count(), 1);
+        assert_eq!(
+            result
+                .report
+                .at("$.credentialSubject.identifier[0].identityHash")
+                .count(),
+            1
+        );
+        assert_eq!(result.report.at("$.credentialSubject.identifier[1]").count(), 0);
+
+        // The context order and the identity hash are local rules on top of the 1EdTech schema.
+        let local = &schema::ob_achievement_schema().local;
+        assert_eq!(local.iter_errors(&credential).count(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_derived_schema() -> Result<(), ValidatorError> {
+        let schema = |schema: serde_json::Value| {
+            jsonschema::draft202012::options()
+                .should_validate_formats(true)
+                .build(&schema)
+                .expect("derived schema is a valid JSON Schema")
+        };
+
+        let edc = schema(types_common::schema_for::<types_elm_v3::EuropassEdcCredential>());
+        for entry in fs::read_dir(manifest_dir().join("elm-requests"))? {
+            let path = entry?.path();
+            let credential: serde_json::Value = serde_json::from_reader(File::open(&path)?)?;
+
+            let errors: Vec<_> = edc.iter_errors(&credential).map(|err| err.to_string()).collect();
+            assert!(errors.is_empty(), "{}: {errors:?}", path.display());
+        }
+
+        // A typed status matches its own variant only, other types go to the catch-all.
+        let json = fs::read_to_string(manifest_dir().join("elm-requests/microcredential.json"))?;
+        let mut credential: serde_json::Value = serde_json::from_str(&json)?;
+        let status = serde_json::json!({
+            "id": "https://example.org/status/1#42",
+            "type": "StatusList2021Entry",
+            "statusPurpose": "revocation",
+            "statusListIndex": "42",
+            "statusListCredential": "https://example.org/status/1"
+        });
+        credential["credentialStatus"] = status.clone();
+        let errors: Vec<_> = edc.iter_errors(&credential).map(|err| err.to_string()).collect();
+        assert!(errors.is_empty(), "{errors:?}");
+
+        credential["credentialStatus"] =
+            serde_json::json!({ "id": "https://example.org/status/2", "type": "RevocationList2020Status" });
+        assert!(edc.is_valid(&credential));
+
+        // A typed status that doesn't conform isn't taken by the catch-all either.
+        credential["credentialStatus"] = status;
+        credential["credentialStatus"]["statusListIndex"] = 42.into();
+        assert!(!edc.is_valid(&credential));
+
+        let achievement = schema(types_common::schema_for::<types_ob_v3::prelude::AchievementCredential>());
+        let file = File::open(
+            manifest_dir().join("../types-ob-v3/tests/obv3_json_examples/full_achievement_credential.json"),
+        )?;
+        let mut credential: serde_json::Value = serde_json::from_reader(file)?;
+        assert!(achievement.is_valid(&credential));
+
+        // An `achievementType` is one of the OBv3 types or an `ext:` extension.
+        credential["credentialSubject"]["achievement"]["achievementType"] = "ext:Trophy".into();
+        assert!(achievement.is_valid(&credential));
+        credential["credentialSubject"]["achievement"]["achievementType"] = "Trophy".into();
+        assert!(!achievement.is_valid(&credential));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conformance() -> Result<(), ValidatorError> {
+        use conformance::{Difference, DifferenceKind, SpecSource};
+
+        let mut schema: serde_json::Value = serde_json::from_str(schema::EDC_SCHEMA)?;
+        let shapes = turtle::parse(shacl::EDC_SHAPES).expect("bundled shapes are valid Turtle");
+
+        let differences = conformance::diff_edc(&schema, &shapes);
+        assert!(differences.contains(&Difference {
+            source: SpecSource::Schema,
+            schema: "EuropeanDigitalCredential".to_string(),
+            field: "expirationDate".to_string(),
+            kind: DifferenceKind::Cardinality,
+            model: Some("1|*, optional".to_string()),
+            spec: Some("1|*, required".to_string()),
+        }));
+        assert!(differences.contains(&Difference {
+            source: SpecSource::Shacl,
+            schema: "Evidence".to_string(),
+            field: "dcType".to_string(),
+            kind: DifferenceKind::Cardinality,
+            model: Some("0..1".to_string()),
+            spec: Some("1".to_string()),
+        }));
+        // The required type tag isn't a difference, the schema never requires `type`.
+        assert!(!differences
+            .iter()
+            .any(|difference| difference.schema == "Person" && difference.field == "type"));
+
+        let person = &mut schema["$defs"]["Person"]["properties"];
+        person.as_object_mut().unwrap().remove("givenName");
+        person["nickname"] = serde_json::json!({ "$ref": "#/$defs/Many!StringType" });
+
+        let person: Vec<_> = conformance::diff_edc(&schema, &shapes)
+            .into_iter()
+            .filter(|difference| difference.source == SpecSource::Schema && difference.schema == "Person")
+            .map(|difference| (difference.field, difference.kind))
+            .collect();
+        assert!(person.contains(&("givenName".to_string(), DifferenceKind::ExtraField)));
+        assert!(person.contains(&("nickname".to_string(), DifferenceKind::MissingField)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_turtle_shapes() {
+        let shapes = shacl::Shapes::from_turtle(
+            r#"
+            @prefix sh: <http://www.w3.org/ns/shacl#> .
+            @prefix ex: <http://example.org/> .
+
+            ex:PersonShape a sh:NodeShape ;
+                sh:targetClass ex:Person ;
+                sh:property [ sh:path ex:name ; sh:minCount 1 ; sh:datatype <http://www.w3.org/2001/XMLSchema#string> ] ,
+                    [ sh:path ( ex:knows ex:name ) ; sh:maxCount 1 ] .
+            "#,
+        )
+        .unwrap();
+
+        let data = turtle::parse(
+            r#"
+            @prefix ex: <http://example.org/> .
+
+            ex:alice a ex:Person ; ex:knows ex:bob, ex:carol .
+            ex:bob ex:name "Bob" .
+            ex:carol ex:name "Carol"@en .
+            "#,
+        )
+        .unwrap();
+
+        let mut components: Vec<String> = shapes
+            .validate(&data)
+            .into_iter()
+            .map(|result| result.source_constraint_component)
+            .collect();
+        components.sort();
+
+        assert_eq!(
+            components,
+            [
+                "http://www.w3.org/ns/shacl#MaxCountConstraintComponent",
+                "http://www.w3.org/ns/shacl#MinCountConstraintComponent"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_skos() -> Result<(), ValidatorError> {
+        use types_elm_v3::vocabulary::VocabularyRegistry;
+
+        let grading = skos::from_turtle(
+            r#"
+            @prefix skos: <http://www.w3.org/2004/02/skos/core#> .
+            @prefix ex: <http://example.org/grading/> .
+
+            ex:scheme a skos:ConceptScheme ; skos:prefLabel "Grading scheme"@en ; skos:hasTopConcept ex:pass .
+            ex:pass a skos:Concept ; skos:notation "P" ; skos:prefLabel "Pass"@en, "Geslaagd"@nl .
+            ex:fail a skos:Concept ; skos:inScheme ex:scheme ; skos:prefLabel "Fail" .
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(grading.len(), 1);
+        assert_eq!(grading[0].id, "http://example.org/grading/scheme");
+        assert_eq!(grading[0].concepts.len(), 2);
+
+        let pass = grading[0].concept_by_notation("p").unwrap();
+        assert_eq!(pass.pref_label("nl"), Some("Geslaagd"));
+        assert_eq!(
+            grading[0]
+                .concept("http://example.org/grading/fail")
+                .unwrap()
+                .pref_label("und"),
+            Some("Fail")
+        );
+
+        let programs = skos::from_json_ld(&serde_json::json!({
+            "@graph": [
+                { "id": "http://example.org/programs", "type": "ConceptScheme" },
+                {
+                    "id": "http://example.org/programs/cs",
+                    "type": "Concept",
+                    "inScheme": { "id": "http://example.org/programs", "type": "ConceptScheme" },
+                    "notation": "CS",
+                    "prefLabel": { "en": "Computer science" }
+                }
+            ]
+        }))
+        .unwrap();
+
+        assert_eq!(programs.len(), 1);
+        assert_eq!(
+            programs[0].concept_by_notation("CS").unwrap().pref_label("en-GB"),
+            Some("Computer science")
+        );
+
+        let mut registry = VocabularyRegistry::new();
+        registry.insert(programs.into_iter().next().unwrap());
+
+        // The bengales diploma has EQF levels, which aren't loaded, and no program concepts.
+        let json = fs::read_to_string(manifest_dir().join("elm-requests/bengales-highschool-diploma.json"))?;
+        let credential: types_elm_v3::EuropassEdcCredential = serde_json::from_str(&json)?;
+        let eqf = "http://data.europa.eu/snb/eqf/25831c2";
+
+        assert!(skos::validate_concepts(&credential, &registry, "http://example.org/programs")?.conforms());
+        let report = skos::validate_concepts(&credential, &registry, eqf)?;
+        assert!(!report.conforms());
+        assert!(report
+            .iter()
+            .all(|violation| violation.source == report::ViolationSource::Vocabulary));
+
+        registry.insert(types_elm_v3::vocabulary::Vocabulary {
+            id: eqf.to_string(),
+            pref_label: Default::default(),
+            concepts: vec![],
+        });
+        let report = skos::validate_concepts(&credential, &registry, eqf)?;
+        assert!(report
+            .iter()
+            .all(|violation| violation.message.contains("isn't a concept of")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rdfc() {
+        // The unique and shared hashes examples of RDFC-1.0.
+        let unique = turtle::parse(
+            r#"
+            <http://example.com/#p> <http://example.com/#q> _:e0 .
+            <http://example.com/#p> <http://example.com/#r> _:e1 .
+            _:e0 <http://example.com/#s> <http://example.com/#u> .
+            _:e1 <http://example.com/#t> <http://example.com/#u> .
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            rdfc::canonicalize(&unique).unwrap(),
+            "<http://example.com/#p> <http://example.com/#q> _:c14n0 .\n\
+             <http://example.com/#p> <http://example.com/#r> _:c14n1 .\n\
+             _:c14n0 <http://example.com/#s> <http://example.com/#u> .\n\
+             _:c14n1 <http://example.com/#t> <http://example.com/#u> .\n"
+        );
+
+        let shared = |statements: &str| rdfc::canonicalize(&turtle::parse(statements).unwrap()).unwrap();
+        let expected = "<http://example.com/#p> <http://example.com/#q> _:c14n2 .\n\
+                        <http://example.com/#p> <http://example.com/#q> _:c14n3 .\n\
+                        _:c14n0 <http://example.com/#r> _:c14n1 .\n\
+                        _:c14n2 <http://example.com/#p> _:c14n1 .\n\
+                        _:c14n3 <http://example.com/#p> _:c14n0 .\n";
+
+        assert_eq!(
+            shared(
+                r#"
+                @prefix : <http://example.com/#> .
+                :p :q _:e0, _:e1 .
+                _:e0 :p _:e2 .
+                _:e1 :p _:e3 .
+                _:e2 :r _:e3 .
+                "#
+            ),
+            expected
+        );
+        // Other blank node labels and another statement order give the same N-Quads.
+        assert_eq!(
+            shared(
+                r#"
+                @prefix : <http://example.com/#> .
+                _:x :r _:y .
+                _:w :p _:y .
+                :p :q _:v, _:w .
+                _:v :p _:x .
+                "#
+            ),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_urdna2015_proof() {
+        use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header};
+        use types_elm_v3::proof::{self, ProofError, ProofOptions};
+
+        let canonicalizer = rdfc::Rdfc::new();
+        let key = EncodingKey::from_secret(b"secret");
+        let resolver = |header: &Header| {
+            (header.kid.as_deref() == Some("did:example:issuer#key-1"))
+                .then(|| (Algorithm::HS256, DecodingKey::from_secret(b"secret")))
+        };
+        let options = ProofOptions::new("did:example:issuer#key-1", Algorithm::HS256);
+
+        let json = fs::read_to_string(manifest_dir().join("elm-requests/microcredential.json")).unwrap();
+        let credential: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let signed = proof::sign_value(&credential, &key, &options, &canonicalizer).unwrap();
+        proof::verify_value(&signed, &resolver, &canonicalizer).unwrap();
+
+        // The proof terms are those of the security vocabulary, not of the ELM one.
+        let mut proof_config = signed["proof"].clone();
+        proof_config.as_object_mut().unwrap().remove("jws");
+        proof_config["@context"] = signed["@context"].clone();
+        let nquads = canonicalizer.canonicalize(&proof_config).unwrap();
+        for term in [
+            "<http://purl.org/dc/terms/created>",
+            "<https://w3id.org/security#proofPurpose> <https://w3id.org/security#assertionMethod>",
+            "<https://w3id.org/security#verificationMethod>",
+            "<https://w3id.org/security#JsonWebSignature2020>",
+        ] {
+            assert!(nquads.contains(term), "{term} not in {nquads}");
+        }
+        assert!(!nquads.contains("http://data.europa.eu/snb/model/elm/"));
+
+        let mut tampered = signed.clone();
+        tampered["validFrom"] = "2020-01-01T00:00:00+02:00".into();
+        assert!(matches!(
+            proof::verify_value(&tampered, &resolver, &canonicalizer),
+            Err(ProofError::InvalidSignature)
+        ));
+
+        let mut unknown = credential.clone();
+        unknown["@context"] = serde_json::json!(["https://example.org/unknown-context"]);
+        assert!(matches!(
+            proof::sign_value(&unknown, &key, &options, &canonicalizer),
+            Err(ProofError::UnsupportedCanonicalization(_))
+        ));
+    }
 }
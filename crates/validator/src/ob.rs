@@ -0,0 +1,88 @@
+//! Validation of Open Badges 3.0 credentials.
+
+use std::{fs, path::Path};
+
+use log::{error, info};
+use types_ob_v3::prelude::{AchievementCredential, EndorsementCredential};
+
+use crate::{
+    error::ValidatorError,
+    report::{self, ValidationReport, Violation},
+    schema,
+};
+
+/// An OBv3 credential, which of the two is decided by the `type` of the document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ObCredential {
+    Achievement(Box<AchievementCredential>),
+    Endorsement(Box<EndorsementCredential>),
+}
+
+#[derive(Debug)]
+pub struct ObValidateRequest {
+    /// Whether the raw JSON is valid according to the OBv3 JSON Schema.
+    pub valid_schema: bool,
+    pub rust_object: Result<ObCredential, ValidatorError>,
+    /// Every JSON Schema violation and deserialization error, located by JSON path.
+    pub report: ValidationReport,
+}
+
+impl ObValidateRequest {
+    pub fn new(json_file: impl AsRef<Path>) -> Result<Self, ValidatorError> {
+        Self::from_json(&fs::read_to_string(json_file)?)
+    }
+
+    pub fn from_json(json: &str) -> Result<Self, ValidatorError> {
+        Self::from_value(&crate::parse_str(json)?)
+    }
+
+    pub fn from_value(document: &serde_json::Value) -> Result<Self, ValidatorError> {
+        let endorsement = is_endorsement(document);
+
+        let schema = if endorsement {
+            schema::ob_endorsement_schema()
+        } else {
+            schema::ob_achievement_schema()
+        };
+
+        let mut report = schema.report(document);
+        let valid_schema = report.conforms();
+
+        let deserializer = serde_stacker::Deserializer::new(document);
+
+        let credential = if endorsement {
+            serde_path_to_error::deserialize(deserializer).map(|c| ObCredential::Endorsement(Box::new(c)))
+        } else {
+            serde_path_to_error::deserialize(deserializer).map(|c| ObCredential::Achievement(Box::new(c)))
+        };
+
+        let rust_object = credential.map_err(|err| {
+            report.push(Violation::from_deserialization(&err));
+
+            ValidatorError::Deserialization {
+                path: report::json_path(err.path()),
+                source: err.into_inner(),
+            }
+        });
+
+        if report.conforms() {
+            info!("Data conforms to the OBv3 schema.");
+        } else {
+            error!("Validation report:\n{report}");
+        }
+
+        Ok(Self {
+            valid_schema,
+            rust_object,
+            report,
+        })
+    }
+}
+
+fn is_endorsement(document: &serde_json::Value) -> bool {
+    match document.get("type") {
+        Some(serde_json::Value::String(type_)) => type_ == "EndorsementCredential",
+        Some(serde_json::Value::Array(types)) => types.iter().any(|type_| type_ == "EndorsementCredential"),
+        _ => false,
+    }
+}
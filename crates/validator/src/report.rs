@@ -0,0 +1,230 @@
+use std::fmt;
+
+use serde::Serialize;
+
+use crate::{
+    jsonld::JsonPaths,
+    rdf::Term,
+    shacl::{PropertyPath, Severity, ValidationResult},
+};
+
+/// Which validation step reported a violation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ViolationSource {
+    Shacl,
+    Schema,
+    Deserialization,
+    /// A concept that isn't in its concept scheme, see [`crate::skos::validate_concepts`].
+    Vocabulary,
+}
+
+impl fmt::Display for ViolationSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ViolationSource::Shacl => write!(f, "SHACL"),
+            ViolationSource::Schema => write!(f, "JSON Schema"),
+            ViolationSource::Deserialization => write!(f, "Deserialization"),
+            ViolationSource::Vocabulary => write!(f, "Vocabulary"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Violation {
+    pub source: ViolationSource,
+    /// Location of the offending field in the credential, e.g. `$.credentialSubject.hasClaim[0].title`.
+    pub json_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub focus_node: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_shape: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub constraint: Option<String>,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl Violation {
+    pub fn from_shacl(result: &ValidationResult, paths: &JsonPaths) -> Self {
+        let predicate = match &result.result_path {
+            Some(PropertyPath::Predicate(predicate)) => Some(predicate.as_str()),
+            _ => None,
+        };
+
+        let focus_path = paths.node(&result.focus_node).unwrap_or("$");
+
+        let json_path = match (predicate, &result.value) {
+            (Some(predicate), Some(value)) => paths.value(&result.focus_node, predicate, value),
+            _ => None,
+        }
+        .or_else(|| predicate.and_then(|predicate| paths.property(&result.focus_node, predicate)))
+        .map(str::to_string)
+        .unwrap_or_else(|| match predicate {
+            // A missing property has no location yet, point at where it is expected.
+            Some(predicate) => format!("{focus_path}.{}", local_name(predicate)),
+            None => focus_path.to_string(),
+        });
+
+        Self {
+            source: ViolationSource::Shacl,
+            json_path,
+            focus_node: Some(node_id(&result.focus_node)),
+            result_path: result.result_path.as_ref().map(ToString::to_string),
+            value: result.value.as_ref().map(ToString::to_string),
+            source_shape: Some(node_id(&result.source_shape)),
+            constraint: Some(result.source_constraint_component.clone()),
+            severity: result.severity,
+            message: result.message.clone(),
+        }
+    }
+
+    pub fn from_schema(err: &jsonschema::ValidationError) -> Self {
+        let mut json_path = "$".to_string();
+
+        for segment in &err.instance_path {
+            match segment {
+                jsonschema::paths::LocationSegment::Index(index) => json_path.push_str(&format!("[{index}]")),
+                jsonschema::paths::LocationSegment::Property(property) => {
+                    json_path.push_str(&format!(".{}", property.replace("~1", "/").replace("~0", "~")))
+                }
+            }
+        }
+
+        Self {
+            source: ViolationSource::Schema,
+            json_path,
+            focus_node: None,
+            result_path: None,
+            value: None,
+            source_shape: None,
+            // The schema path ends with the failing keyword, e.g. `/allOf/0/properties/id/format`.
+            constraint: Some(err.schema_path.to_string()),
+            severity: Severity::Violation,
+            message: err.to_string(),
+        }
+    }
+
+    pub fn from_deserialization(err: &serde_path_to_error::Error<serde_json::Error>) -> Self {
+        Self {
+            source: ViolationSource::Deserialization,
+            json_path: json_path(err.path()),
+            focus_node: None,
+            result_path: None,
+            value: None,
+            source_shape: None,
+            constraint: None,
+            severity: Severity::Violation,
+            message: err.inner().to_string(),
+        }
+    }
+
+    /// The input isn't JSON at all, the message has the line and column.
+    pub fn from_parse(err: &serde_json::Error) -> Self {
+        Self {
+            source: ViolationSource::Deserialization,
+            json_path: "$".to_string(),
+            focus_node: None,
+            result_path: None,
+            value: None,
+            source_shape: None,
+            constraint: None,
+            severity: Severity::Violation,
+            message: err.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}] {} at {}", self.source, self.severity, self.json_path)?;
+
+        if let Some(constraint) = &self.constraint {
+            let constraint = constraint.rsplit('#').next().unwrap_or(constraint);
+            write!(f, " ({constraint})")?;
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationReport {
+    pub violations: Vec<Violation>,
+}
+
+impl ValidationReport {
+    pub fn conforms(&self) -> bool {
+        self.violations.is_empty()
+    }
+
+    pub fn push(&mut self, violation: Violation) {
+        self.violations.push(violation);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Violation> {
+        self.violations.iter()
+    }
+
+    pub fn by_source(&self, source: ViolationSource) -> impl Iterator<Item = &Violation> {
+        self.violations
+            .iter()
+            .filter(move |violation| violation.source == source)
+    }
+
+    /// All violations at `json_path` or inside of it.
+    pub fn at<'a>(&'a self, json_path: &'a str) -> impl Iterator<Item = &'a Violation> {
+        self.violations.iter().filter(move |violation| {
+            violation
+                .json_path
+                .strip_prefix(json_path)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('.') || rest.starts_with('['))
+        })
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.conforms() {
+            return write!(f, "Conforms");
+        }
+
+        for violation in &self.violations {
+            writeln!(f, "{violation}")?;
+        }
+
+        Ok(())
+    }
+}
+
+fn node_id(term: &Term) -> String {
+    match term {
+        Term::Iri(iri) => iri.clone(),
+        term => term.to_string(),
+    }
+}
+
+fn local_name(iri: &str) -> &str {
+    iri.rsplit(['/', '#']).next().unwrap_or(iri)
+}
+
+pub(crate) fn json_path(path: &serde_path_to_error::Path) -> String {
+    let mut json_path = "$".to_string();
+
+    for segment in path.iter() {
+        match segment {
+            serde_path_to_error::Segment::Seq { index } => json_path.push_str(&format!("[{index}]")),
+            serde_path_to_error::Segment::Map { key } => json_path.push_str(&format!(".{key}")),
+            serde_path_to_error::Segment::Enum { variant } => json_path.push_str(&format!(".{variant}")),
+            serde_path_to_error::Segment::Unknown => {}
+        }
+    }
+
+    json_path
+}
@@ -0,0 +1,97 @@
+//! JSON Schema validation against the EBSI "Europass EDC credential" schema in `types-elm-v3/schema.json` and the
+//! 1EdTech Open Badges 3.0 schemas in `types-ob-v3/schemas`, with the local rules of `types-ob-v3/schemas/local.json`
+//! on top. `types-ob-v3/fetch-schemas.sh` downloads the 1EdTech schemas over the bundled stand-ins.
+
+use std::sync::OnceLock;
+
+use jsonschema::Validator;
+use serde_json::{json, Value};
+
+use crate::report::{ValidationReport, Violation};
+
+/// The EBSI "Europass EDC credential" schema, `types-elm-v3/schema.json`.
+pub const EDC_SCHEMA: &str = include_str!("../../types-elm-v3/schema.json");
+const OB_ACHIEVEMENT_SCHEMA: &str = include_str!("../../types-ob-v3/schemas/ob_v3p0_achievementcredential_schema.json");
+const OB_ENDORSEMENT_SCHEMA: &str = include_str!("../../types-ob-v3/schemas/ob_v3p0_endorsementcredential_schema.json");
+const OB_LOCAL_SCHEMA: &str = include_str!("../../types-ob-v3/schemas/local.json");
+
+/// A 1EdTech schema and the local rules for the same credential, a credential has to pass both.
+pub struct LayeredSchema {
+    pub official: Validator,
+    pub local: Validator,
+}
+
+impl LayeredSchema {
+    /// `definition` is the entry point of the local rules in `local.json`.
+    fn new(official: &str, definition: &str) -> Self {
+        let official = serde_json::from_str(official).expect("bundled schema is valid JSON");
+        let local: Value = serde_json::from_str(OB_LOCAL_SCHEMA).expect("bundled schema is valid JSON");
+
+        Self {
+            official: ob_validator(official),
+            local: ob_validator(json!({
+                "$schema": local["$schema"],
+                "$ref": format!("#/$defs/{definition}"),
+                "$defs": local["$defs"],
+            })),
+        }
+    }
+
+    /// Violations of the 1EdTech schema, then of the local rules.
+    pub fn report(&self, document: &Value) -> ValidationReport {
+        let mut report = report(&self.official, document);
+        report.violations.extend(report_violations(&self.local, document));
+
+        report
+    }
+}
+
+/// The compiled EDC credential schema, formats like `date-time` and `uri` are asserted.
+pub fn edc_schema() -> &'static Validator {
+    static SCHEMA: OnceLock<Validator> = OnceLock::new();
+
+    SCHEMA.get_or_init(|| {
+        let schema = serde_json::from_str(EDC_SCHEMA).expect("bundled schema is valid JSON");
+
+        jsonschema::draft202012::options()
+            .should_validate_formats(true)
+            .build(&schema)
+            .expect("bundled schema is a valid JSON Schema")
+    })
+}
+
+/// The compiled OBv3 `AchievementCredential` (and `OpenBadgeCredential`) schemas.
+pub fn ob_achievement_schema() -> &'static LayeredSchema {
+    static SCHEMA: OnceLock<LayeredSchema> = OnceLock::new();
+
+    SCHEMA.get_or_init(|| LayeredSchema::new(OB_ACHIEVEMENT_SCHEMA, "AchievementCredential"))
+}
+
+/// The compiled OBv3 `EndorsementCredential` schemas.
+pub fn ob_endorsement_schema() -> &'static LayeredSchema {
+    static SCHEMA: OnceLock<LayeredSchema> = OnceLock::new();
+
+    SCHEMA.get_or_init(|| LayeredSchema::new(OB_ENDORSEMENT_SCHEMA, "EndorsementCredential"))
+}
+
+fn ob_validator(schema: Value) -> Validator {
+    jsonschema::draft201909::options()
+        .should_validate_formats(true)
+        .build(&schema)
+        .expect("bundled schema is a valid JSON Schema")
+}
+
+/// Validates the raw JSON of a credential, every failing keyword becomes a violation.
+pub fn validate_schema(document: &Value) -> ValidationReport {
+    report(edc_schema(), document)
+}
+
+fn report(schema: &Validator, document: &Value) -> ValidationReport {
+    ValidationReport {
+        violations: report_violations(schema, document).collect(),
+    }
+}
+
+fn report_violations<'a>(schema: &'a Validator, document: &'a Value) -> impl Iterator<Item = Violation> + 'a {
+    schema.iter_errors(document).map(|err| Violation::from_schema(&err))
+}
@@ -0,0 +1,733 @@
+//! A native SHACL Core validator.
+//!
+//! Shapes are read from a Turtle document and evaluated against an RDF [`Graph`], usually produced by
+//! [`crate::jsonld::to_rdf`]. SPARQL-based constraints and `sh:closed` are not supported.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    sync::OnceLock,
+};
+
+use regex::Regex;
+use serde::Serialize;
+
+use crate::{
+    jsonld::JsonLdError,
+    rdf::{Graph, Term, RDF_LANG_STRING},
+    turtle,
+};
+
+/// The European Digital Credentials shapes, `shacl-validator/shape.ttl`.
+pub const EDC_SHAPES: &str = include_str!("../shacl-validator/shape.ttl");
+
+pub(crate) const SH: &str = "http://www.w3.org/ns/shacl#";
+const RDFS_CLASS: &str = "http://www.w3.org/2000/01/rdf-schema#Class";
+
+/// Nested shapes (`sh:node`, `sh:not`, ...) deeper than this are considered conforming, which keeps recursive
+/// shape graphs from overflowing the stack.
+const MAX_DEPTH: usize = 64;
+
+#[derive(Debug)]
+pub enum ShaclError {
+    Turtle(turtle::ParseError),
+    InvalidShape {
+        shape: Term,
+        message: String,
+    },
+    /// The credential couldn't be converted into an RDF graph.
+    JsonLd(JsonLdError),
+}
+
+impl fmt::Display for ShaclError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShaclError::Turtle(err) => write!(f, "Invalid shapes graph: {err}"),
+            ShaclError::InvalidShape { shape, message } => write!(f, "Invalid shape {shape}: {message}"),
+            ShaclError::JsonLd(err) => write!(f, "Invalid JSON-LD: {err}"),
+        }
+    }
+}
+
+impl std::error::Error for ShaclError {}
+
+impl From<JsonLdError> for ShaclError {
+    fn from(err: JsonLdError) -> Self {
+        ShaclError::JsonLd(err)
+    }
+}
+
+impl From<turtle::ParseError> for ShaclError {
+    fn from(err: turtle::ParseError) -> Self {
+        ShaclError::Turtle(err)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum Severity {
+    Info,
+    Warning,
+    Violation,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "Info"),
+            Severity::Warning => write!(f, "Warning"),
+            Severity::Violation => write!(f, "Violation"),
+        }
+    }
+}
+
+/// A single `sh:ValidationResult`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationResult {
+    pub focus_node: Term,
+    pub result_path: Option<PropertyPath>,
+    pub value: Option<Term>,
+    pub source_shape: Term,
+    /// The IRI of the constraint component, e.g. `http://www.w3.org/ns/shacl#MinCountConstraintComponent`.
+    pub source_constraint_component: String,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let component = self
+            .source_constraint_component
+            .strip_prefix(SH)
+            .unwrap_or(&self.source_constraint_component);
+
+        write!(f, "{} ({component}) on {}", self.severity, self.focus_node)?;
+
+        if let Some(path) = &self.result_path {
+            write!(f, " -> {path}")?;
+        }
+
+        if let Some(value) = &self.value {
+            write!(f, ", value {value}")?;
+        }
+
+        write!(f, ": {}", self.message)
+    }
+}
+
+/// A SHACL property path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyPath {
+    Predicate(String),
+    Inverse(Box<PropertyPath>),
+    Sequence(Vec<PropertyPath>),
+    Alternative(Vec<PropertyPath>),
+    ZeroOrMore(Box<PropertyPath>),
+    OneOrMore(Box<PropertyPath>),
+    ZeroOrOne(Box<PropertyPath>),
+}
+
+impl PropertyPath {
+    fn values(&self, data: &Graph, focus: &Term) -> BTreeSet<Term> {
+        match self {
+            PropertyPath::Predicate(predicate) => data.objects(focus, predicate).cloned().collect(),
+            PropertyPath::Inverse(path) => match path.as_ref() {
+                PropertyPath::Predicate(predicate) => data.subjects(predicate, focus).cloned().collect(),
+                // Inverse complex paths walk the whole graph, these don't occur in practice.
+                path => data
+                    .triples()
+                    .map(|(subject, _, _)| subject)
+                    .filter(|node| path.values(data, node).contains(focus))
+                    .cloned()
+                    .collect(),
+            },
+            PropertyPath::Sequence(paths) => paths.iter().fold(BTreeSet::from([focus.clone()]), |nodes, path| {
+                nodes.iter().flat_map(|node| path.values(data, node)).collect()
+            }),
+            PropertyPath::Alternative(paths) => paths.iter().flat_map(|path| path.values(data, focus)).collect(),
+            PropertyPath::ZeroOrMore(path) => Self::closure(path, data, focus, true),
+            PropertyPath::OneOrMore(path) => Self::closure(path, data, focus, false),
+            PropertyPath::ZeroOrOne(path) => {
+                let mut values = path.values(data, focus);
+                values.insert(focus.clone());
+                values
+            }
+        }
+    }
+
+    fn closure(path: &PropertyPath, data: &Graph, focus: &Term, include_focus: bool) -> BTreeSet<Term> {
+        let mut values = BTreeSet::new();
+        let mut stack: Vec<Term> = path.values(data, focus).into_iter().collect();
+
+        while let Some(node) = stack.pop() {
+            if values.insert(node.clone()) {
+                stack.extend(path.values(data, &node));
+            }
+        }
+
+        if include_focus {
+            values.insert(focus.clone());
+        }
+
+        values
+    }
+}
+
+impl fmt::Display for PropertyPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let join = |f: &mut fmt::Formatter<'_>, paths: &[PropertyPath], separator: &str| {
+            write!(f, "(")?;
+            for (i, path) in paths.iter().enumerate() {
+                if i > 0 {
+                    write!(f, "{separator}")?;
+                }
+                write!(f, "{path}")?;
+            }
+            write!(f, ")")
+        };
+
+        match self {
+            PropertyPath::Predicate(predicate) => write!(f, "<{predicate}>"),
+            PropertyPath::Inverse(path) => write!(f, "^{path}"),
+            PropertyPath::Sequence(paths) => join(f, paths, "/"),
+            PropertyPath::Alternative(paths) => join(f, paths, "|"),
+            PropertyPath::ZeroOrMore(path) => write!(f, "{path}*"),
+            PropertyPath::OneOrMore(path) => write!(f, "{path}+"),
+            PropertyPath::ZeroOrOne(path) => write!(f, "{path}?"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NodeKind {
+    BlankNode,
+    Iri,
+    Literal,
+    BlankNodeOrIri,
+    BlankNodeOrLiteral,
+    IriOrLiteral,
+}
+
+impl NodeKind {
+    fn matches(self, node: &Term) -> bool {
+        match self {
+            NodeKind::BlankNode => node.is_blank_node(),
+            NodeKind::Iri => node.is_iri(),
+            NodeKind::Literal => node.is_literal(),
+            NodeKind::BlankNodeOrIri => !node.is_literal(),
+            NodeKind::BlankNodeOrLiteral => !node.is_iri(),
+            NodeKind::IriOrLiteral => !node.is_blank_node(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Class(String),
+    Node(Term),
+    SubjectsOf(String),
+    ObjectsOf(String),
+}
+
+#[derive(Debug, Clone)]
+enum Constraint {
+    Class(String),
+    Datatype(String),
+    NodeKind(NodeKind),
+    MinCount(usize),
+    MaxCount(usize),
+    MinLength(usize),
+    MaxLength(usize),
+    Pattern(Regex),
+    LanguageIn(Vec<String>),
+    UniqueLang,
+    In(Vec<Term>),
+    HasValue(Term),
+    Node(Term),
+    Not(Term),
+    And(Vec<Term>),
+    Or(Vec<Term>),
+    Xone(Vec<Term>),
+}
+
+impl Constraint {
+    fn component(&self) -> &'static str {
+        match self {
+            Constraint::Class(_) => "ClassConstraintComponent",
+            Constraint::Datatype(_) => "DatatypeConstraintComponent",
+            Constraint::NodeKind(_) => "NodeKindConstraintComponent",
+            Constraint::MinCount(_) => "MinCountConstraintComponent",
+            Constraint::MaxCount(_) => "MaxCountConstraintComponent",
+            Constraint::MinLength(_) => "MinLengthConstraintComponent",
+            Constraint::MaxLength(_) => "MaxLengthConstraintComponent",
+            Constraint::Pattern(_) => "PatternConstraintComponent",
+            Constraint::LanguageIn(_) => "LanguageInConstraintComponent",
+            Constraint::UniqueLang => "UniqueLangConstraintComponent",
+            Constraint::In(_) => "InConstraintComponent",
+            Constraint::HasValue(_) => "HasValueConstraintComponent",
+            Constraint::Node(_) => "NodeConstraintComponent",
+            Constraint::Not(_) => "NotConstraintComponent",
+            Constraint::And(_) => "AndConstraintComponent",
+            Constraint::Or(_) => "OrConstraintComponent",
+            Constraint::Xone(_) => "XoneConstraintComponent",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Shape {
+    id: Term,
+    path: Option<PropertyPath>,
+    targets: Vec<Target>,
+    constraints: Vec<Constraint>,
+    properties: Vec<Term>,
+    severity: Severity,
+    message: Option<String>,
+    deactivated: bool,
+}
+
+/// A parsed shapes graph.
+#[derive(Debug, Clone)]
+pub struct Shapes {
+    shapes: BTreeMap<Term, Shape>,
+}
+
+impl Shapes {
+    /// The European Digital Credentials shapes, `shacl-validator/shape.ttl`.
+    pub fn edc() -> &'static Shapes {
+        static SHAPES: OnceLock<Shapes> = OnceLock::new();
+
+        SHAPES.get_or_init(|| Shapes::from_turtle(EDC_SHAPES).expect("bundled shapes are valid"))
+    }
+
+    pub fn from_turtle(turtle: &str) -> Result<Self, ShaclError> {
+        Self::from_graph(&turtle::parse(turtle)?)
+    }
+
+    pub fn from_graph(graph: &Graph) -> Result<Self, ShaclError> {
+        let mut ids: BTreeSet<Term> = BTreeSet::new();
+
+        for class in ["NodeShape", "PropertyShape"] {
+            ids.extend(graph.instances_of(&format!("{SH}{class}")).into_iter().cloned());
+        }
+
+        for predicate in [
+            "targetClass",
+            "targetNode",
+            "targetSubjectsOf",
+            "targetObjectsOf",
+            "path",
+        ] {
+            ids.extend(graph.subjects_with(&format!("{SH}{predicate}")).cloned());
+        }
+
+        for predicate in ["property", "node", "not"] {
+            ids.extend(graph.objects_of(&format!("{SH}{predicate}")).cloned());
+        }
+
+        for predicate in ["and", "or", "xone"] {
+            for list in graph.objects_of(&format!("{SH}{predicate}")) {
+                ids.extend(graph.list(list).unwrap_or_default());
+            }
+        }
+
+        let shapes = ids
+            .into_iter()
+            .map(|id| Ok((id.clone(), parse_shape(graph, id)?)))
+            .collect::<Result<_, ShaclError>>()?;
+
+        Ok(Self { shapes })
+    }
+
+    /// Validates `data` against all shapes, an empty result means the data conforms.
+    pub fn validate(&self, data: &Graph) -> Vec<ValidationResult> {
+        let mut results = vec![];
+
+        for shape in self.shapes.values().filter(|shape| !shape.deactivated) {
+            let mut focus_nodes = BTreeSet::new();
+
+            for target in &shape.targets {
+                match target {
+                    Target::Class(class) => focus_nodes.extend(data.instances_of(class).into_iter().cloned()),
+                    Target::Node(node) => {
+                        focus_nodes.insert(node.clone());
+                    }
+                    Target::SubjectsOf(predicate) => focus_nodes.extend(data.subjects_with(predicate).cloned()),
+                    Target::ObjectsOf(predicate) => focus_nodes.extend(data.objects_of(predicate).cloned()),
+                }
+            }
+
+            for focus in &focus_nodes {
+                self.validate_shape(data, shape, focus, 0, &mut results);
+            }
+        }
+
+        results
+    }
+
+    fn validate_shape(
+        &self,
+        data: &Graph,
+        shape: &Shape,
+        focus: &Term,
+        depth: usize,
+        results: &mut Vec<ValidationResult>,
+    ) {
+        if shape.deactivated || depth > MAX_DEPTH {
+            return;
+        }
+
+        let values: Vec<Term> = match &shape.path {
+            Some(path) => path.values(data, focus).into_iter().collect(),
+            None => vec![focus.clone()],
+        };
+
+        for constraint in &shape.constraints {
+            self.check(data, shape, constraint, focus, &values, depth, results);
+        }
+
+        for property in &shape.properties {
+            if let Some(property) = self.shapes.get(property) {
+                self.validate_shape(data, property, focus, depth + 1, results);
+            }
+        }
+    }
+
+    fn conforms(&self, data: &Graph, shape: &Term, node: &Term, depth: usize) -> bool {
+        let Some(shape) = self.shapes.get(shape) else {
+            return true;
+        };
+
+        let mut results = vec![];
+        self.validate_shape(data, shape, node, depth + 1, &mut results);
+
+        results.is_empty()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn check(
+        &self,
+        data: &Graph,
+        shape: &Shape,
+        constraint: &Constraint,
+        focus: &Term,
+        values: &[Term],
+        depth: usize,
+        results: &mut Vec<ValidationResult>,
+    ) {
+        let mut report = |value: Option<&Term>, message: String| {
+            results.push(ValidationResult {
+                focus_node: focus.clone(),
+                result_path: shape.path.clone(),
+                value: value.cloned(),
+                source_shape: shape.id.clone(),
+                source_constraint_component: format!("{SH}{}", constraint.component()),
+                severity: shape.severity,
+                message: shape.message.clone().unwrap_or(message),
+            })
+        };
+
+        match constraint {
+            Constraint::MinCount(min) if values.len() < *min => report(None, format!("Less than {min} values")),
+            Constraint::MaxCount(max) if values.len() > *max => report(None, format!("More than {max} values")),
+            Constraint::HasValue(expected) if !values.contains(expected) => {
+                report(None, format!("Missing expected value {expected}"))
+            }
+            Constraint::UniqueLang => {
+                let mut seen = BTreeSet::new();
+                let mut duplicates = BTreeSet::new();
+
+                for language in values.iter().filter_map(|v| v.as_literal()?.language.as_deref()) {
+                    if !seen.insert(language) {
+                        duplicates.insert(language);
+                    }
+                }
+
+                for language in duplicates {
+                    report(None, format!("Language {language:?} is used more than once"));
+                }
+            }
+            Constraint::MinCount(_) | Constraint::MaxCount(_) | Constraint::HasValue(_) => {}
+            _ => {
+                for value in values {
+                    if let Some(message) = self.check_value(data, constraint, value, depth) {
+                        report(Some(value), message);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Checks a single value node, returns a message if it violates the constraint.
+    fn check_value(&self, data: &Graph, constraint: &Constraint, value: &Term, depth: usize) -> Option<String> {
+        let valid = match constraint {
+            Constraint::Class(class) => data.is_instance_of(value, class),
+            Constraint::Datatype(datatype) => value.as_literal().is_some_and(|literal| {
+                &literal.datatype == datatype && (literal.language.is_none() || datatype == RDF_LANG_STRING)
+            }),
+            Constraint::NodeKind(kind) => kind.matches(value),
+            Constraint::MinLength(min) => lexical_form(value).is_some_and(|text| text.chars().count() >= *min),
+            Constraint::MaxLength(max) => lexical_form(value).is_some_and(|text| text.chars().count() <= *max),
+            Constraint::Pattern(pattern) => lexical_form(value).is_some_and(|text| pattern.is_match(text)),
+            Constraint::LanguageIn(languages) => value
+                .as_literal()
+                .and_then(|literal| literal.language.as_deref())
+                .is_some_and(|language| languages.iter().any(|range| language_matches(language, range))),
+            Constraint::In(allowed) => allowed.contains(value),
+            Constraint::Node(shape) => self.conforms(data, shape, value, depth),
+            Constraint::Not(shape) => !self.conforms(data, shape, value, depth),
+            Constraint::And(shapes) => shapes.iter().all(|shape| self.conforms(data, shape, value, depth)),
+            Constraint::Or(shapes) => shapes.iter().any(|shape| self.conforms(data, shape, value, depth)),
+            Constraint::Xone(shapes) => {
+                shapes
+                    .iter()
+                    .filter(|shape| self.conforms(data, shape, value, depth))
+                    .count()
+                    == 1
+            }
+            Constraint::MinCount(_) | Constraint::MaxCount(_) | Constraint::HasValue(_) | Constraint::UniqueLang => {
+                true
+            }
+        };
+
+        if valid {
+            return None;
+        }
+
+        Some(match constraint {
+            Constraint::Class(class) => format!("Value is not an instance of <{class}>"),
+            Constraint::Datatype(datatype) => format!("Value is not a literal of datatype <{datatype}>"),
+            Constraint::NodeKind(kind) => format!("Value is not of node kind {kind:?}"),
+            Constraint::MinLength(min) => format!("Value is shorter than {min} characters"),
+            Constraint::MaxLength(max) => format!("Value is longer than {max} characters"),
+            Constraint::Pattern(pattern) => format!("Value does not match the pattern {:?}", pattern.as_str()),
+            Constraint::LanguageIn(languages) => format!("Value language is not one of {languages:?}"),
+            Constraint::In(_) => "Value is not one of the allowed values".to_string(),
+            Constraint::Node(shape) => format!("Value does not conform to shape {shape}"),
+            Constraint::Not(shape) => format!("Value conforms to shape {shape}"),
+            Constraint::And(_) => "Value does not conform to all shapes".to_string(),
+            Constraint::Or(_) => "Value does not conform to any shape".to_string(),
+            Constraint::Xone(_) => "Value does not conform to exactly one shape".to_string(),
+            Constraint::MinCount(_) | Constraint::MaxCount(_) | Constraint::HasValue(_) | Constraint::UniqueLang => {
+                unreachable!("checked on the whole value set")
+            }
+        })
+    }
+}
+
+fn parse_shape(graph: &Graph, id: Term) -> Result<Shape, ShaclError> {
+    let sh = |name: &str| format!("{SH}{name}");
+    let invalid = |message: String| ShaclError::InvalidShape {
+        shape: id.clone(),
+        message,
+    };
+
+    let iris = |predicate: &str| -> Result<Vec<String>, ShaclError> {
+        graph
+            .objects(&id, &sh(predicate))
+            .map(|object| {
+                object
+                    .as_iri()
+                    .map(str::to_string)
+                    .ok_or_else(|| invalid(format!("sh:{predicate} must be an IRI")))
+            })
+            .collect()
+    };
+
+    let list = |head: &Term, predicate: &str| {
+        graph
+            .list(head)
+            .ok_or_else(|| invalid(format!("sh:{predicate} must be a well-formed list")))
+    };
+
+    let integer = |object: &Term, predicate: &str| {
+        object
+            .as_literal()
+            .and_then(|literal| literal.value.parse::<usize>().ok())
+            .ok_or_else(|| invalid(format!("sh:{predicate} must be a non-negative integer")))
+    };
+
+    let mut targets = vec![];
+    for class in iris("targetClass")? {
+        targets.push(Target::Class(class));
+    }
+    for node in graph.objects(&id, &sh("targetNode")) {
+        targets.push(Target::Node(node.clone()));
+    }
+    for predicate in iris("targetSubjectsOf")? {
+        targets.push(Target::SubjectsOf(predicate));
+    }
+    for predicate in iris("targetObjectsOf")? {
+        targets.push(Target::ObjectsOf(predicate));
+    }
+    if graph.is_instance_of(&id, RDFS_CLASS) {
+        if let Some(class) = id.as_iri() {
+            targets.push(Target::Class(class.to_string()));
+        }
+    }
+
+    let path = match graph.object(&id, &sh("path")) {
+        Some(path) => Some(parse_path(graph, path).ok_or_else(|| invalid("Unsupported property path".to_string()))?),
+        None => None,
+    };
+
+    let mut constraints = vec![];
+
+    for class in iris("class")? {
+        constraints.push(Constraint::Class(class));
+    }
+    for datatype in iris("datatype")? {
+        constraints.push(Constraint::Datatype(datatype));
+    }
+    for kind in iris("nodeKind")? {
+        let kind = match kind.strip_prefix(SH) {
+            Some("BlankNode") => NodeKind::BlankNode,
+            Some("IRI") => NodeKind::Iri,
+            Some("Literal") => NodeKind::Literal,
+            Some("BlankNodeOrIRI") => NodeKind::BlankNodeOrIri,
+            Some("BlankNodeOrLiteral") => NodeKind::BlankNodeOrLiteral,
+            Some("IRIOrLiteral") => NodeKind::IriOrLiteral,
+            _ => return Err(invalid(format!("Unknown node kind <{kind}>"))),
+        };
+        constraints.push(Constraint::NodeKind(kind));
+    }
+    for (predicate, constraint) in [
+        ("minCount", Constraint::MinCount as fn(usize) -> Constraint),
+        ("maxCount", Constraint::MaxCount),
+        ("minLength", Constraint::MinLength),
+        ("maxLength", Constraint::MaxLength),
+    ] {
+        for object in graph.objects(&id, &sh(predicate)) {
+            constraints.push(constraint(integer(object, predicate)?));
+        }
+    }
+    for pattern in graph.objects(&id, &sh("pattern")) {
+        let pattern = lexical_form(pattern).ok_or_else(|| invalid("sh:pattern must be a literal".to_string()))?;
+        let flags = graph
+            .object(&id, &sh("flags"))
+            .and_then(lexical_form)
+            .unwrap_or_default();
+        let regex = match flags {
+            "" => Regex::new(pattern),
+            flags => Regex::new(&format!("(?{flags}){pattern}")),
+        }
+        .map_err(|err| invalid(format!("Invalid sh:pattern: {err}")))?;
+        constraints.push(Constraint::Pattern(regex));
+    }
+    for head in graph.objects(&id, &sh("languageIn")) {
+        let languages = list(head, "languageIn")?
+            .iter()
+            .filter_map(|language| lexical_form(language).map(str::to_lowercase))
+            .collect();
+        constraints.push(Constraint::LanguageIn(languages));
+    }
+    if graph
+        .objects(&id, &sh("uniqueLang"))
+        .any(|value| lexical_form(value) == Some("true"))
+    {
+        constraints.push(Constraint::UniqueLang);
+    }
+    for head in graph.objects(&id, &sh("in")) {
+        constraints.push(Constraint::In(list(head, "in")?));
+    }
+    for value in graph.objects(&id, &sh("hasValue")) {
+        constraints.push(Constraint::HasValue(value.clone()));
+    }
+    for shape in graph.objects(&id, &sh("node")) {
+        constraints.push(Constraint::Node(shape.clone()));
+    }
+    for shape in graph.objects(&id, &sh("not")) {
+        constraints.push(Constraint::Not(shape.clone()));
+    }
+    for (predicate, constraint) in [
+        ("and", Constraint::And as fn(Vec<Term>) -> Constraint),
+        ("or", Constraint::Or),
+        ("xone", Constraint::Xone),
+    ] {
+        for head in graph.objects(&id, &sh(predicate)) {
+            constraints.push(constraint(list(head, predicate)?));
+        }
+    }
+
+    let severity = match graph.object(&id, &sh("severity")).and_then(Term::as_iri) {
+        Some(severity) if severity == sh("Info") => Severity::Info,
+        Some(severity) if severity == sh("Warning") => Severity::Warning,
+        _ => Severity::Violation,
+    };
+
+    Ok(Shape {
+        path,
+        targets,
+        constraints,
+        properties: graph.objects(&id, &sh("property")).cloned().collect(),
+        severity,
+        message: graph
+            .object(&id, &sh("message"))
+            .and_then(lexical_form)
+            .map(str::to_string),
+        deactivated: graph
+            .objects(&id, &sh("deactivated"))
+            .any(|value| lexical_form(value) == Some("true")),
+        id,
+    })
+}
+
+fn parse_path(graph: &Graph, path: &Term) -> Option<PropertyPath> {
+    if let Term::Iri(predicate) = path {
+        return Some(PropertyPath::Predicate(predicate.clone()));
+    }
+
+    if let Some(items) = graph.list(path).filter(|items| !items.is_empty()) {
+        return items
+            .iter()
+            .map(|item| parse_path(graph, item))
+            .collect::<Option<_>>()
+            .map(PropertyPath::Sequence);
+    }
+
+    let nested = |name: &str| {
+        graph
+            .object(path, &format!("{SH}{name}"))
+            .and_then(|inner| parse_path(graph, inner))
+            .map(Box::new)
+    };
+
+    if let Some(inner) = nested("inversePath") {
+        return Some(PropertyPath::Inverse(inner));
+    }
+    if let Some(inner) = nested("zeroOrMorePath") {
+        return Some(PropertyPath::ZeroOrMore(inner));
+    }
+    if let Some(inner) = nested("oneOrMorePath") {
+        return Some(PropertyPath::OneOrMore(inner));
+    }
+    if let Some(inner) = nested("zeroOrOnePath") {
+        return Some(PropertyPath::ZeroOrOne(inner));
+    }
+
+    let alternatives = graph.object(path, &format!("{SH}alternativePath"))?;
+    graph
+        .list(alternatives)?
+        .iter()
+        .map(|item| parse_path(graph, item))
+        .collect::<Option<_>>()
+        .map(PropertyPath::Alternative)
+}
+
+/// The string value of an IRI or literal, as used by the string based constraints.
+fn lexical_form(term: &Term) -> Option<&str> {
+    match term {
+        Term::Iri(iri) => Some(iri),
+        Term::Literal(literal) => Some(&literal.value),
+        Term::BlankNode(_) => None,
+    }
+}
+
+/// Basic language range matching from RFC 4647.
+fn language_matches(language: &str, range: &str) -> bool {
+    range == "*"
+        || language.eq_ignore_ascii_case(range)
+        || language
+            .to_lowercase()
+            .strip_prefix(&range.to_lowercase())
+            .is_some_and(|rest| rest.starts_with('-'))
+}
@@ -0,0 +1,248 @@
+//! Loads SKOS concept schemes from Turtle or JSON-LD, e.g. the grading schemes or program codes of an institution,
+//! and checks the `Concept`s of a credential against them.
+//!
+//! A concept belongs to the schemes of its `skos:inScheme` and `skos:topConceptOf`, and to the schemes that list it
+//! as `skos:hasTopConcept`. Concepts without an IRI are skipped, credentials can't reference them.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt, fs, io,
+    path::Path,
+};
+
+use serde_json::Value;
+use types_elm_v3::{
+    vocabulary::{Vocabulary, VocabularyConcept, VocabularyRegistry},
+    EuropassEdcCredential, LangKey,
+};
+
+use crate::{
+    jsonld::{self, JsonLdError},
+    rdf::{Graph, Term},
+    report::{ValidationReport, Violation, ViolationSource},
+    shacl::Severity,
+    turtle,
+};
+
+pub const SKOS: &str = "http://www.w3.org/2004/02/skos/core#";
+
+const SKOS_CONCEPT_SCHEME: &str = "http://www.w3.org/2004/02/skos/core#ConceptScheme";
+const SKOS_IN_SCHEME: &str = "http://www.w3.org/2004/02/skos/core#inScheme";
+const SKOS_TOP_CONCEPT_OF: &str = "http://www.w3.org/2004/02/skos/core#topConceptOf";
+const SKOS_HAS_TOP_CONCEPT: &str = "http://www.w3.org/2004/02/skos/core#hasTopConcept";
+const SKOS_PREF_LABEL: &str = "http://www.w3.org/2004/02/skos/core#prefLabel";
+const SKOS_NOTATION: &str = "http://www.w3.org/2004/02/skos/core#notation";
+
+#[derive(Debug)]
+pub enum SkosError {
+    Turtle(turtle::ParseError),
+    JsonLd(JsonLdError),
+    Json(serde_json::Error),
+    Io(io::Error),
+    /// The file isn't a `.ttl`, `.json` or `.jsonld` file.
+    UnknownFormat(String),
+}
+
+impl fmt::Display for SkosError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SkosError::Turtle(err) => write!(f, "Invalid Turtle: {err}"),
+            SkosError::JsonLd(err) => write!(f, "Invalid JSON-LD: {err}"),
+            SkosError::Json(err) => write!(f, "Invalid JSON: {err}"),
+            SkosError::Io(err) => write!(f, "{err}"),
+            SkosError::UnknownFormat(path) => write!(f, "Not a Turtle or JSON-LD file: {path}"),
+        }
+    }
+}
+
+impl std::error::Error for SkosError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SkosError::Turtle(err) => Some(err),
+            SkosError::JsonLd(err) => Some(err),
+            SkosError::Json(err) => Some(err),
+            SkosError::Io(err) => Some(err),
+            SkosError::UnknownFormat(_) => None,
+        }
+    }
+}
+
+impl From<turtle::ParseError> for SkosError {
+    fn from(err: turtle::ParseError) -> Self {
+        SkosError::Turtle(err)
+    }
+}
+
+impl From<JsonLdError> for SkosError {
+    fn from(err: JsonLdError) -> Self {
+        SkosError::JsonLd(err)
+    }
+}
+
+impl From<serde_json::Error> for SkosError {
+    fn from(err: serde_json::Error) -> Self {
+        SkosError::Json(err)
+    }
+}
+
+impl From<io::Error> for SkosError {
+    fn from(err: io::Error) -> Self {
+        SkosError::Io(err)
+    }
+}
+
+pub fn from_turtle(input: &str) -> Result<Vec<Vocabulary>, SkosError> {
+    Ok(from_graph(&turtle::parse(input)?))
+}
+
+/// Reads a JSON-LD document, terms are expanded with the bundled EDC context and its embedded contexts.
+pub fn from_json_ld(document: &Value) -> Result<Vec<Vocabulary>, SkosError> {
+    Ok(from_graph(&jsonld::to_rdf(document)?))
+}
+
+/// The concept schemes of `graph` with their concepts, ordered by id.
+pub fn from_graph(graph: &Graph) -> Vec<Vocabulary> {
+    let mut schemes: BTreeMap<String, BTreeSet<&Term>> = graph
+        .instances_of(SKOS_CONCEPT_SCHEME)
+        .into_iter()
+        .filter_map(|scheme| Some((scheme_id(scheme)?, BTreeSet::new())))
+        .collect();
+
+    for predicate in [SKOS_IN_SCHEME, SKOS_TOP_CONCEPT_OF] {
+        for (concept, _, scheme) in graph.triples().filter(|(_, p, _)| *p == predicate) {
+            if let Some(scheme) = scheme_id(scheme) {
+                schemes.entry(scheme).or_default().insert(concept);
+            }
+        }
+    }
+
+    for (scheme, _, concept) in graph.triples().filter(|(_, p, _)| *p == SKOS_HAS_TOP_CONCEPT) {
+        if let Some(scheme) = scheme_id(scheme) {
+            schemes.entry(scheme).or_default().insert(concept);
+        }
+    }
+
+    schemes
+        .into_iter()
+        .map(|(id, concepts)| Vocabulary {
+            pref_label: pref_labels(graph, &Term::Iri(id.clone())),
+            concepts: concepts
+                .into_iter()
+                .filter_map(|concept| {
+                    Some(VocabularyConcept {
+                        id: concept.as_iri()?.to_string(),
+                        notation: graph
+                            .object(concept, SKOS_NOTATION)
+                            .and_then(Term::as_literal)
+                            .map(|notation| notation.value.clone()),
+                        pref_label: pref_labels(graph, concept),
+                    })
+                })
+                .collect(),
+            id,
+        })
+        .collect()
+}
+
+/// Adds the concept schemes of a `.ttl`, `.json` or `.jsonld` file to `registry`, returns how many were added.
+pub fn load(registry: &mut VocabularyRegistry, path: &Path) -> Result<usize, SkosError> {
+    let input = fs::read_to_string(path)?;
+
+    let vocabularies = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("ttl") => from_turtle(&input)?,
+        Some("json" | "jsonld") => from_json_ld(&serde_json::from_str(&input)?)?,
+        _ => return Err(SkosError::UnknownFormat(path.display().to_string())),
+    };
+
+    let count = vocabularies.len();
+    for vocabulary in vocabularies {
+        registry.insert(vocabulary);
+    }
+
+    Ok(count)
+}
+
+/// A violation for every `Concept` of `credential` in `scheme` that isn't one of the concepts of `scheme`.
+///
+/// Every concept in `scheme` is a violation when `registry` doesn't have the scheme.
+pub fn validate_concepts(
+    credential: &EuropassEdcCredential,
+    registry: &VocabularyRegistry,
+    scheme: &str,
+) -> Result<ValidationReport, serde_json::Error> {
+    let mut report = ValidationReport::default();
+    let vocabulary = registry.vocabulary(scheme);
+
+    for (json_path, concept) in concepts(&serde_json::to_value(credential)?, "$".to_string()) {
+        if concept["inScheme"]["id"].as_str() != Some(scheme) {
+            continue;
+        }
+
+        let message = match (vocabulary, concept["id"].as_str()) {
+            (None, _) => format!("Concept scheme {scheme} isn't loaded"),
+            (Some(_), None) => format!("Concept in {scheme} has no id"),
+            (Some(vocabulary), Some(id)) if !vocabulary.contains(id) => format!("{id} isn't a concept of {scheme}"),
+            _ => continue,
+        };
+
+        report.push(Violation {
+            source: ViolationSource::Vocabulary,
+            json_path,
+            focus_node: concept["id"].as_str().map(String::from),
+            result_path: None,
+            value: None,
+            source_shape: None,
+            constraint: None,
+            severity: Severity::Violation,
+            message,
+        });
+    }
+
+    Ok(report)
+}
+
+/// Every object with the type `Concept`, with its JSON path.
+fn concepts(value: &Value, path: String) -> Vec<(String, &Value)> {
+    match value {
+        Value::Object(object) => {
+            let mut concepts = vec![];
+            if object.get("type").and_then(Value::as_str) == Some("Concept") {
+                concepts.push((path.clone(), value));
+            }
+
+            for (key, value) in object {
+                concepts.extend(self::concepts(value, format!("{path}.{key}")));
+            }
+
+            concepts
+        }
+        Value::Array(values) => values
+            .iter()
+            .enumerate()
+            .flat_map(|(i, value)| self::concepts(value, format!("{path}[{i}]")))
+            .collect(),
+        _ => vec![],
+    }
+}
+
+/// The id of a scheme, the EDC context reads an `inScheme` string as a literal.
+fn scheme_id(scheme: &Term) -> Option<String> {
+    match scheme {
+        Term::Iri(iri) => Some(iri.clone()),
+        Term::Literal(literal) => Some(literal.value.clone()),
+        Term::BlankNode(_) => None,
+    }
+}
+
+/// Labels without a language are kept as `und`, undetermined.
+fn pref_labels(graph: &Graph, node: &Term) -> BTreeMap<LangKey, String> {
+    graph
+        .objects(node, SKOS_PREF_LABEL)
+        .filter_map(Term::as_literal)
+        .filter_map(|label| {
+            let lang = label.language.as_deref().unwrap_or("und").parse().ok()?;
+
+            Some((lang, label.value.clone()))
+        })
+        .collect()
+}
@@ -1,59 +1,115 @@
 use log::{error, info};
 use serde::Deserialize;
-use std::{fs, io, path::PathBuf, process::Command};
+use std::{fs, path::Path};
 use types_elm_v3::EuropassEdcCredential;
 
-use crate::manifest_dir;
+use crate::{
+    error::ValidatorError,
+    jsonld,
+    report::{self, ValidationReport, Violation, ViolationSource},
+    schema,
+    shacl::Shapes,
+};
 
 #[derive(Debug)]
 pub struct ValidateRequest {
     pub valid_shacl: bool,
-    pub rust_object: io::Result<EuropassEdcCredential>,
+    /// Whether the raw JSON is valid according to the EDC credential JSON Schema.
+    pub valid_schema: bool,
+    pub rust_object: Result<EuropassEdcCredential, ValidatorError>,
+    /// Every SHACL violation and deserialization error, located by JSON path.
+    pub report: ValidationReport,
 }
 
 impl ValidateRequest {
-    pub fn new(json_file: PathBuf) -> io::Result<Self> {
-        let valid_shacl = validate_shacl(&json_file)?;
-        let rust_object = validate_rust(&json_file);
+    pub fn new(json_file: impl AsRef<Path>) -> Result<Self, ValidatorError> {
+        Self::from_json(&fs::read_to_string(json_file)?)
+    }
+
+    /// Validates a credential that is already in memory, `json` must be a JSON(-LD) document.
+    pub fn from_json(json: &str) -> Result<Self, ValidatorError> {
+        let document = parse_json(json)?;
+
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        deserializer.disable_recursion_limit();
+        let deserializer = serde_stacker::Deserializer::new(&mut deserializer);
+
+        Self::from_document(&document, serde_path_to_error::deserialize(deserializer))
+    }
+
+    pub fn from_value(document: &serde_json::Value) -> Result<Self, ValidatorError> {
+        let deserializer = serde_stacker::Deserializer::new(document);
+
+        Self::from_document(document, serde_path_to_error::deserialize(deserializer))
+    }
+
+    fn from_document(
+        document: &serde_json::Value,
+        credential: Result<EuropassEdcCredential, serde_path_to_error::Error<serde_json::Error>>,
+    ) -> Result<Self, ValidatorError> {
+        let mut report = shacl_report(document)?;
+        let valid_shacl = report.conforms();
+
+        let schema_report = schema::validate_schema(document);
+        let valid_schema = schema_report.conforms();
+        report.violations.extend(schema_report.violations);
+
+        let rust_object = credential.map_err(|err| {
+            report.push(Violation::from_deserialization(&err));
+
+            ValidatorError::Deserialization {
+                path: report::json_path(err.path()),
+                source: err.into_inner(),
+            }
+        });
+
+        log_report(&report);
 
         Ok(Self {
             valid_shacl,
+            valid_schema,
             rust_object,
+            report,
         })
     }
 }
 
-pub fn validate_shacl(json_file: &PathBuf) -> io::Result<bool> {
-    let manifest_dir = manifest_dir();
-    let cwd = manifest_dir.join("shacl-validator/");
-    let python_bin = manifest_dir.join("shacl-validator/venv/bin/python");
+pub fn validate_shacl(json_file: &Path) -> Result<bool, ValidatorError> {
+    let json = fs::read_to_string(json_file)?;
+    let report = shacl_report(&parse_json(&json)?)?;
+
+    log_report(&report);
+
+    Ok(report.conforms())
+}
+
+fn shacl_report(document: &serde_json::Value) -> Result<ValidationReport, ValidatorError> {
+    let (data, paths) = jsonld::to_rdf_with_paths(document).map_err(|err| ValidatorError::Shacl(err.into()))?;
+
+    let violations = Shapes::edc()
+        .validate(&data)
+        .iter()
+        .map(|result| Violation::from_shacl(result, &paths))
+        .collect();
 
-    let out = Command::new(python_bin)
-        .current_dir(cwd)
-        .args(["main.py", "--input-file", json_file.to_str().unwrap()])
-        .output()?;
+    Ok(ValidationReport { violations })
+}
 
-    if !out.status.success() {
-        error!("{}", String::from_utf8_lossy(&out.stderr));
-    } else {
-        info!("{}", String::from_utf8_lossy(&out.stdout));
+fn log_report(report: &ValidationReport) {
+    if report.by_source(ViolationSource::Shacl).next().is_none() {
+        info!("Data conforms to the SHACL shape.");
     }
 
-    Ok(out.status.success())
+    if !report.conforms() {
+        error!("Validation report:\n{report}");
+    }
 }
 
-fn validate_rust(json_file: &PathBuf) -> io::Result<EuropassEdcCredential> {
-    let json = fs::read_to_string(json_file)?;
-    let mut deserializer = serde_json::Deserializer::from_str(&json);
+pub(crate) fn parse_json(json: &str) -> Result<serde_json::Value, ValidatorError> {
+    let mut deserializer = serde_json::Deserializer::from_str(json);
     deserializer.disable_recursion_limit();
 
     let deserializer = serde_stacker::Deserializer::new(&mut deserializer);
 
-    let credential = EuropassEdcCredential::deserialize(deserializer);
-
-    if let Err(err) = &credential {
-        eprintln!("Error: {err:?}");
-    }
-
-    Ok(credential?)
+    Ok(serde_json::Value::deserialize(deserializer)?)
 }
@@ -0,0 +1,113 @@
+//! Maintenance tasks of the workspace, run with `cargo xtask <task>`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use clap::Parser;
+use serde::Serialize;
+use types_elm_v3::{
+    verification,
+    vocabulary::{self, Vocabulary, VocabularyRegistry},
+};
+use validator::skos;
+
+/// The bundled schemes, by the name of their file in `types-elm-v3/vocabularies`.
+const SCHEMES: &[(&str, &str)] = &[
+    ("eqf", vocabulary::EQF),
+    ("isced-f", vocabulary::ISCED_F),
+    ("country", vocabulary::COUNTRY),
+    ("language", vocabulary::LANGUAGE),
+    ("credential", vocabulary::CREDENTIAL_TYPE),
+    ("learning-assessment", vocabulary::LEARNING_ASSESSMENT),
+    ("learning-setting", vocabulary::LEARNING_SETTING),
+    ("verification", verification::VERIFICATION_TYPE_SCHEME),
+    ("verification-status", verification::VERIFICATION_STATUS_SCHEME),
+];
+
+/// The authority tables, their concepts are looked up by the code their id ends with.
+const AUTHORITY_TABLES: &[&str] = &[vocabulary::COUNTRY, vocabulary::LANGUAGE];
+
+#[derive(Debug, Parser)]
+#[command(name = "xtask")]
+enum Task {
+    /// Regenerates `types-elm-v3/vocabularies/*.json` from the SKOS dumps of the Publications Office, with the
+    /// labels in all languages. `types-elm-v3/fetch-vocabularies.sh` downloads the dumps.
+    Vocabularies {
+        /// The directory of the `<name>.ttl` dumps, defaults to `types-elm-v3/vocabularies/skos`.
+        #[arg(long)]
+        dumps: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let vocabularies_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../types-elm-v3/vocabularies");
+
+    let result = match Task::parse() {
+        Task::Vocabularies { dumps } => {
+            vocabularies(&dumps.unwrap_or(vocabularies_dir.join("skos")), &vocabularies_dir)
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{err}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn vocabularies(dumps: &Path, out: &Path) -> Result<(), String> {
+    for (name, scheme) in SCHEMES {
+        let dump = dumps.join(format!("{name}.ttl"));
+
+        let mut registry = VocabularyRegistry::new();
+        skos::load(&mut registry, &dump).map_err(|err| format!("{}: {err}", dump.display()))?;
+
+        let mut vocabulary = registry
+            .vocabulary(scheme)
+            .cloned()
+            .ok_or_else(|| format!("{}: no concept scheme {scheme}", dump.display()))?;
+
+        if AUTHORITY_TABLES.contains(scheme) {
+            for concept in &mut vocabulary.concepts {
+                concept.notation = concept.id.rsplit('/').next().map(str::to_string);
+            }
+        }
+        vocabulary.concepts.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let path = out.join(format!("{name}.json"));
+        fs::write(&path, snapshot(&vocabulary)).map_err(|err| format!("{}: {err}", path.display()))?;
+        println!("{}: {} concepts", path.display(), vocabulary.concepts.len());
+    }
+
+    Ok(())
+}
+
+/// The JSON of a bundled vocabulary, one concept per line.
+fn snapshot(vocabulary: &Vocabulary) -> String {
+    let concepts: Vec<_> = vocabulary
+        .concepts
+        .iter()
+        .map(|concept| format!("    {}", json(concept)))
+        .collect();
+    let source = format!(
+        "Generated by `cargo xtask vocabularies` from the SKOS dump of {}",
+        vocabulary.id
+    );
+
+    format!(
+        "{{\n  \"id\": {},\n  \"prefLabel\": {},\n  \"source\": {},\n  \"concepts\": [\n{}\n  ]\n}}\n",
+        json(&vocabulary.id),
+        json(&vocabulary.pref_label),
+        json(&source),
+        concepts.join(",\n"),
+    )
+}
+
+fn json(value: &impl Serialize) -> String {
+    serde_json::to_string(value).expect("vocabularies serialize to JSON")
+}
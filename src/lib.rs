@@ -9,3 +9,11 @@ pub mod elmv3 {
 pub mod types_common {
     pub use types_common::*;
 }
+
+pub mod converter {
+    pub use converter::*;
+}
+
+pub mod status_list {
+    pub use status_list::*;
+}